@@ -0,0 +1 @@
+ALTER TABLE accounts DROP COLUMN archived;
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP INDEX movements_transfer_id_idx;
+
+ALTER TABLE movements DROP COLUMN transfer_id;
\ No newline at end of file
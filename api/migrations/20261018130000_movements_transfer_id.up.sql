@@ -0,0 +1,3 @@
+ALTER TABLE movements ADD COLUMN transfer_id UUID;
+
+CREATE INDEX movements_transfer_id_idx ON movements(transfer_id);
\ No newline at end of file
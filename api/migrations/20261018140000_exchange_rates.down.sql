@@ -0,0 +1,5 @@
+ALTER TABLE movements DROP CONSTRAINT movements_transfer_id_fkey;
+
+DROP TABLE transfers;
+
+DROP TABLE exchange_rates;
\ No newline at end of file
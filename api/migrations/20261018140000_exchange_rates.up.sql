@@ -0,0 +1,20 @@
+CREATE TABLE exchange_rates(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) NOT NULL,
+    date DATE NOT NULL,
+    from_currency VARCHAR NOT NULL,
+    to_currency VARCHAR NOT NULL,
+    rate NUMERIC(20,10) NOT NULL,
+    UNIQUE(user_id, date, from_currency, to_currency)
+);
+
+CREATE TABLE transfers(
+    id UUID PRIMARY KEY,
+    rate NUMERIC(20,10) NOT NULL,
+    rate_date DATE
+);
+
+INSERT INTO transfers(id, rate)
+SELECT DISTINCT transfer_id, 1 FROM movements WHERE transfer_id IS NOT NULL;
+
+ALTER TABLE movements ADD CONSTRAINT movements_transfer_id_fkey FOREIGN KEY (transfer_id) REFERENCES transfers(id);
\ No newline at end of file
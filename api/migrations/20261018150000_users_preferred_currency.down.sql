@@ -0,0 +1 @@
+ALTER TABLE users DROP COLUMN preferred_currency;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE users ADD COLUMN preferred_currency VARCHAR NOT NULL DEFAULT 'USD';
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE movements ALTER COLUMN amount TYPE NUMERIC(10,2);
+
+ALTER TABLE accounts ALTER COLUMN balance TYPE NUMERIC(10,2);
\ No newline at end of file
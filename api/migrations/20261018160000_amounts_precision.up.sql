@@ -0,0 +1,3 @@
+ALTER TABLE accounts ALTER COLUMN balance TYPE NUMERIC(20,4);
+
+ALTER TABLE movements ALTER COLUMN amount TYPE NUMERIC(20,4);
\ No newline at end of file
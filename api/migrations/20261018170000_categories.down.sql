@@ -0,0 +1,13 @@
+ALTER TABLE movements ADD COLUMN category VARCHAR NOT NULL DEFAULT 'GENERIC';
+
+UPDATE movements
+SET category = upper(categories.name)
+FROM categories
+WHERE categories.id = movements.category_id
+AND upper(categories.name) IN ('GENERIC', 'BILLS', 'SHOPPING', 'INCOME', 'INSURANCE');
+
+ALTER TABLE movements ALTER COLUMN category DROP DEFAULT;
+
+ALTER TABLE movements DROP COLUMN category_id;
+
+DROP TABLE categories;
\ No newline at end of file
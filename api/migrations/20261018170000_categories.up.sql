@@ -0,0 +1,27 @@
+CREATE TABLE categories(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    parent_id UUID REFERENCES categories(id),
+    name VARCHAR NOT NULL,
+    color VARCHAR,
+    icon VARCHAR
+);
+
+CREATE INDEX categories_user_id_idx ON categories(user_id);
+
+INSERT INTO categories(id, user_id, name)
+SELECT gen_random_uuid(), users.id, defaults.name
+FROM users
+CROSS JOIN (VALUES ('Generic'), ('Bills'), ('Shopping'), ('Income'), ('Insurance')) AS defaults(name);
+
+ALTER TABLE movements ADD COLUMN category_id UUID REFERENCES categories(id);
+
+UPDATE movements
+SET category_id = categories.id
+FROM accounts, categories
+WHERE accounts.id = movements.account_id
+AND categories.user_id = accounts.user_id
+AND upper(categories.name) = movements.category
+AND movements.transfer_id IS NULL;
+
+ALTER TABLE movements DROP COLUMN category;
\ No newline at end of file
@@ -0,0 +1,10 @@
+CREATE TABLE budgets(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    category_id UUID REFERENCES categories(id) ON DELETE CASCADE NOT NULL,
+    month DATE NOT NULL CHECK (EXTRACT(DAY FROM month) = 1),
+    amount NUMERIC(20,4) NOT NULL,
+    currency VARCHAR NOT NULL,
+    rollover BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE(user_id, category_id, month)
+);
\ No newline at end of file
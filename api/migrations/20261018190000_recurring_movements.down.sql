@@ -0,0 +1 @@
+DROP TABLE recurring_movements;
\ No newline at end of file
@@ -0,0 +1,15 @@
+CREATE TABLE recurring_movements(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    account_id UUID REFERENCES accounts(id) ON DELETE CASCADE NOT NULL,
+    category_id UUID REFERENCES categories(id) NOT NULL,
+    title VARCHAR NOT NULL,
+    amount NUMERIC(20,4) NOT NULL,
+    frequency VARCHAR NOT NULL,
+    start_date DATE NOT NULL,
+    next_date DATE NOT NULL,
+    paused BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX recurring_movements_user_id_idx ON recurring_movements(user_id);
+CREATE INDEX recurring_movements_next_date_idx ON recurring_movements(next_date) WHERE NOT paused;
\ No newline at end of file
@@ -0,0 +1,15 @@
+CREATE TABLE import_mappings(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    name VARCHAR NOT NULL,
+    delimiter VARCHAR(1) NOT NULL,
+    date_column VARCHAR NOT NULL,
+    description_column VARCHAR NOT NULL,
+    amount_column VARCHAR,
+    debit_column VARCHAR,
+    credit_column VARCHAR,
+    date_format VARCHAR NOT NULL,
+    decimal_separator VARCHAR(1) NOT NULL
+);
+
+CREATE INDEX import_mappings_user_id_idx ON import_mappings(user_id);
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP INDEX movements_account_id_external_id_idx;
+
+ALTER TABLE movements DROP COLUMN external_id;
\ No newline at end of file
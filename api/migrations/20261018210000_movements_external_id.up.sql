@@ -0,0 +1,3 @@
+ALTER TABLE movements ADD COLUMN external_id VARCHAR;
+
+CREATE UNIQUE INDEX movements_account_id_external_id_idx ON movements(account_id, external_id);
\ No newline at end of file
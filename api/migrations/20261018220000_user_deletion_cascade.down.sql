@@ -0,0 +1,5 @@
+ALTER TABLE exchange_rates DROP CONSTRAINT exchange_rates_user_id_fkey,
+    ADD CONSTRAINT exchange_rates_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id);
+
+ALTER TABLE accounts DROP CONSTRAINT accounts_user_id_fkey,
+    ADD CONSTRAINT accounts_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id);
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE accounts DROP COLUMN opening_date;
+
+ALTER TABLE accounts DROP COLUMN opening_balance;
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE accounts ADD COLUMN opening_balance NUMERIC(20,4) NOT NULL DEFAULT 0;
+
+ALTER TABLE accounts ADD COLUMN opening_date DATE;
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP TABLE reconciliations;
+
+ALTER TABLE movements DROP COLUMN status;
\ No newline at end of file
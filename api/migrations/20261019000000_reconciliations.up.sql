@@ -0,0 +1,11 @@
+ALTER TABLE movements ADD COLUMN status VARCHAR NOT NULL DEFAULT 'PENDING';
+
+CREATE TABLE reconciliations(
+    id UUID PRIMARY KEY,
+    account_id UUID REFERENCES accounts(id) ON DELETE CASCADE NOT NULL,
+    statement_date DATE NOT NULL,
+    statement_balance NUMERIC(20,4) NOT NULL,
+    finished_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX reconciliations_account_id_open_idx ON reconciliations(account_id) WHERE finished_at IS NULL;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE movement_splits;
\ No newline at end of file
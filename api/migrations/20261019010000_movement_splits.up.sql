@@ -0,0 +1,10 @@
+CREATE TABLE movement_splits(
+    id UUID PRIMARY KEY,
+    movement_id UUID REFERENCES movements(id) ON DELETE CASCADE NOT NULL,
+    category_id UUID REFERENCES categories(id) NOT NULL,
+    amount NUMERIC(20,4) NOT NULL,
+    memo VARCHAR,
+    position INTEGER NOT NULL
+);
+
+CREATE INDEX movement_splits_movement_id_idx ON movement_splits(movement_id);
\ No newline at end of file
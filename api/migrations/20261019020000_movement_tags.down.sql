@@ -0,0 +1,5 @@
+DROP INDEX movements_tags_idx;
+
+ALTER TABLE movements DROP COLUMN tags;
+
+ALTER TABLE movements DROP COLUMN note;
\ No newline at end of file
@@ -0,0 +1,5 @@
+ALTER TABLE movements ADD COLUMN note TEXT;
+
+ALTER TABLE movements ADD COLUMN tags VARCHAR[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX movements_tags_idx ON movements USING GIN (tags);
\ No newline at end of file
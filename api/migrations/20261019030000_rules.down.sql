@@ -0,0 +1 @@
+DROP TABLE rules;
\ No newline at end of file
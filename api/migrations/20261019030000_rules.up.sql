@@ -0,0 +1,16 @@
+CREATE TABLE rules(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    name VARCHAR NOT NULL,
+    priority INTEGER NOT NULL,
+    title_contains VARCHAR,
+    title_regex VARCHAR,
+    min_amount NUMERIC(20,4),
+    max_amount NUMERIC(20,4),
+    account_id UUID REFERENCES accounts(id) ON DELETE CASCADE,
+    category_id UUID REFERENCES categories(id) ON DELETE CASCADE,
+    add_tags VARCHAR[] NOT NULL DEFAULT '{}',
+    rename_title VARCHAR
+);
+
+CREATE INDEX rules_user_id_idx ON rules(user_id);
\ No newline at end of file
@@ -0,0 +1,7 @@
+DROP TRIGGER movements_retrain_category_classifier ON movements;
+DROP TRIGGER movements_train_category_classifier ON movements;
+DROP FUNCTION train_category_classifier;
+DROP FUNCTION count_movement_tokens;
+DROP FUNCTION title_tokens;
+DROP TABLE category_movement_counts;
+DROP TABLE category_token_counts;
\ No newline at end of file
@@ -0,0 +1,86 @@
+-- Naive Bayes counts of the title tokens of categorized movements, kept up to date by triggers
+CREATE TABLE category_token_counts(
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    category_id UUID REFERENCES categories(id) ON DELETE CASCADE NOT NULL,
+    token VARCHAR NOT NULL,
+    count BIGINT NOT NULL,
+    PRIMARY KEY (category_id, token)
+);
+
+CREATE INDEX category_token_counts_user_id_token_idx ON category_token_counts(user_id, token);
+
+CREATE TABLE category_movement_counts(
+    category_id UUID PRIMARY KEY REFERENCES categories(id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    movements BIGINT NOT NULL,
+    tokens BIGINT NOT NULL
+);
+
+CREATE INDEX category_movement_counts_user_id_idx ON category_movement_counts(user_id);
+
+-- Lowercase words of at least two characters, numbers such as bank references are ignored
+CREATE FUNCTION title_tokens(title VARCHAR) RETURNS SETOF VARCHAR AS $$
+    SELECT DISTINCT token
+    FROM regexp_split_to_table(lower(title), '[^[:alnum:]]+') token
+    WHERE length(token) > 1 AND token !~ '^[0-9]+$'
+$$ LANGUAGE SQL IMMUTABLE;
+
+CREATE FUNCTION count_movement_tokens(movement movements, delta BIGINT) RETURNS VOID AS $$
+BEGIN
+    IF movement.category_id IS NULL THEN
+        RETURN;
+    END IF;
+
+    IF delta > 0 THEN
+        INSERT INTO category_token_counts(user_id, category_id, token, count)
+        SELECT accounts.user_id, movement.category_id, token, delta
+        FROM accounts, title_tokens(movement.title) token
+        WHERE accounts.id = movement.account_id
+        ON CONFLICT (category_id, token) DO UPDATE SET count = category_token_counts.count + delta;
+
+        INSERT INTO category_movement_counts(category_id, user_id, movements, tokens)
+        SELECT movement.category_id, accounts.user_id, delta, (SELECT count(*) FROM title_tokens(movement.title))
+        FROM accounts
+        WHERE accounts.id = movement.account_id
+        ON CONFLICT (category_id) DO UPDATE
+        SET movements = category_movement_counts.movements + EXCLUDED.movements,
+            tokens = category_movement_counts.tokens + EXCLUDED.tokens;
+    ELSE
+        -- The account may be gone already when its movements are deleted, so rows are matched by category
+        UPDATE category_token_counts
+        SET count = count + delta
+        WHERE category_id = movement.category_id AND token IN (SELECT title_tokens(movement.title));
+        DELETE FROM category_token_counts WHERE category_id = movement.category_id AND count <= 0;
+
+        UPDATE category_movement_counts
+        SET movements = movements + delta,
+            tokens = tokens + delta * (SELECT count(*) FROM title_tokens(movement.title))
+        WHERE category_id = movement.category_id;
+        DELETE FROM category_movement_counts WHERE category_id = movement.category_id AND movements <= 0;
+    END IF;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION train_category_classifier() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        PERFORM count_movement_tokens(OLD, -1);
+    END IF;
+    IF TG_OP IN ('INSERT', 'UPDATE') THEN
+        PERFORM count_movement_tokens(NEW, 1);
+    END IF;
+    RETURN NULL;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER movements_train_category_classifier
+AFTER INSERT OR DELETE ON movements
+FOR EACH ROW EXECUTE FUNCTION train_category_classifier();
+
+CREATE TRIGGER movements_retrain_category_classifier
+AFTER UPDATE OF title, category_id ON movements
+FOR EACH ROW
+WHEN (OLD.title IS DISTINCT FROM NEW.title OR OLD.category_id IS DISTINCT FROM NEW.category_id)
+EXECUTE FUNCTION train_category_classifier();
+
+SELECT count_movement_tokens(movements, 1) FROM movements;
\ No newline at end of file
@@ -0,0 +1,17 @@
+CREATE TABLE goals(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    name VARCHAR NOT NULL,
+    target_amount NUMERIC(20,4) NOT NULL,
+    currency VARCHAR NOT NULL,
+    start_date DATE NOT NULL,
+    deadline DATE NOT NULL
+);
+
+CREATE INDEX goals_user_id_idx ON goals(user_id);
+
+CREATE TABLE goal_accounts(
+    goal_id UUID REFERENCES goals(id) ON DELETE CASCADE NOT NULL,
+    account_id UUID REFERENCES accounts(id) ON DELETE CASCADE NOT NULL,
+    PRIMARY KEY (goal_id, account_id)
+);
\ No newline at end of file
@@ -0,0 +1,11 @@
+ALTER TABLE accounts DROP COLUMN loan_term_months;
+
+ALTER TABLE accounts DROP COLUMN loan_principal;
+
+ALTER TABLE accounts DROP COLUMN statement_day;
+
+ALTER TABLE accounts DROP COLUMN apr;
+
+ALTER TABLE accounts DROP COLUMN credit_limit;
+
+ALTER TABLE accounts DROP COLUMN kind;
\ No newline at end of file
@@ -0,0 +1,11 @@
+ALTER TABLE accounts ADD COLUMN kind VARCHAR NOT NULL DEFAULT 'CHECKING';
+
+ALTER TABLE accounts ADD COLUMN credit_limit NUMERIC(20,4);
+
+ALTER TABLE accounts ADD COLUMN apr NUMERIC(7,4);
+
+ALTER TABLE accounts ADD COLUMN statement_day SMALLINT;
+
+ALTER TABLE accounts ADD COLUMN loan_principal NUMERIC(20,4);
+
+ALTER TABLE accounts ADD COLUMN loan_term_months INTEGER;
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP TABLE prices;
+DROP TABLE trades;
\ No newline at end of file
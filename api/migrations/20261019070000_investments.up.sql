@@ -0,0 +1,23 @@
+CREATE TABLE trades(
+    id UUID PRIMARY KEY,
+    account_id UUID REFERENCES accounts(id) ON DELETE CASCADE NOT NULL,
+    movement_id UUID REFERENCES movements(id) ON DELETE CASCADE NOT NULL UNIQUE,
+    date DATE NOT NULL,
+    kind VARCHAR NOT NULL,
+    symbol VARCHAR NOT NULL,
+    quantity NUMERIC(28,8) NOT NULL,
+    price NUMERIC(28,8) NOT NULL,
+    fees NUMERIC(20,4) NOT NULL
+);
+
+CREATE INDEX trades_account_id_idx ON trades(account_id);
+
+CREATE TABLE prices(
+    id UUID PRIMARY KEY,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    symbol VARCHAR NOT NULL,
+    currency VARCHAR NOT NULL,
+    date DATE NOT NULL,
+    price NUMERIC(28,8) NOT NULL,
+    UNIQUE(user_id, symbol, currency, date)
+);
\ No newline at end of file
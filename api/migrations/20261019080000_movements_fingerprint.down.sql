@@ -0,0 +1 @@
+ALTER TABLE movements DROP COLUMN fingerprint;
\ No newline at end of file
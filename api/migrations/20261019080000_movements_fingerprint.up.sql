@@ -0,0 +1 @@
+ALTER TABLE movements ADD COLUMN fingerprint VARCHAR;
\ No newline at end of file
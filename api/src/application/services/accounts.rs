@@ -1,9 +1,15 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use super::Repository;
 use crate::{
-    domain::entities::accounts::{Account, Movement},
+    domain::entities::accounts::{
+        Account, BalanceDrift, Movement, MovementCursor, MovementFilter, MovementSplit,
+        SplitMovement, Tag, Transfer,
+    },
     domain::error::Result,
 };
 
@@ -11,8 +17,49 @@ use crate::{
 pub trait AccountService: Repository<Account> + Send + Sync {
     async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Account>;
     async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Account>>;
-    async fn find_movements(&self, account_id: Uuid) -> Result<Vec<Movement>>;
+    async fn update_details(&self, account: Account) -> Result<Account>;
+    async fn find_movements(
+        &self,
+        account_id: Uuid,
+        filter: MovementFilter,
+        cursor: Option<MovementCursor>,
+        limit: i64,
+    ) -> Result<Vec<Movement>>;
+    fn stream_movements(
+        &self,
+        account_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> BoxStream<'static, Result<Movement>>;
+    async fn has_movements(&self, account_id: Uuid) -> Result<bool>;
+    async fn find_balance_at(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Decimal>;
+    async fn find_movement(&self, id: Uuid, account_id: Uuid) -> Result<Movement>;
     async fn insert_movement(&self, movement: Movement) -> Result<Movement>;
+    async fn insert_movements(
+        &self,
+        account_id: Uuid,
+        movements: Vec<Movement>,
+    ) -> Result<Vec<Movement>>;
+    async fn update_movement(&self, movement: Movement) -> Result<Movement>;
+    async fn delete_movement(&self, movement: Movement) -> Result<Movement>;
+    async fn find_splits(&self, movement_ids: Vec<Uuid>) -> Result<Vec<MovementSplit>>;
+    async fn find_tags(&self, user_id: Uuid) -> Result<Vec<Tag>>;
+    async fn insert_split_movement(
+        &self,
+        movement: Movement,
+        splits: Vec<MovementSplit>,
+    ) -> Result<SplitMovement>;
+    async fn update_splits(
+        &self,
+        movement: Movement,
+        splits: Vec<MovementSplit>,
+    ) -> Result<SplitMovement>;
+    async fn find_transfer(&self, id: Uuid, user_id: Uuid) -> Result<Transfer>;
+    async fn find_transfers(&self, user_id: Uuid) -> Result<Vec<Transfer>>;
+    async fn insert_transfer(&self, transfer: Transfer) -> Result<Transfer>;
+    async fn delete_transfer(&self, transfer: Transfer) -> Result<Transfer>;
+    async fn find_balance_drifts(&self) -> Result<Vec<BalanceDrift>>;
+    async fn repair_balance(&self, account_id: Uuid) -> Result<Account>;
 }
 
 #[cfg(test)]
@@ -32,7 +79,48 @@ mock! {
     impl AccountService for AccountService {
         async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Account>;
         async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Account>>;
-        async fn find_movements(&self, account_id: Uuid) -> Result<Vec<Movement>>;
+        async fn update_details(&self, account: Account) -> Result<Account>;
+        async fn find_movements(
+        &self,
+        account_id: Uuid,
+        filter: MovementFilter,
+        cursor: Option<MovementCursor>,
+        limit: i64,
+    ) -> Result<Vec<Movement>>;
+        fn stream_movements(
+            &self,
+            account_id: Uuid,
+            from: Option<DateTime<Utc>>,
+            to: Option<DateTime<Utc>>,
+        ) -> BoxStream<'static, Result<Movement>>;
+        async fn has_movements(&self, account_id: Uuid) -> Result<bool>;
+        async fn find_balance_at(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Decimal>;
+        async fn find_movement(&self, id: Uuid, account_id: Uuid) -> Result<Movement>;
         async fn insert_movement(&self, movement: Movement) -> Result<Movement>;
+        async fn insert_movements(
+            &self,
+            account_id: Uuid,
+            movements: Vec<Movement>,
+        ) -> Result<Vec<Movement>>;
+        async fn update_movement(&self, movement: Movement) -> Result<Movement>;
+        async fn delete_movement(&self, movement: Movement) -> Result<Movement>;
+        async fn find_splits(&self, movement_ids: Vec<Uuid>) -> Result<Vec<MovementSplit>>;
+        async fn find_tags(&self, user_id: Uuid) -> Result<Vec<Tag>>;
+        async fn insert_split_movement(
+            &self,
+            movement: Movement,
+            splits: Vec<MovementSplit>,
+        ) -> Result<SplitMovement>;
+        async fn update_splits(
+            &self,
+            movement: Movement,
+            splits: Vec<MovementSplit>,
+        ) -> Result<SplitMovement>;
+        async fn find_transfer(&self, id: Uuid, user_id: Uuid) -> Result<Transfer>;
+        async fn find_transfers(&self, user_id: Uuid) -> Result<Vec<Transfer>>;
+        async fn insert_transfer(&self, transfer: Transfer) -> Result<Transfer>;
+        async fn delete_transfer(&self, transfer: Transfer) -> Result<Transfer>;
+        async fn find_balance_drifts(&self) -> Result<Vec<BalanceDrift>>;
+        async fn repair_balance(&self, account_id: Uuid) -> Result<Account>;
     }
 }
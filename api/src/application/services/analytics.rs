@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::analytics::{BalancePoint, CategoryTotal, Interval, PeriodTotal, TitleTotal},
+    domain::error::Result,
+};
+
+#[async_trait]
+pub trait AnalyticsService: Send + Sync {
+    async fn find_period_totals(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+    ) -> Result<Vec<PeriodTotal>>;
+    async fn find_category_totals(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<CategoryTotal>>;
+    async fn find_top_titles(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+        limit: i64,
+    ) -> Result<Vec<TitleTotal>>;
+    async fn find_balances(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+    ) -> Result<Vec<BalancePoint>>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub AnalyticsService {}
+    #[async_trait]
+    impl AnalyticsService for AnalyticsService {
+        async fn find_period_totals(
+            &self,
+            user_id: Uuid,
+            account_id: Option<Uuid>,
+            tag: Option<String>,
+            from: NaiveDate,
+            to: NaiveDate,
+            interval: Interval,
+        ) -> Result<Vec<PeriodTotal>>;
+        async fn find_category_totals(
+            &self,
+            user_id: Uuid,
+            account_id: Option<Uuid>,
+            tag: Option<String>,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> Result<Vec<CategoryTotal>>;
+        async fn find_top_titles(
+            &self,
+            user_id: Uuid,
+            account_id: Option<Uuid>,
+            tag: Option<String>,
+            from: NaiveDate,
+            to: NaiveDate,
+            limit: i64,
+        ) -> Result<Vec<TitleTotal>>;
+        async fn find_balances(
+            &self,
+            account_id: Uuid,
+            from: NaiveDate,
+            to: NaiveDate,
+            interval: Interval,
+        ) -> Result<Vec<BalancePoint>>;
+    }
+}
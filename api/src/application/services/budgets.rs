@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::budgets::{Budget, Spending},
+    domain::error::Result,
+};
+
+#[async_trait]
+pub trait BudgetService: Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Budget>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Budget>>;
+    async fn upsert(&self, budget: Budget) -> Result<Budget>;
+    async fn delete(&self, budget: Budget) -> Result<Budget>;
+    async fn find_spending(
+        &self,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<Spending>>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub BudgetService {}
+    #[async_trait]
+    impl BudgetService for BudgetService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Budget>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Budget>>;
+        async fn upsert(&self, budget: Budget) -> Result<Budget>;
+        async fn delete(&self, budget: Budget) -> Result<Budget>;
+        async fn find_spending(
+            &self,
+            user_id: Uuid,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> Result<Vec<Spending>>;
+    }
+}
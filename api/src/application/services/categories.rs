@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::Repository;
+use crate::{
+    domain::entities::categories::{Category, ClassifierData},
+    domain::error::Result,
+};
+
+#[async_trait]
+pub trait CategoryService: Repository<Category> + Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Category>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Category>>;
+    async fn find_classifier_data(&self, user_id: Uuid, title: String) -> Result<ClassifierData>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub CategoryService {}
+    #[async_trait]
+    impl Repository<Category> for CategoryService {
+        async fn get_all(&self) -> Result<Vec<Category>>;
+        async fn find_by_id(&self, id: uuid::Uuid) -> Result<Category>;
+        async fn insert(&self, item: Category) -> Result<Category>;
+        async fn update(&self, item: Category) -> Result<Category>;
+        async fn delete(&self, item: Category) -> Result<Category>;
+    }
+    #[async_trait]
+    impl CategoryService for CategoryService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Category>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Category>>;
+        async fn find_classifier_data(&self, user_id: Uuid, title: String) -> Result<ClassifierData>;
+    }
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        currencies::Currency,
+        exchange_rates::{ExchangeRate, Quote},
+    },
+    domain::error::Result,
+};
+
+#[async_trait]
+pub trait ExchangeRateService: Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<ExchangeRate>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<ExchangeRate>>;
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        from: Currency,
+        to: Currency,
+        date: NaiveDate,
+    ) -> Result<ExchangeRate>;
+    async fn upsert_many(&self, rates: Vec<ExchangeRate>) -> Result<Vec<ExchangeRate>>;
+    async fn delete(&self, rate: ExchangeRate) -> Result<ExchangeRate>;
+}
+
+#[async_trait]
+pub trait ExchangeRateProvider: Send + Sync {
+    async fn get_quote(&self, user_id: Uuid, from: Currency, to: Currency) -> Result<Quote>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub ExchangeRateService {}
+    #[async_trait]
+    impl ExchangeRateService for ExchangeRateService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<ExchangeRate>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<ExchangeRate>>;
+        async fn find_latest(
+            &self,
+            user_id: Uuid,
+            from: Currency,
+            to: Currency,
+            date: NaiveDate,
+        ) -> Result<ExchangeRate>;
+        async fn upsert_many(&self, rates: Vec<ExchangeRate>) -> Result<Vec<ExchangeRate>>;
+        async fn delete(&self, rate: ExchangeRate) -> Result<ExchangeRate>;
+    }
+}
+
+#[cfg(test)]
+mock! {
+    pub ExchangeRateProvider {}
+    #[async_trait]
+    impl ExchangeRateProvider for ExchangeRateProvider {
+        async fn get_quote(&self, user_id: Uuid, from: Currency, to: Currency) -> Result<Quote>;
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::Repository;
+use crate::{domain::entities::goals::Goal, domain::error::Result};
+
+#[async_trait]
+pub trait GoalService: Repository<Goal> + Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Goal>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Goal>>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub GoalService {}
+    #[async_trait]
+    impl Repository<Goal> for GoalService {
+        async fn get_all(&self) -> Result<Vec<Goal>>;
+        async fn find_by_id(&self, id: uuid::Uuid) -> Result<Goal>;
+        async fn insert(&self, item: Goal) -> Result<Goal>;
+        async fn update(&self, item: Goal) -> Result<Goal>;
+        async fn delete(&self, item: Goal) -> Result<Goal>;
+    }
+    #[async_trait]
+    impl GoalService for GoalService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Goal>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Goal>>;
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::Repository;
+use crate::{domain::entities::imports::ImportMapping, domain::error::Result};
+
+#[async_trait]
+pub trait ImportMappingService: Repository<ImportMapping> + Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<ImportMapping>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<ImportMapping>>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub ImportMappingService {}
+    #[async_trait]
+    impl Repository<ImportMapping> for ImportMappingService {
+        async fn get_all(&self) -> Result<Vec<ImportMapping>>;
+        async fn find_by_id(&self, id: uuid::Uuid) -> Result<ImportMapping>;
+        async fn insert(&self, item: ImportMapping) -> Result<ImportMapping>;
+        async fn update(&self, item: ImportMapping) -> Result<ImportMapping>;
+        async fn delete(&self, item: ImportMapping) -> Result<ImportMapping>;
+    }
+    #[async_trait]
+    impl ImportMappingService for ImportMappingService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<ImportMapping>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<ImportMapping>>;
+    }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        accounts::Movement,
+        currencies::Currency,
+        investments::{Price, Trade},
+    },
+    domain::error::Result,
+};
+
+#[async_trait]
+pub trait TradeService: Send + Sync {
+    async fn find_trade(&self, id: Uuid, account_id: Uuid) -> Result<Trade>;
+    async fn find_trades(&self, account_id: Uuid) -> Result<Vec<Trade>>;
+    async fn is_trade_movement(&self, movement_id: Uuid) -> Result<bool>;
+    async fn insert_trade(&self, trade: Trade, movement: Movement) -> Result<Trade>;
+    async fn delete_trade(&self, trade: Trade) -> Result<Trade>;
+}
+
+#[async_trait]
+pub trait PriceService: Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Price>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Price>>;
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        currency: Currency,
+        date: NaiveDate,
+    ) -> Result<Vec<Price>>;
+    async fn upsert_many(&self, prices: Vec<Price>) -> Result<Vec<Price>>;
+    async fn delete(&self, price: Price) -> Result<Price>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub TradeService {}
+    #[async_trait]
+    impl TradeService for TradeService {
+        async fn find_trade(&self, id: Uuid, account_id: Uuid) -> Result<Trade>;
+        async fn find_trades(&self, account_id: Uuid) -> Result<Vec<Trade>>;
+        async fn is_trade_movement(&self, movement_id: Uuid) -> Result<bool>;
+        async fn insert_trade(&self, trade: Trade, movement: Movement) -> Result<Trade>;
+        async fn delete_trade(&self, trade: Trade) -> Result<Trade>;
+    }
+}
+
+#[cfg(test)]
+mock! {
+    pub PriceService {}
+    #[async_trait]
+    impl PriceService for PriceService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Price>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Price>>;
+        async fn find_latest(
+            &self,
+            user_id: Uuid,
+            currency: Currency,
+            date: NaiveDate,
+        ) -> Result<Vec<Price>>;
+        async fn upsert_many(&self, prices: Vec<Price>) -> Result<Vec<Price>>;
+        async fn delete(&self, price: Price) -> Result<Price>;
+    }
+}
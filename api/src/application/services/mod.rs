@@ -5,8 +5,18 @@ use serde::Serialize;
 use crate::domain::error::Result;
 
 pub mod accounts;
+pub mod analytics;
+pub mod budgets;
+pub mod categories;
+pub mod exchange_rates;
+pub mod goals;
+pub mod imports;
+pub mod investments;
 pub mod mail;
 pub mod otp;
+pub mod reconciliations;
+pub mod recurring_movements;
+pub mod rules;
 pub mod tokens;
 pub mod users;
 
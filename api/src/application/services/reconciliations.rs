@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::Repository;
+use crate::{
+    domain::entities::{accounts::Movement, reconciliations::Reconciliation},
+    domain::error::Result,
+};
+
+#[async_trait]
+pub trait ReconciliationService: Repository<Reconciliation> + Send + Sync {
+    async fn find_open_by_account_id(&self, account_id: Uuid) -> Result<Reconciliation>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Reconciliation>>;
+    async fn find_cleared_balance(&self, account_id: Uuid, date: NaiveDate) -> Result<Decimal>;
+    async fn find_unreconciled_movements(
+        &self,
+        account_id: Uuid,
+        date: NaiveDate,
+    ) -> Result<Vec<Movement>>;
+    async fn finish(&self, reconciliation: Reconciliation) -> Result<Reconciliation>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub ReconciliationService {}
+    #[async_trait]
+    impl Repository<Reconciliation> for ReconciliationService {
+        async fn get_all(&self) -> Result<Vec<Reconciliation>>;
+        async fn find_by_id(&self, id: uuid::Uuid) -> Result<Reconciliation>;
+        async fn insert(&self, item: Reconciliation) -> Result<Reconciliation>;
+        async fn update(&self, item: Reconciliation) -> Result<Reconciliation>;
+        async fn delete(&self, item: Reconciliation) -> Result<Reconciliation>;
+    }
+    #[async_trait]
+    impl ReconciliationService for ReconciliationService {
+        async fn find_open_by_account_id(&self, account_id: Uuid) -> Result<Reconciliation>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Reconciliation>>;
+        async fn find_cleared_balance(&self, account_id: Uuid, date: NaiveDate) -> Result<Decimal>;
+        async fn find_unreconciled_movements(
+            &self,
+            account_id: Uuid,
+            date: NaiveDate,
+        ) -> Result<Vec<Movement>>;
+        async fn finish(&self, reconciliation: Reconciliation) -> Result<Reconciliation>;
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use super::Repository;
+use crate::{
+    domain::entities::{accounts::Movement, recurring_movements::RecurringMovement},
+    domain::error::Result,
+};
+
+#[async_trait]
+pub trait RecurringMovementService: Repository<RecurringMovement> + Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<RecurringMovement>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<RecurringMovement>>;
+    async fn find_due(&self, date: NaiveDate) -> Result<Vec<RecurringMovement>>;
+    async fn materialise(
+        &self,
+        recurring_movement: RecurringMovement,
+        movement: Movement,
+        next_date: NaiveDate,
+    ) -> Result<Option<Movement>>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub RecurringMovementService {}
+    #[async_trait]
+    impl Repository<RecurringMovement> for RecurringMovementService {
+        async fn get_all(&self) -> Result<Vec<RecurringMovement>>;
+        async fn find_by_id(&self, id: uuid::Uuid) -> Result<RecurringMovement>;
+        async fn insert(&self, item: RecurringMovement) -> Result<RecurringMovement>;
+        async fn update(&self, item: RecurringMovement) -> Result<RecurringMovement>;
+        async fn delete(&self, item: RecurringMovement) -> Result<RecurringMovement>;
+    }
+    #[async_trait]
+    impl RecurringMovementService for RecurringMovementService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<RecurringMovement>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<RecurringMovement>>;
+        async fn find_due(&self, date: NaiveDate) -> Result<Vec<RecurringMovement>>;
+        async fn materialise(
+            &self,
+            recurring_movement: RecurringMovement,
+            movement: Movement,
+            next_date: NaiveDate,
+        ) -> Result<Option<Movement>>;
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::Repository;
+use crate::{domain::entities::rules::Rule, domain::error::Result};
+
+#[async_trait]
+pub trait RuleService: Repository<Rule> + Send + Sync {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Rule>;
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Rule>>;
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub RuleService {}
+    #[async_trait]
+    impl Repository<Rule> for RuleService {
+        async fn get_all(&self) -> Result<Vec<Rule>>;
+        async fn find_by_id(&self, id: uuid::Uuid) -> Result<Rule>;
+        async fn insert(&self, item: Rule) -> Result<Rule>;
+        async fn update(&self, item: Rule) -> Result<Rule>;
+        async fn delete(&self, item: Rule) -> Result<Rule>;
+    }
+    #[async_trait]
+    impl RuleService for RuleService {
+        async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Rule>;
+        async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Rule>>;
+    }
+}
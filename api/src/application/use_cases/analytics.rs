@@ -0,0 +1,758 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::application::services::{
+    accounts::AccountService, analytics::AnalyticsService, exchange_rates::ExchangeRateService,
+    users::UserService,
+};
+use crate::domain::entities::accounts::normalize_tags;
+use crate::domain::entities::analytics::{
+    Analytics, AnalyticsCategory, AnalyticsPeriod, AnalyticsTitle, BalanceSeries, Interval,
+};
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+#[async_trait]
+pub trait AnalyticsUseCaseTrait: Send + Sync {
+    #[allow(clippy::too_many_arguments)]
+    async fn get_analytics(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+        top: i64,
+    ) -> Result<Analytics>;
+    async fn get_balance_series(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+    ) -> Result<BalanceSeries>;
+}
+
+const MAX_ANALYTICS_PERIODS: usize = 1000;
+
+pub struct AnalyticsUseCase {
+    analytics_service: Box<dyn AnalyticsService>,
+    account_service: Box<dyn AccountService>,
+    user_service: Box<dyn UserService>,
+    exchange_rate_service: Box<dyn ExchangeRateService>,
+}
+
+impl AnalyticsUseCase {
+    pub fn new(
+        analytics_service: Box<dyn AnalyticsService>,
+        account_service: Box<dyn AccountService>,
+        user_service: Box<dyn UserService>,
+        exchange_rate_service: Box<dyn ExchangeRateService>,
+    ) -> Self {
+        Self {
+            analytics_service,
+            account_service,
+            user_service,
+            exchange_rate_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AnalyticsUseCaseTrait for AnalyticsUseCase {
+    #[allow(clippy::too_many_arguments)]
+    async fn get_analytics(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+        top: i64,
+    ) -> Result<Analytics> {
+        if from > to {
+            return Err(Error::Validation(anyhow!("from must not be after to")));
+        }
+        let tag = tag
+            .map(|tag| normalize_tags(vec![tag]))
+            .transpose()?
+            .and_then(|tags| tags.into_iter().next());
+        let end = to
+            .checked_add_days(Days::new(1))
+            .ok_or(Error::Validation(anyhow!("invalid to")))?;
+        let periods = interval.periods(from, end);
+        if periods.len() > MAX_ANALYTICS_PERIODS {
+            return Err(Error::Validation(anyhow!(
+                "too many periods, use a longer interval"
+            )));
+        }
+        let currency = match account_id {
+            Some(account_id) => {
+                self.account_service
+                    .find_by_id_and_user_id(account_id, user_id)
+                    .await?
+                    .currency
+            }
+            None => {
+                self.user_service
+                    .find_by_id(user_id)
+                    .await?
+                    .preferred_currency
+            }
+        };
+
+        let period_totals = self
+            .analytics_service
+            .find_period_totals(user_id, account_id, tag.clone(), from, end, interval)
+            .await?;
+        let category_totals = self
+            .analytics_service
+            .find_category_totals(user_id, account_id, tag.clone(), from, end)
+            .await?;
+        let title_totals = self
+            .analytics_service
+            .find_top_titles(user_id, account_id, tag, from, end, top)
+            .await?;
+
+        let mut rates = HashMap::from([(currency, Decimal::from(1))]);
+        for item_currency in period_totals
+            .iter()
+            .map(|total| total.currency)
+            .chain(category_totals.iter().map(|total| total.currency))
+            .chain(title_totals.iter().map(|total| total.currency))
+        {
+            if rates.contains_key(&item_currency) {
+                continue;
+            }
+            let rate = self
+                .exchange_rate_service
+                .find_latest(user_id, item_currency, currency, to)
+                .await
+                .map_err(|err| match err {
+                    Error::Repository(RepositoryErrorType::NotFound) => {
+                        Error::Validation(anyhow!("no exchange rate to the preferred currency"))
+                    }
+                    err => err,
+                })?;
+            rates.insert(item_currency, rate.rate);
+        }
+
+        let mut by_period: HashMap<NaiveDate, (Decimal, Decimal)> = HashMap::new();
+        for total in period_totals {
+            let rate = rates[&total.currency];
+            let entry = by_period.entry(total.period).or_default();
+            entry.0 += total.income * rate;
+            entry.1 += total.expense * rate;
+        }
+        let periods: Vec<AnalyticsPeriod> = periods
+            .into_iter()
+            .map(|period| {
+                let (income, expense) = by_period.remove(&period).unwrap_or_default();
+                let (income, expense) = (currency.round(income), currency.round(expense));
+                AnalyticsPeriod {
+                    period,
+                    income,
+                    expense,
+                    net: income - expense,
+                }
+            })
+            .collect();
+
+        let mut categories: Vec<AnalyticsCategory> = vec![];
+        for total in category_totals {
+            let rate = rates[&total.currency];
+            let index = match categories
+                .iter()
+                .position(|category| category.category_id == total.category_id)
+            {
+                Some(index) => index,
+                None => {
+                    categories.push(AnalyticsCategory {
+                        category_id: total.category_id,
+                        income: Decimal::from(0),
+                        expense: Decimal::from(0),
+                    });
+                    categories.len() - 1
+                }
+            };
+            categories[index].income += total.income * rate;
+            categories[index].expense += total.expense * rate;
+        }
+        for category in categories.iter_mut() {
+            category.income = currency.round(category.income);
+            category.expense = currency.round(category.expense);
+        }
+        categories.sort_by(|a, b| b.expense.cmp(&a.expense).then(b.income.cmp(&a.income)));
+
+        let mut top_titles: Vec<AnalyticsTitle> = vec![];
+        for total in title_totals {
+            let rate = rates[&total.currency];
+            match top_titles
+                .iter_mut()
+                .find(|title| title.title == total.title)
+            {
+                Some(title) => {
+                    title.count += total.count;
+                    title.expense += total.expense * rate;
+                }
+                None => top_titles.push(AnalyticsTitle {
+                    title: total.title,
+                    count: total.count,
+                    expense: total.expense * rate,
+                }),
+            }
+        }
+        for title in top_titles.iter_mut() {
+            title.expense = currency.round(title.expense);
+        }
+        top_titles.sort_by(|a, b| b.expense.cmp(&a.expense).then(a.title.cmp(&b.title)));
+        top_titles.truncate(top.try_into().unwrap_or_default());
+
+        // Totals are the sum of the rounded periods, so that they match the chart
+        let income: Decimal = periods.iter().map(|period| period.income).sum();
+        let expense: Decimal = periods.iter().map(|period| period.expense).sum();
+        Ok(Analytics {
+            currency,
+            from,
+            to,
+            interval,
+            income,
+            expense,
+            net: income - expense,
+            periods,
+            categories,
+            top_titles,
+        })
+    }
+
+    async fn get_balance_series(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+    ) -> Result<BalanceSeries> {
+        if from > to {
+            return Err(Error::Validation(anyhow!("from must not be after to")));
+        }
+        let (from, to) = (interval.start(from), interval.start(to));
+        let periods = interval.periods(from, to).len() + 1;
+        if periods > MAX_ANALYTICS_PERIODS {
+            return Err(Error::Validation(anyhow!(
+                "too many periods, use a longer interval"
+            )));
+        }
+        let account = self
+            .account_service
+            .find_by_id_and_user_id(account_id, user_id)
+            .await?;
+
+        let balances = self
+            .analytics_service
+            .find_balances(account.id, from, to, interval)
+            .await?;
+        Ok(BalanceSeries {
+            account_id: account.id,
+            currency: account.currency,
+            interval,
+            balances,
+        })
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub AnalyticsUseCase {}
+    #[async_trait]
+    impl AnalyticsUseCaseTrait for AnalyticsUseCase {
+        #[allow(clippy::too_many_arguments)]
+        async fn get_analytics(
+            &self,
+            user_id: Uuid,
+            account_id: Option<Uuid>,
+            tag: Option<String>,
+            from: NaiveDate,
+            to: NaiveDate,
+            interval: Interval,
+            top: i64,
+        ) -> Result<Analytics>;
+        async fn get_balance_series(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            from: NaiveDate,
+            to: NaiveDate,
+            interval: Interval,
+        ) -> Result<BalanceSeries>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService, analytics::MockAnalyticsService,
+        exchange_rates::MockExchangeRateService, users::MockUserService,
+    };
+    use crate::domain::entities::accounts::{Account, AccountKind, AccountTerms};
+    use crate::domain::entities::analytics::{
+        BalancePoint, CategoryTotal, PeriodTotal, TitleTotal,
+    };
+    use crate::domain::entities::currencies::Currency;
+    use crate::domain::entities::exchange_rates::ExchangeRate;
+    use crate::domain::entities::users::User;
+
+    fn get_mock_use_case(
+        analytics_service: MockAnalyticsService,
+        account_service: MockAccountService,
+        user_service: MockUserService,
+        exchange_rate_service: MockExchangeRateService,
+    ) -> AnalyticsUseCase {
+        AnalyticsUseCase {
+            analytics_service: Box::new(analytics_service),
+            account_service: Box::new(account_service),
+            user_service: Box::new(user_service),
+            exchange_rate_service: Box::new(exchange_rate_service),
+        }
+    }
+
+    fn get_analytics_service(user_id: Uuid) -> MockAnalyticsService {
+        let january = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let march = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap();
+        let mut analytics_service = MockAnalyticsService::new();
+        analytics_service
+            .expect_find_period_totals()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(None),
+                predicate::eq(None),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 1, 15).unwrap()),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 4, 1).unwrap()),
+                predicate::eq(Interval::Month),
+            )
+            .return_once(move |_, _, _, _, _, _| {
+                Ok(vec![
+                    PeriodTotal {
+                        period: january,
+                        currency: Currency::EUR,
+                        income: Decimal::from(0),
+                        expense: Decimal::new(1005, 1),
+                    },
+                    PeriodTotal {
+                        period: january,
+                        currency: Currency::USD,
+                        income: Decimal::from(1000),
+                        expense: Decimal::from(50),
+                    },
+                    PeriodTotal {
+                        period: march,
+                        currency: Currency::USD,
+                        income: Decimal::from(0),
+                        expense: Decimal::from(20),
+                    },
+                ])
+            });
+        analytics_service
+            .expect_find_category_totals()
+            .return_once(move |_, _, _, _, _| {
+                Ok(vec![
+                    CategoryTotal {
+                        category_id: None,
+                        currency: Currency::USD,
+                        income: Decimal::from(1000),
+                        expense: Decimal::from(20),
+                    },
+                    CategoryTotal {
+                        category_id: Some(user_id),
+                        currency: Currency::USD,
+                        income: Decimal::from(0),
+                        expense: Decimal::from(50),
+                    },
+                    CategoryTotal {
+                        category_id: None,
+                        currency: Currency::EUR,
+                        income: Decimal::from(0),
+                        expense: Decimal::new(1005, 1),
+                    },
+                ])
+            });
+        analytics_service
+            .expect_find_top_titles()
+            .with(
+                predicate::always(),
+                predicate::always(),
+                predicate::always(),
+                predicate::always(),
+                predicate::always(),
+                predicate::eq(2),
+            )
+            .return_once(move |_, _, _, _, _, _| {
+                Ok(vec![
+                    TitleTotal {
+                        title: "Grocery".to_string(),
+                        currency: Currency::EUR,
+                        count: 2,
+                        expense: Decimal::new(1005, 1),
+                    },
+                    TitleTotal {
+                        title: "Grocery".to_string(),
+                        currency: Currency::USD,
+                        count: 1,
+                        expense: Decimal::from(50),
+                    },
+                    TitleTotal {
+                        title: "Bakery".to_string(),
+                        currency: Currency::USD,
+                        count: 3,
+                        expense: Decimal::from(20),
+                    },
+                ])
+            });
+        analytics_service
+    }
+
+    #[tokio::test]
+    async fn get_analytics_successful() {
+        let user_id = Uuid::new_v4();
+
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_find_by_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| {
+                Ok(User {
+                    id: user_id,
+                    email: "somebody@somebody.com".to_string(),
+                    preferred_currency: Currency::USD,
+                })
+            });
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_find_latest()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(Currency::EUR),
+                predicate::eq(Currency::USD),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 3, 31).unwrap()),
+            )
+            .times(1)
+            .returning(move |_, from, to, date| {
+                Ok(ExchangeRate {
+                    id: Uuid::new_v4(),
+                    user_id,
+                    date,
+                    from,
+                    to,
+                    rate: Decimal::new(11, 1),
+                })
+            });
+
+        let use_case = get_mock_use_case(
+            get_analytics_service(user_id),
+            MockAccountService::new(),
+            user_service,
+            exchange_rate_service,
+        );
+
+        let analytics = use_case
+            .get_analytics(
+                user_id,
+                None,
+                None,
+                NaiveDate::from_ymd_opt(2023, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+                Interval::Month,
+                2,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            analytics,
+            Analytics {
+                currency: Currency::USD,
+                from: NaiveDate::from_ymd_opt(2023, 1, 15).unwrap(),
+                to: NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+                interval: Interval::Month,
+                income: Decimal::from(1000),
+                expense: Decimal::new(18055, 2),
+                net: Decimal::new(81945, 2),
+                periods: vec![
+                    AnalyticsPeriod {
+                        period: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                        income: Decimal::from(1000),
+                        expense: Decimal::new(16055, 2),
+                        net: Decimal::new(83945, 2),
+                    },
+                    AnalyticsPeriod {
+                        period: NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                        income: Decimal::from(0),
+                        expense: Decimal::from(0),
+                        net: Decimal::from(0),
+                    },
+                    AnalyticsPeriod {
+                        period: NaiveDate::from_ymd_opt(2023, 3, 1).unwrap(),
+                        income: Decimal::from(0),
+                        expense: Decimal::from(20),
+                        net: Decimal::from(-20),
+                    },
+                ],
+                categories: vec![
+                    AnalyticsCategory {
+                        category_id: None,
+                        income: Decimal::from(1000),
+                        expense: Decimal::new(13055, 2),
+                    },
+                    AnalyticsCategory {
+                        category_id: Some(user_id),
+                        income: Decimal::from(0),
+                        expense: Decimal::from(50),
+                    },
+                ],
+                top_titles: vec![
+                    AnalyticsTitle {
+                        title: "Grocery".to_string(),
+                        count: 3,
+                        expense: Decimal::new(16055, 2),
+                    },
+                    AnalyticsTitle {
+                        title: "Bakery".to_string(),
+                        count: 3,
+                        expense: Decimal::from(20),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn get_analytics_account() {
+        let user_id = Uuid::new_v4();
+        let account_id = Uuid::new_v4();
+        let day = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    balance: Decimal::from(0),
+                    currency: Currency::EUR,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        let mut analytics_service = MockAnalyticsService::new();
+        analytics_service
+            .expect_find_period_totals()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(Some(account_id)),
+                predicate::eq(Some("food".to_string())),
+                predicate::eq(day),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 1, 16).unwrap()),
+                predicate::eq(Interval::Day),
+            )
+            .return_once(move |_, _, _, _, _, _| {
+                Ok(vec![PeriodTotal {
+                    period: day,
+                    currency: Currency::EUR,
+                    income: Decimal::from(5),
+                    expense: Decimal::from(0),
+                }])
+            });
+        analytics_service
+            .expect_find_category_totals()
+            .return_once(|_, _, _, _, _| Ok(vec![]));
+        analytics_service
+            .expect_find_top_titles()
+            .return_once(|_, _, _, _, _, _| Ok(vec![]));
+
+        let use_case = get_mock_use_case(
+            analytics_service,
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateService::new(),
+        );
+
+        let analytics = use_case
+            .get_analytics(
+                user_id,
+                Some(account_id),
+                Some(" Food ".to_string()),
+                day,
+                day,
+                Interval::Day,
+                10,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(analytics.currency, Currency::EUR);
+        assert_eq!(analytics.net, Decimal::from(5));
+        assert_eq!(analytics.periods.len(), 1);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn get_analytics_invalid_range() {
+        let use_case = get_mock_use_case(
+            MockAnalyticsService::new(),
+            MockAccountService::new(),
+            MockUserService::new(),
+            MockExchangeRateService::new(),
+        );
+
+        use_case
+            .get_analytics(
+                Uuid::new_v4(),
+                None,
+                None,
+                NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                Interval::Month,
+                10,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn get_analytics_too_many_periods() {
+        let use_case = get_mock_use_case(
+            MockAnalyticsService::new(),
+            MockAccountService::new(),
+            MockUserService::new(),
+            MockExchangeRateService::new(),
+        );
+
+        use_case
+            .get_analytics(
+                Uuid::new_v4(),
+                None,
+                None,
+                NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                Interval::Day,
+                10,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_balance_series_successful() {
+        let user_id = Uuid::new_v4();
+        let account_id = Uuid::new_v4();
+        let balances = vec![
+            BalancePoint {
+                period: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                balance: Decimal::from(100),
+            },
+            BalancePoint {
+                period: NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                balance: Decimal::from(50),
+            },
+        ];
+        let balances2 = balances.clone();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    balance: Decimal::from(50),
+                    currency: Currency::EUR,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        let mut analytics_service = MockAnalyticsService::new();
+        analytics_service
+            .expect_find_balances()
+            .with(
+                predicate::eq(account_id),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 2, 1).unwrap()),
+                predicate::eq(Interval::Month),
+            )
+            .return_once(move |_, _, _, _| Ok(balances));
+
+        let use_case = get_mock_use_case(
+            analytics_service,
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateService::new(),
+        );
+
+        let result = use_case
+            .get_balance_series(
+                user_id,
+                account_id,
+                NaiveDate::from_ymd_opt(2023, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 2, 10).unwrap(),
+                Interval::Month,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            BalanceSeries {
+                account_id,
+                currency: Currency::EUR,
+                interval: Interval::Month,
+                balances: balances2,
+            }
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn get_balance_series_too_many_periods() {
+        let use_case = get_mock_use_case(
+            MockAnalyticsService::new(),
+            MockAccountService::new(),
+            MockUserService::new(),
+            MockExchangeRateService::new(),
+        );
+
+        use_case
+            .get_balance_series(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                Interval::Day,
+            )
+            .await
+            .unwrap();
+    }
+}
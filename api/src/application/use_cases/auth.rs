@@ -6,6 +6,7 @@ use crate::application::services::otp::OtpService;
 use crate::application::services::tokens::TokenService;
 use crate::application::services::users::UserService;
 use crate::domain::entities::auth::Claims;
+use crate::domain::entities::currencies::Currency;
 use crate::domain::entities::users::User;
 use crate::domain::error::Result;
 
@@ -15,6 +16,13 @@ pub trait AuthUseCaseTrait: Send + Sync {
     async fn validate_token(&self, token: &str) -> Result<Claims>;
     async fn login(&self, email: &str, otp: &str) -> Result<String>;
     async fn signup(&self, email: &str, otp: &str) -> Result<()>;
+    async fn send_deletion_otp(&self, user_id: Uuid) -> Result<()>;
+    async fn delete_user(&self, user_id: Uuid, otp: &str) -> Result<()>;
+}
+
+// Deletion otps are keyed by user so they can't be used to login
+fn deletion_otp_key(user_id: Uuid) -> String {
+    format!("delete:{user_id}")
 }
 
 pub struct AuthUseCase {
@@ -69,10 +77,36 @@ impl AuthUseCaseTrait for AuthUseCase {
             .insert(User {
                 id: Uuid::new_v4(),
                 email: email.to_string(),
+                preferred_currency: Currency::USD,
             })
             .await?;
         Ok(())
     }
+
+    async fn send_deletion_otp(&self, user_id: Uuid) -> Result<()> {
+        let user = self.user_service.find_by_id(user_id).await?;
+        let otp = self
+            .otp_service
+            .generate_otp_for(&deletion_otp_key(user_id))
+            .await?;
+        self.mail_service
+            .send_email(
+                &user.email,
+                "Account deletion",
+                &format!("Your OTP to delete your account and all of its data is <b>{otp}</b>"),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_user(&self, user_id: Uuid, otp: &str) -> Result<()> {
+        let user = self.user_service.find_by_id(user_id).await?;
+        self.otp_service
+            .validate(&deletion_otp_key(user_id), otp)
+            .await?;
+        self.user_service.delete(user).await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -86,6 +120,8 @@ mock! {
         async fn validate_token(&self, token: &str) -> Result<Claims>;
         async fn login(&self, email: &str, otp: &str) -> Result<String>;
         async fn signup(&self, email: &str, otp: &str) -> Result<()>;
+        async fn send_deletion_otp(&self, user_id: Uuid) -> Result<()>;
+        async fn delete_user(&self, user_id: Uuid, otp: &str) -> Result<()>;
     }
 }
 
@@ -99,6 +135,7 @@ mod tests {
     use crate::application::services::otp::MockOtpService;
     use crate::application::services::tokens::MockTokenService;
     use crate::application::services::users::MockUserService;
+    use crate::domain::error::Error;
 
     fn get_mock_use_case(
         mail_service: MockMailService,
@@ -191,6 +228,7 @@ mod tests {
                 Ok(User {
                     id: user_id,
                     email: email.to_string(),
+                    preferred_currency: Currency::USD,
                 })
             });
 
@@ -231,6 +269,7 @@ mod tests {
                 Ok(User {
                     id: user_id,
                     email: email.to_string(),
+                    preferred_currency: Currency::USD,
                 })
             });
 
@@ -243,4 +282,119 @@ mod tests {
 
         use_case.signup(email, otp).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn send_deletion_otp_successful() {
+        let email = "somebody@somebody.com";
+        let otp = "123456";
+        let user_id = Uuid::new_v4();
+
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_find_by_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| {
+                Ok(User {
+                    id: user_id,
+                    email: email.to_string(),
+                    preferred_currency: Currency::USD,
+                })
+            });
+
+        let mut otp_service = MockOtpService::new();
+        otp_service
+            .expect_generate_otp_for()
+            .with(predicate::eq(format!("delete:{user_id}")))
+            .return_once(|_| Ok(otp.to_string()));
+
+        let mut mail_service = MockMailService::new();
+        mail_service
+            .expect_send_email()
+            .with(
+                predicate::eq(email),
+                predicate::eq("Account deletion"),
+                predicate::function(move |x: &str| x.contains(otp)),
+            )
+            .return_once(|_, _, _| Ok(()));
+
+        let use_case = get_mock_use_case(
+            mail_service,
+            otp_service,
+            MockTokenService::new(),
+            user_service,
+        );
+
+        use_case.send_deletion_otp(user_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_user_successful() {
+        let otp = "123456";
+        let user = User {
+            id: Uuid::new_v4(),
+            email: "somebody@somebody.com".to_string(),
+            preferred_currency: Currency::USD,
+        };
+        let user_id = user.id;
+
+        let mut otp_service = MockOtpService::new();
+        otp_service
+            .expect_validate()
+            .with(
+                predicate::eq(format!("delete:{user_id}")),
+                predicate::eq(otp),
+            )
+            .return_once(|_, _| Ok(()));
+
+        let mut user_service = MockUserService::new();
+        let user2 = user.clone();
+        user_service
+            .expect_find_by_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(user2));
+        user_service
+            .expect_delete()
+            .with(predicate::eq(user))
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            MockMailService::new(),
+            otp_service,
+            MockTokenService::new(),
+            user_service,
+        );
+
+        use_case.delete_user(user_id, otp).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn delete_user_invalid_otp() {
+        let user = User {
+            id: Uuid::new_v4(),
+            email: "somebody@somebody.com".to_string(),
+            preferred_currency: Currency::USD,
+        };
+        let user_id = user.id;
+
+        let mut otp_service = MockOtpService::new();
+        otp_service
+            .expect_validate()
+            .return_once(|_, _| Err(Error::Validation(anyhow::anyhow!("invalid otp"))));
+
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_find_by_id()
+            .return_once(move |_| Ok(user));
+        user_service.expect_delete().never();
+
+        let use_case = get_mock_use_case(
+            MockMailService::new(),
+            otp_service,
+            MockTokenService::new(),
+            user_service,
+        );
+
+        use_case.delete_user(user_id, "000000").await.unwrap();
+    }
 }
@@ -0,0 +1,555 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{Datelike, Months, NaiveDate};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::application::services::{
+    budgets::BudgetService, categories::CategoryService, exchange_rates::ExchangeRateService,
+};
+use crate::domain::entities::budgets::{Budget, BudgetReport, BudgetReportEntry, Spending};
+use crate::domain::entities::categories::Category;
+use crate::domain::entities::currencies::Currency;
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+#[async_trait]
+pub trait BudgetUseCaseTrait: Send + Sync {
+    async fn get_budgets(&self, user_id: Uuid, month: Option<NaiveDate>) -> Result<Vec<Budget>>;
+    async fn set_budget(
+        &self,
+        user_id: Uuid,
+        category_id: Uuid,
+        month: NaiveDate,
+        amount: Decimal,
+        currency: Currency,
+        rollover: bool,
+    ) -> Result<Budget>;
+    async fn delete_budget(&self, user_id: Uuid, budget_id: Uuid) -> Result<Budget>;
+    async fn get_budget_report(
+        &self,
+        user_id: Uuid,
+        month: NaiveDate,
+        threshold: u32,
+    ) -> Result<BudgetReport>;
+}
+
+fn previous_budget<'a>(budgets: &'a [Budget], budget: &Budget) -> Option<&'a Budget> {
+    let month = budget.month.checked_sub_months(Months::new(1))?;
+    budgets
+        .iter()
+        .find(|previous| previous.category_id == budget.category_id && previous.month == month)
+}
+
+fn with_subcategories(category_id: Uuid, categories: &[Category]) -> Vec<Uuid> {
+    let mut ids = vec![category_id];
+    let mut i = 0;
+    while i < ids.len() {
+        let parent_id = ids[i];
+        ids.extend(
+            categories
+                .iter()
+                .filter(|category| category.parent_id == Some(parent_id))
+                .map(|category| category.id),
+        );
+        i += 1;
+    }
+    ids
+}
+
+pub struct BudgetUseCase {
+    budget_service: Box<dyn BudgetService>,
+    category_service: Box<dyn CategoryService>,
+    exchange_rate_service: Box<dyn ExchangeRateService>,
+}
+
+impl BudgetUseCase {
+    pub fn new(
+        budget_service: Box<dyn BudgetService>,
+        category_service: Box<dyn CategoryService>,
+        exchange_rate_service: Box<dyn ExchangeRateService>,
+    ) -> Self {
+        Self {
+            budget_service,
+            category_service,
+            exchange_rate_service,
+        }
+    }
+
+    async fn get_spent(
+        &self,
+        user_id: Uuid,
+        spending: &[Spending],
+        category_ids: &[Uuid],
+        month: NaiveDate,
+        currency: Currency,
+    ) -> Result<Decimal> {
+        let last_day = month
+            .checked_add_months(Months::new(1))
+            .and_then(|date| date.pred_opt())
+            .ok_or(Error::Validation(anyhow!("invalid month")))?;
+
+        let mut spent = Decimal::from(0);
+        for item in spending
+            .iter()
+            .filter(|item| item.month == month && category_ids.contains(&item.category_id))
+        {
+            spent += match item.currency == currency {
+                true => item.amount,
+                false => {
+                    let rate = self
+                        .exchange_rate_service
+                        .find_latest(user_id, item.currency, currency, last_day)
+                        .await
+                        .map_err(|err| match err {
+                            Error::Repository(RepositoryErrorType::NotFound) => Error::Validation(
+                                anyhow!("no exchange rate to the budget currency"),
+                            ),
+                            err => err,
+                        })?;
+                    item.amount * rate.rate
+                }
+            };
+        }
+        Ok(currency.round(spent))
+    }
+}
+
+#[async_trait]
+impl BudgetUseCaseTrait for BudgetUseCase {
+    async fn get_budgets(&self, user_id: Uuid, month: Option<NaiveDate>) -> Result<Vec<Budget>> {
+        let month = month.and_then(|month| month.with_day(1));
+        let budgets = self
+            .budget_service
+            .find_many_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter(|budget| month.is_none() || month == Some(budget.month))
+            .collect();
+        Ok(budgets)
+    }
+
+    async fn set_budget(
+        &self,
+        user_id: Uuid,
+        category_id: Uuid,
+        month: NaiveDate,
+        amount: Decimal,
+        currency: Currency,
+        rollover: bool,
+    ) -> Result<Budget> {
+        if amount.is_sign_negative() {
+            return Err(Error::Validation(anyhow!(
+                "budget amount must not be negative"
+            )));
+        }
+        let amount = currency.validate_amount(amount)?;
+        self.category_service
+            .find_by_id_and_user_id(category_id, user_id)
+            .await?;
+
+        let budget = self
+            .budget_service
+            .upsert(Budget {
+                id: Uuid::new_v4(),
+                user_id,
+                category_id,
+                month: month.with_day(1).unwrap_or(month),
+                amount,
+                currency,
+                rollover,
+            })
+            .await?;
+        Ok(budget)
+    }
+
+    async fn delete_budget(&self, user_id: Uuid, budget_id: Uuid) -> Result<Budget> {
+        let budget = self
+            .budget_service
+            .find_by_id_and_user_id(budget_id, user_id)
+            .await?;
+        let budget = self.budget_service.delete(budget).await?;
+        Ok(budget)
+    }
+
+    async fn get_budget_report(
+        &self,
+        user_id: Uuid,
+        month: NaiveDate,
+        threshold: u32,
+    ) -> Result<BudgetReport> {
+        let month = month.with_day(1).unwrap_or(month);
+        let next_month = month
+            .checked_add_months(Months::new(1))
+            .ok_or(Error::Validation(anyhow!("invalid month")))?;
+        let budgets: Vec<Budget> = self
+            .budget_service
+            .find_many_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter(|budget| budget.month <= month)
+            .collect();
+        let categories = self.category_service.find_many_by_user_id(user_id).await?;
+
+        let mut chains = vec![];
+        for budget in budgets.iter().filter(|budget| budget.month == month) {
+            let mut chain = vec![budget];
+            while let Some(previous) = chain
+                .last()
+                .filter(|budget| budget.rollover)
+                .and_then(|budget| previous_budget(&budgets, budget))
+            {
+                chain.push(previous);
+            }
+            chain.reverse();
+            chains.push(chain);
+        }
+
+        let from = chains
+            .iter()
+            .filter_map(|chain| chain.first())
+            .map(|budget| budget.month)
+            .min()
+            .unwrap_or(month);
+        let spending = self
+            .budget_service
+            .find_spending(user_id, from, next_month)
+            .await?;
+
+        let mut report = BudgetReport {
+            month,
+            threshold,
+            entries: vec![],
+        };
+        for chain in chains {
+            let category_ids = with_subcategories(chain[0].category_id, &categories);
+            let mut entry: Option<BudgetReportEntry> = None;
+            for budget in chain {
+                let carried_over = match entry {
+                    Some(previous) if previous.currency == budget.currency => {
+                        previous.remaining.max(Decimal::from(0))
+                    }
+                    _ => Decimal::from(0),
+                };
+                let available = budget.amount + carried_over;
+                let spent = self
+                    .get_spent(
+                        user_id,
+                        &spending,
+                        &category_ids,
+                        budget.month,
+                        budget.currency,
+                    )
+                    .await?;
+                entry = Some(BudgetReportEntry {
+                    budget_id: budget.id,
+                    category_id: budget.category_id,
+                    currency: budget.currency,
+                    budgeted: budget.amount,
+                    carried_over,
+                    available,
+                    spent,
+                    remaining: available - spent,
+                    over_threshold: spent * Decimal::from(100)
+                        > available * Decimal::from(threshold),
+                });
+            }
+            report.entries.extend(entry);
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub BudgetUseCase {}
+    #[async_trait]
+    impl BudgetUseCaseTrait for BudgetUseCase {
+        async fn get_budgets(&self, user_id: Uuid, month: Option<NaiveDate>) -> Result<Vec<Budget>>;
+        async fn set_budget(
+            &self,
+            user_id: Uuid,
+            category_id: Uuid,
+            month: NaiveDate,
+            amount: Decimal,
+            currency: Currency,
+            rollover: bool,
+        ) -> Result<Budget>;
+        async fn delete_budget(&self, user_id: Uuid, budget_id: Uuid) -> Result<Budget>;
+        async fn get_budget_report(
+            &self,
+            user_id: Uuid,
+            month: NaiveDate,
+            threshold: u32,
+        ) -> Result<BudgetReport>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        budgets::MockBudgetService, categories::MockCategoryService,
+        exchange_rates::MockExchangeRateService,
+    };
+    use crate::domain::entities::exchange_rates::ExchangeRate;
+
+    fn get_mock_use_case(
+        budget_service: MockBudgetService,
+        category_service: MockCategoryService,
+        exchange_rate_service: MockExchangeRateService,
+    ) -> BudgetUseCase {
+        BudgetUseCase {
+            budget_service: Box::new(budget_service),
+            category_service: Box::new(category_service),
+            exchange_rate_service: Box::new(exchange_rate_service),
+        }
+    }
+
+    fn get_category(id: Uuid, user_id: Uuid, parent_id: Option<Uuid>) -> Category {
+        Category {
+            id,
+            user_id,
+            parent_id,
+            name: "name".to_string(),
+            color: None,
+            icon: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn set_budget_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(get_category(category_id, user_id, None)));
+        let mut budget_service = MockBudgetService::new();
+        budget_service
+            .expect_upsert()
+            .withf(move |x: &Budget| {
+                x.user_id == user_id
+                    && x.category_id == category_id
+                    && x.month == NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()
+                    && x.amount == Decimal::new(2505, 1)
+                    && x.rollover
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            budget_service,
+            category_service,
+            MockExchangeRateService::new(),
+        );
+
+        let result = use_case
+            .set_budget(
+                user_id,
+                category_id,
+                NaiveDate::from_ymd_opt(2023, 1, 15).unwrap(),
+                Decimal::new(2505, 1),
+                Currency::USD,
+                true,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.month, NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn set_budget_negative_amount() {
+        let use_case = get_mock_use_case(
+            MockBudgetService::new(),
+            MockCategoryService::new(),
+            MockExchangeRateService::new(),
+        );
+
+        use_case
+            .set_budget(
+                uuid::Uuid::new_v4(),
+                uuid::Uuid::new_v4(),
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                Decimal::from(-10),
+                Currency::USD,
+                false,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_budget_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let budget_id = uuid::Uuid::new_v4();
+        let budget = Budget {
+            id: budget_id,
+            user_id,
+            category_id: uuid::Uuid::new_v4(),
+            month: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            amount: Decimal::from(100),
+            currency: Currency::USD,
+            rollover: false,
+        };
+        let budget2 = budget.clone();
+        let budget3 = budget.clone();
+
+        let mut budget_service = MockBudgetService::new();
+        budget_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(budget_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(budget));
+        budget_service
+            .expect_delete()
+            .with(predicate::eq(budget2))
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            budget_service,
+            MockCategoryService::new(),
+            MockExchangeRateService::new(),
+        );
+
+        let result = use_case.delete_budget(user_id, budget_id).await.unwrap();
+
+        assert_eq!(result, budget3);
+    }
+
+    #[tokio::test]
+    async fn get_budget_report_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let parent_id = uuid::Uuid::new_v4();
+        let child_id = uuid::Uuid::new_v4();
+        let other_id = uuid::Uuid::new_v4();
+        let december = NaiveDate::from_ymd_opt(2022, 12, 1).unwrap();
+        let january = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let get_budget = |category_id, month, amount, rollover| Budget {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            category_id,
+            month,
+            amount: Decimal::from(amount),
+            currency: Currency::USD,
+            rollover,
+        };
+        let budgets = vec![
+            get_budget(parent_id, january, 100, true),
+            get_budget(other_id, january, 200, false),
+            get_budget(parent_id, december, 100, false),
+        ];
+        let budgets2 = budgets.clone();
+        let spending = vec![
+            Spending {
+                category_id: parent_id,
+                month: december,
+                currency: Currency::USD,
+                amount: Decimal::from(60),
+            },
+            Spending {
+                category_id: parent_id,
+                month: january,
+                currency: Currency::USD,
+                amount: Decimal::from(30),
+            },
+            Spending {
+                category_id: child_id,
+                month: january,
+                currency: Currency::EUR,
+                amount: Decimal::from(50),
+            },
+        ];
+
+        let mut budget_service = MockBudgetService::new();
+        budget_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(budgets));
+        budget_service
+            .expect_find_spending()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(december),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 2, 1).unwrap()),
+            )
+            .return_once(move |_, _, _| Ok(spending));
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| {
+                Ok(vec![
+                    get_category(parent_id, user_id, None),
+                    get_category(child_id, user_id, Some(parent_id)),
+                    get_category(other_id, user_id, None),
+                ])
+            });
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_find_latest()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(Currency::EUR),
+                predicate::eq(Currency::USD),
+                predicate::eq(NaiveDate::from_ymd_opt(2023, 1, 31).unwrap()),
+            )
+            .return_once(move |_, from, to, date| {
+                Ok(ExchangeRate {
+                    id: uuid::Uuid::new_v4(),
+                    user_id,
+                    date,
+                    from,
+                    to,
+                    rate: Decimal::new(11, 1),
+                })
+            });
+
+        let use_case = get_mock_use_case(budget_service, category_service, exchange_rate_service);
+
+        let result = use_case
+            .get_budget_report(user_id, january, 50)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            BudgetReport {
+                month: january,
+                threshold: 50,
+                entries: vec![
+                    BudgetReportEntry {
+                        budget_id: budgets2[0].id,
+                        category_id: parent_id,
+                        currency: Currency::USD,
+                        budgeted: Decimal::from(100),
+                        carried_over: Decimal::from(40),
+                        available: Decimal::from(140),
+                        spent: Decimal::from(85),
+                        remaining: Decimal::from(55),
+                        over_threshold: true,
+                    },
+                    BudgetReportEntry {
+                        budget_id: budgets2[1].id,
+                        category_id: other_id,
+                        currency: Currency::USD,
+                        budgeted: Decimal::from(200),
+                        carried_over: Decimal::from(0),
+                        available: Decimal::from(200),
+                        spent: Decimal::from(0),
+                        remaining: Decimal::from(200),
+                        over_threshold: false,
+                    },
+                ],
+            }
+        );
+    }
+}
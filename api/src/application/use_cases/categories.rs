@@ -0,0 +1,431 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::services::categories::CategoryService;
+use crate::domain::entities::categories::{Category, CategorySuggestion};
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+#[async_trait]
+pub trait CategoryUseCaseTrait: Send + Sync {
+    async fn get_categories(&self, user_id: Uuid) -> Result<Vec<Category>>;
+    async fn suggest_category(
+        &self,
+        user_id: Uuid,
+        title: &str,
+    ) -> Result<Option<CategorySuggestion>>;
+    async fn create_category(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        parent_id: Option<Uuid>,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<Category>;
+    async fn update_category(
+        &self,
+        user_id: Uuid,
+        category_id: Uuid,
+        name: &str,
+        parent_id: Option<Uuid>,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<Category>;
+    async fn delete_category(&self, user_id: Uuid, category_id: Uuid) -> Result<Category>;
+}
+
+pub struct CategoryUseCase {
+    category_service: Box<dyn CategoryService>,
+}
+
+impl CategoryUseCase {
+    pub fn new(category_service: Box<dyn CategoryService>) -> Self {
+        Self { category_service }
+    }
+
+    async fn validate_parent(
+        &self,
+        user_id: Uuid,
+        category_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Result<()> {
+        let Some(parent_id) = parent_id else {
+            return Ok(());
+        };
+
+        let categories = self.category_service.find_many_by_user_id(user_id).await?;
+        let mut ancestor = Some(parent_id);
+        while let Some(id) = ancestor {
+            if id == category_id {
+                return Err(Error::Validation(anyhow!(
+                    "category cannot be nested under itself"
+                )));
+            }
+            ancestor = categories
+                .iter()
+                .find(|category| category.id == id)
+                .ok_or(Error::Repository(RepositoryErrorType::NotFound))?
+                .parent_id;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CategoryUseCaseTrait for CategoryUseCase {
+    async fn get_categories(&self, user_id: Uuid) -> Result<Vec<Category>> {
+        let categories = self.category_service.find_many_by_user_id(user_id).await?;
+        Ok(categories)
+    }
+
+    async fn suggest_category(
+        &self,
+        user_id: Uuid,
+        title: &str,
+    ) -> Result<Option<CategorySuggestion>> {
+        let data = self
+            .category_service
+            .find_classifier_data(user_id, title.to_string())
+            .await?;
+        Ok(data.suggest())
+    }
+
+    async fn create_category(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        parent_id: Option<Uuid>,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<Category> {
+        let id = Uuid::new_v4();
+        self.validate_parent(user_id, id, parent_id).await?;
+        let category = self
+            .category_service
+            .insert(Category {
+                id,
+                user_id,
+                parent_id,
+                name: name.to_string(),
+                color,
+                icon,
+            })
+            .await?;
+        Ok(category)
+    }
+
+    async fn update_category(
+        &self,
+        user_id: Uuid,
+        category_id: Uuid,
+        name: &str,
+        parent_id: Option<Uuid>,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<Category> {
+        let category = self
+            .category_service
+            .find_by_id_and_user_id(category_id, user_id)
+            .await?;
+        self.validate_parent(user_id, category_id, parent_id)
+            .await?;
+        let category = self
+            .category_service
+            .update(Category {
+                parent_id,
+                name: name.to_string(),
+                color,
+                icon,
+                ..category
+            })
+            .await?;
+        Ok(category)
+    }
+
+    async fn delete_category(&self, user_id: Uuid, category_id: Uuid) -> Result<Category> {
+        let category = self
+            .category_service
+            .find_by_id_and_user_id(category_id, user_id)
+            .await?;
+        let category = self.category_service.delete(category).await?;
+        Ok(category)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub CategoryUseCase {}
+    #[async_trait]
+    impl CategoryUseCaseTrait for CategoryUseCase {
+        async fn get_categories(&self, user_id: Uuid) -> Result<Vec<Category>>;
+        async fn suggest_category(
+            &self,
+            user_id: Uuid,
+            title: &str,
+        ) -> Result<Option<CategorySuggestion>>;
+        async fn create_category(
+            &self,
+            user_id: Uuid,
+            name: &str,
+            parent_id: Option<Uuid>,
+            color: Option<String>,
+            icon: Option<String>,
+        ) -> Result<Category>;
+        async fn update_category(
+            &self,
+            user_id: Uuid,
+            category_id: Uuid,
+            name: &str,
+            parent_id: Option<Uuid>,
+            color: Option<String>,
+            icon: Option<String>,
+        ) -> Result<Category>;
+        async fn delete_category(&self, user_id: Uuid, category_id: Uuid) -> Result<Category>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::categories::MockCategoryService;
+    use crate::domain::entities::categories::{CategoryStats, ClassifierData, TokenCount};
+
+    fn get_mock_use_case(category_service: MockCategoryService) -> CategoryUseCase {
+        CategoryUseCase {
+            category_service: Box::new(category_service),
+        }
+    }
+
+    fn get_category(id: Uuid, user_id: Uuid, parent_id: Option<Uuid>) -> Category {
+        Category {
+            id,
+            user_id,
+            parent_id,
+            name: "name".to_string(),
+            color: None,
+            icon: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn get_categories_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let categories = vec![get_category(uuid::Uuid::new_v4(), user_id, None)];
+        let categories2 = categories.clone();
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(categories));
+
+        let use_case = get_mock_use_case(category_service);
+
+        let result = use_case.get_categories(user_id).await.unwrap();
+
+        assert_eq!(result, categories2);
+    }
+
+    #[tokio::test]
+    async fn suggest_category_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_classifier_data()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq("Supermarket".to_string()),
+            )
+            .return_once(move |_, _| {
+                Ok(ClassifierData {
+                    tokens: vec!["supermarket".to_string()],
+                    vocabulary: 1,
+                    categories: vec![CategoryStats {
+                        category_id,
+                        movements: 1,
+                        tokens: 1,
+                    }],
+                    token_counts: vec![TokenCount {
+                        category_id,
+                        token: "supermarket".to_string(),
+                        count: 1,
+                    }],
+                })
+            });
+
+        let use_case = get_mock_use_case(category_service);
+
+        let result = use_case
+            .suggest_category(user_id, "Supermarket")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            Some(CategorySuggestion {
+                category_id,
+                confidence: 1.0,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn create_category_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let parent_id = uuid::Uuid::new_v4();
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(vec![get_category(parent_id, user_id, None)]));
+        category_service
+            .expect_insert()
+            .withf(move |x: &Category| {
+                x.user_id == user_id
+                    && x.parent_id == Some(parent_id)
+                    && x.name == "name"
+                    && x.color == Some("#ff0000".to_string())
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(category_service);
+
+        let result = use_case
+            .create_category(
+                user_id,
+                "name",
+                Some(parent_id),
+                Some("#ff0000".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.parent_id, Some(parent_id));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn create_category_parent_not_found() {
+        let user_id = uuid::Uuid::new_v4();
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(vec![]));
+
+        let use_case = get_mock_use_case(category_service);
+
+        use_case
+            .create_category(user_id, "name", Some(uuid::Uuid::new_v4()), None, None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_category_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let category = get_category(category_id, user_id, None);
+        let updated = Category {
+            name: "new name".to_string(),
+            icon: Some("cart".to_string()),
+            ..category.clone()
+        };
+        let updated2 = updated.clone();
+        let updated3 = updated.clone();
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(category));
+        category_service
+            .expect_update()
+            .with(predicate::eq(updated))
+            .return_once(move |_| Ok(updated2));
+
+        let use_case = get_mock_use_case(category_service);
+
+        let result = use_case
+            .update_category(
+                user_id,
+                category_id,
+                "new name",
+                None,
+                None,
+                Some("cart".to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, updated3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn update_category_cycle() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let child_id = uuid::Uuid::new_v4();
+        let category = get_category(category_id, user_id, None);
+        let categories = vec![
+            category.clone(),
+            get_category(child_id, user_id, Some(category_id)),
+        ];
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(category));
+        category_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(categories));
+
+        let use_case = get_mock_use_case(category_service);
+
+        use_case
+            .update_category(user_id, category_id, "name", Some(child_id), None, None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_category_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let category = get_category(category_id, user_id, None);
+        let category2 = category.clone();
+        let category3 = category.clone();
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(category));
+        category_service
+            .expect_delete()
+            .with(predicate::eq(category2))
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(category_service);
+
+        let result = use_case
+            .delete_category(user_id, category_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result, category3);
+    }
+}
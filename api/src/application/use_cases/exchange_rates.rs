@@ -0,0 +1,284 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::application::services::exchange_rates::ExchangeRateService;
+use crate::domain::entities::currencies::Currency;
+use crate::domain::entities::exchange_rates::{ExchangeRate, Quote};
+use crate::domain::error::{Error, Result};
+
+#[async_trait]
+pub trait ExchangeRateUseCaseTrait: Send + Sync {
+    async fn get_exchange_rates(&self, user_id: Uuid) -> Result<Vec<ExchangeRate>>;
+    async fn set_exchange_rate(
+        &self,
+        user_id: Uuid,
+        date: NaiveDate,
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+    ) -> Result<ExchangeRate>;
+    async fn import_exchange_rates(&self, user_id: Uuid, csv: &str) -> Result<Vec<ExchangeRate>>;
+    async fn delete_exchange_rate(&self, user_id: Uuid, rate_id: Uuid) -> Result<ExchangeRate>;
+}
+
+pub struct ExchangeRateUseCase {
+    exchange_rate_service: Box<dyn ExchangeRateService>,
+}
+
+impl ExchangeRateUseCase {
+    pub fn new(exchange_rate_service: Box<dyn ExchangeRateService>) -> Self {
+        Self {
+            exchange_rate_service,
+        }
+    }
+
+    fn new_exchange_rate(
+        user_id: Uuid,
+        date: NaiveDate,
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+    ) -> Result<ExchangeRate> {
+        if from == to {
+            return Err(Error::Validation(anyhow!(
+                "exchange rate currencies must differ"
+            )));
+        }
+        if rate <= Decimal::from(0) {
+            return Err(Error::Validation(anyhow!("exchange rate must be positive")));
+        }
+        Ok(ExchangeRate {
+            id: Uuid::new_v4(),
+            user_id,
+            date,
+            from,
+            to,
+            rate,
+        })
+    }
+}
+
+#[async_trait]
+impl ExchangeRateUseCaseTrait for ExchangeRateUseCase {
+    async fn get_exchange_rates(&self, user_id: Uuid) -> Result<Vec<ExchangeRate>> {
+        let rates = self
+            .exchange_rate_service
+            .find_many_by_user_id(user_id)
+            .await?;
+        Ok(rates)
+    }
+
+    async fn set_exchange_rate(
+        &self,
+        user_id: Uuid,
+        date: NaiveDate,
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+    ) -> Result<ExchangeRate> {
+        let rate = Self::new_exchange_rate(user_id, date, from, to, rate)?;
+        let mut rates = self.exchange_rate_service.upsert_many(vec![rate]).await?;
+        Ok(rates.remove(0))
+    }
+
+    async fn import_exchange_rates(&self, user_id: Uuid, csv: &str) -> Result<Vec<ExchangeRate>> {
+        let mut rates = vec![];
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_bytes());
+        for (line, record) in reader.deserialize::<Quote>().enumerate() {
+            let record = record.map_err(|err| {
+                Error::Validation(anyhow!("invalid exchange rate at line {}: {err}", line + 2))
+            })?;
+            rates.push(Self::new_exchange_rate(
+                user_id,
+                record.date,
+                record.from,
+                record.to,
+                record.rate,
+            )?);
+        }
+        if rates.is_empty() {
+            return Err(Error::Validation(anyhow!("no exchange rates to import")));
+        }
+
+        let rates = self.exchange_rate_service.upsert_many(rates).await?;
+        Ok(rates)
+    }
+
+    async fn delete_exchange_rate(&self, user_id: Uuid, rate_id: Uuid) -> Result<ExchangeRate> {
+        let rate = self
+            .exchange_rate_service
+            .find_by_id_and_user_id(rate_id, user_id)
+            .await?;
+        let rate = self.exchange_rate_service.delete(rate).await?;
+        Ok(rate)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub ExchangeRateUseCase {}
+    #[async_trait]
+    impl ExchangeRateUseCaseTrait for ExchangeRateUseCase {
+        async fn get_exchange_rates(&self, user_id: Uuid) -> Result<Vec<ExchangeRate>>;
+        async fn set_exchange_rate(
+            &self,
+            user_id: Uuid,
+            date: NaiveDate,
+            from: Currency,
+            to: Currency,
+            rate: Decimal,
+        ) -> Result<ExchangeRate>;
+        async fn import_exchange_rates(&self, user_id: Uuid, csv: &str) -> Result<Vec<ExchangeRate>>;
+        async fn delete_exchange_rate(&self, user_id: Uuid, rate_id: Uuid) -> Result<ExchangeRate>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::exchange_rates::MockExchangeRateService;
+
+    fn get_mock_use_case(exchange_rate_service: MockExchangeRateService) -> ExchangeRateUseCase {
+        ExchangeRateUseCase {
+            exchange_rate_service: Box::new(exchange_rate_service),
+        }
+    }
+
+    #[tokio::test]
+    async fn set_exchange_rate_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        let rate = ExchangeRate {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            date,
+            from: Currency::USD,
+            to: Currency::EUR,
+            rate: Decimal::new(92, 2),
+        };
+        let rate2 = rate.clone();
+
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_upsert_many()
+            .withf(move |x: &Vec<ExchangeRate>| {
+                x.len() == 1 && x[0].user_id == user_id && x[0].rate == Decimal::new(92, 2)
+            })
+            .return_once(move |_| Ok(vec![rate]));
+
+        let use_case = get_mock_use_case(exchange_rate_service);
+
+        let result = use_case
+            .set_exchange_rate(
+                user_id,
+                date,
+                Currency::USD,
+                Currency::EUR,
+                Decimal::new(92, 2),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, rate2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn set_exchange_rate_same_currency() {
+        let use_case = get_mock_use_case(MockExchangeRateService::new());
+
+        use_case
+            .set_exchange_rate(
+                uuid::Uuid::new_v4(),
+                NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                Currency::USD,
+                Currency::USD,
+                Decimal::from(1),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn import_exchange_rates_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let csv = "date,from,to,rate\n2023-01-30,USD,EUR,0.92\n2023-01-31, EUR, USD, 1.08\n";
+
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_upsert_many()
+            .withf(move |x: &Vec<ExchangeRate>| {
+                x.len() == 2
+                    && x[0].date == NaiveDate::from_ymd_opt(2023, 1, 30).unwrap()
+                    && x[0].from == Currency::USD
+                    && x[1].from == Currency::EUR
+                    && x[1].rate == Decimal::new(108, 2)
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(exchange_rate_service);
+
+        let result = use_case.import_exchange_rates(user_id, csv).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "line 3")]
+    async fn import_exchange_rates_invalid() {
+        let csv = "date,from,to,rate\n2023-01-30,USD,EUR,0.92\n2023-01-31,EUR,XXX,1.08\n";
+
+        let use_case = get_mock_use_case(MockExchangeRateService::new());
+
+        use_case
+            .import_exchange_rates(uuid::Uuid::new_v4(), csv)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_exchange_rate_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let rate_id = uuid::Uuid::new_v4();
+        let rate = ExchangeRate {
+            id: rate_id,
+            user_id,
+            date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            from: Currency::USD,
+            to: Currency::EUR,
+            rate: Decimal::new(92, 2),
+        };
+        let rate2 = rate.clone();
+        let rate3 = rate.clone();
+        let rate4 = rate.clone();
+
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(rate_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(rate));
+        exchange_rate_service
+            .expect_delete()
+            .with(predicate::eq(rate2))
+            .return_once(move |_| Ok(rate3));
+
+        let use_case = get_mock_use_case(exchange_rate_service);
+
+        let result = use_case
+            .delete_exchange_rate(user_id, rate_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result, rate4);
+    }
+}
@@ -0,0 +1,511 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::investments::with_market_value;
+use crate::application::services::{
+    accounts::AccountService,
+    exchange_rates::ExchangeRateService,
+    goals::GoalService,
+    investments::{PriceService, TradeService},
+};
+use crate::domain::entities::accounts::Account;
+use crate::domain::entities::currencies::Currency;
+use crate::domain::entities::goals::{Goal, GoalProgress};
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+#[async_trait]
+pub trait GoalUseCaseTrait: Send + Sync {
+    async fn get_goals(&self, user_id: Uuid) -> Result<Vec<GoalProgress>>;
+    async fn get_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<GoalProgress>;
+    async fn create_goal(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        target_amount: Decimal,
+        currency: Currency,
+        deadline: NaiveDate,
+        account_ids: Vec<Uuid>,
+    ) -> Result<Goal>;
+    #[allow(clippy::too_many_arguments)]
+    async fn update_goal(
+        &self,
+        user_id: Uuid,
+        goal_id: Uuid,
+        name: &str,
+        target_amount: Decimal,
+        currency: Currency,
+        deadline: NaiveDate,
+        account_ids: Vec<Uuid>,
+    ) -> Result<Goal>;
+    async fn delete_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<Goal>;
+}
+
+pub struct GoalUseCase {
+    goal_service: Box<dyn GoalService>,
+    account_service: Box<dyn AccountService>,
+    exchange_rate_service: Box<dyn ExchangeRateService>,
+    trade_service: Box<dyn TradeService>,
+    price_service: Box<dyn PriceService>,
+}
+
+impl GoalUseCase {
+    pub fn new(
+        goal_service: Box<dyn GoalService>,
+        account_service: Box<dyn AccountService>,
+        exchange_rate_service: Box<dyn ExchangeRateService>,
+        trade_service: Box<dyn TradeService>,
+        price_service: Box<dyn PriceService>,
+    ) -> Self {
+        Self {
+            goal_service,
+            account_service,
+            exchange_rate_service,
+            trade_service,
+            price_service,
+        }
+    }
+
+    async fn new_goal(&self, goal: Goal) -> Result<Goal> {
+        let target_amount = goal.currency.round(goal.target_amount);
+        if target_amount <= Decimal::from(0) {
+            return Err(Error::Validation(anyhow!(
+                "target amount must be greater than 0"
+            )));
+        }
+        if goal.deadline < goal.start_date {
+            return Err(Error::Validation(anyhow!(
+                "deadline must not be before the goal start"
+            )));
+        }
+        let mut account_ids = goal.account_ids;
+        account_ids.sort();
+        account_ids.dedup();
+        for account_id in account_ids.iter() {
+            self.account_service
+                .find_by_id_and_user_id(*account_id, goal.user_id)
+                .await?;
+        }
+        Ok(Goal {
+            target_amount,
+            account_ids,
+            ..goal
+        })
+    }
+
+    async fn get_accounts(&self, user_id: Uuid) -> Result<Vec<Account>> {
+        let accounts = self.account_service.find_many_by_user_id(user_id).await?;
+        let mut displayed = vec![];
+        for account in accounts {
+            displayed.push(
+                with_market_value(
+                    self.trade_service.as_ref(),
+                    self.price_service.as_ref(),
+                    account,
+                )
+                .await?,
+            );
+        }
+        Ok(displayed)
+    }
+
+    async fn get_goal_progress(
+        &self,
+        goal: Goal,
+        accounts: &[Account],
+        today: NaiveDate,
+    ) -> Result<GoalProgress> {
+        let mut saved = Decimal::from(0);
+        for account in accounts
+            .iter()
+            .filter(|account| goal.account_ids.contains(&account.id))
+        {
+            saved += match account.currency == goal.currency {
+                true => account.balance,
+                false => {
+                    let rate = self
+                        .exchange_rate_service
+                        .find_latest(goal.user_id, account.currency, goal.currency, today)
+                        .await
+                        .map_err(|err| match err {
+                            Error::Repository(RepositoryErrorType::NotFound) => {
+                                Error::Validation(anyhow!("no exchange rate to the goal currency"))
+                            }
+                            err => err,
+                        })?;
+                    account.balance * rate.rate
+                }
+            };
+        }
+        let saved = goal.currency.round(saved);
+        Ok(GoalProgress::new(goal, saved, today))
+    }
+}
+
+#[async_trait]
+impl GoalUseCaseTrait for GoalUseCase {
+    async fn get_goals(&self, user_id: Uuid) -> Result<Vec<GoalProgress>> {
+        let goals = self.goal_service.find_many_by_user_id(user_id).await?;
+        let accounts = self.get_accounts(user_id).await?;
+        let today = Utc::now().date_naive();
+
+        let mut progress = vec![];
+        for goal in goals {
+            progress.push(self.get_goal_progress(goal, &accounts, today).await?);
+        }
+        Ok(progress)
+    }
+
+    async fn get_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<GoalProgress> {
+        let goal = self
+            .goal_service
+            .find_by_id_and_user_id(goal_id, user_id)
+            .await?;
+        let accounts = self.get_accounts(user_id).await?;
+        self.get_goal_progress(goal, &accounts, Utc::now().date_naive())
+            .await
+    }
+
+    async fn create_goal(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        target_amount: Decimal,
+        currency: Currency,
+        deadline: NaiveDate,
+        account_ids: Vec<Uuid>,
+    ) -> Result<Goal> {
+        let goal = self
+            .new_goal(Goal {
+                id: Uuid::new_v4(),
+                user_id,
+                name: name.to_string(),
+                target_amount,
+                currency,
+                start_date: Utc::now().date_naive(),
+                deadline,
+                account_ids,
+            })
+            .await?;
+        let goal = self.goal_service.insert(goal).await?;
+        Ok(goal)
+    }
+
+    async fn update_goal(
+        &self,
+        user_id: Uuid,
+        goal_id: Uuid,
+        name: &str,
+        target_amount: Decimal,
+        currency: Currency,
+        deadline: NaiveDate,
+        account_ids: Vec<Uuid>,
+    ) -> Result<Goal> {
+        let goal = self
+            .goal_service
+            .find_by_id_and_user_id(goal_id, user_id)
+            .await?;
+        let goal = self
+            .new_goal(Goal {
+                name: name.to_string(),
+                target_amount,
+                currency,
+                deadline,
+                account_ids,
+                ..goal
+            })
+            .await?;
+        let goal = self.goal_service.update(goal).await?;
+        Ok(goal)
+    }
+
+    async fn delete_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<Goal> {
+        let goal = self
+            .goal_service
+            .find_by_id_and_user_id(goal_id, user_id)
+            .await?;
+        let goal = self.goal_service.delete(goal).await?;
+        Ok(goal)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub GoalUseCase {}
+    #[async_trait]
+    impl GoalUseCaseTrait for GoalUseCase {
+        async fn get_goals(&self, user_id: Uuid) -> Result<Vec<GoalProgress>>;
+        async fn get_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<GoalProgress>;
+        async fn create_goal(
+            &self,
+            user_id: Uuid,
+            name: &str,
+            target_amount: Decimal,
+            currency: Currency,
+            deadline: NaiveDate,
+            account_ids: Vec<Uuid>,
+        ) -> Result<Goal>;
+        #[allow(clippy::too_many_arguments)]
+        async fn update_goal(
+            &self,
+            user_id: Uuid,
+            goal_id: Uuid,
+            name: &str,
+            target_amount: Decimal,
+            currency: Currency,
+            deadline: NaiveDate,
+            account_ids: Vec<Uuid>,
+        ) -> Result<Goal>;
+        async fn delete_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<Goal>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService,
+        exchange_rates::MockExchangeRateService,
+        goals::MockGoalService,
+        investments::{MockPriceService, MockTradeService},
+    };
+    use crate::domain::entities::accounts::{AccountKind, AccountTerms};
+    use crate::domain::entities::exchange_rates::ExchangeRate;
+
+    fn get_mock_use_case(
+        goal_service: MockGoalService,
+        account_service: MockAccountService,
+        exchange_rate_service: MockExchangeRateService,
+        trade_service: MockTradeService,
+        price_service: MockPriceService,
+    ) -> GoalUseCase {
+        GoalUseCase {
+            goal_service: Box::new(goal_service),
+            account_service: Box::new(account_service),
+            exchange_rate_service: Box::new(exchange_rate_service),
+            trade_service: Box::new(trade_service),
+            price_service: Box::new(price_service),
+        }
+    }
+
+    fn get_goal(user_id: Uuid, account_ids: Vec<Uuid>) -> Goal {
+        Goal {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "Emergency fund".to_string(),
+            target_amount: Decimal::from(5000),
+            currency: Currency::EUR,
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            deadline: NaiveDate::from_ymd_opt(2099, 12, 31).unwrap(),
+            account_ids,
+        }
+    }
+
+    #[tokio::test]
+    async fn get_goal_converts_balances() {
+        let user_id = uuid::Uuid::new_v4();
+        let eur_account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "savings".to_string(),
+            balance: Decimal::from(1000),
+            currency: Currency::EUR,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let usd_account = Account {
+            id: uuid::Uuid::new_v4(),
+            balance: Decimal::from(500),
+            currency: Currency::USD,
+            ..eur_account.clone()
+        };
+        let other_account = Account {
+            id: uuid::Uuid::new_v4(),
+            balance: Decimal::from(10000),
+            ..eur_account.clone()
+        };
+        let goal = get_goal(user_id, vec![eur_account.id, usd_account.id]);
+        let goal_id = goal.id;
+        let goal2 = goal.clone();
+        let rate = ExchangeRate {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            from: Currency::USD,
+            to: Currency::EUR,
+            rate: Decimal::new(8, 1),
+        };
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(vec![eur_account, usd_account, other_account]));
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_find_latest()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(Currency::USD),
+                predicate::eq(Currency::EUR),
+                predicate::always(),
+            )
+            .return_once(move |_, _, _, _| Ok(rate));
+        let mut goal_service = MockGoalService::new();
+        goal_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(goal_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(goal));
+
+        let use_case = get_mock_use_case(
+            goal_service,
+            account_service,
+            exchange_rate_service,
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case.get_goal(user_id, goal_id).await.unwrap();
+
+        assert_eq!(result.goal, goal2);
+        assert_eq!(result.saved, Decimal::from(1400));
+        assert_eq!(result.remaining, Decimal::from(3600));
+        assert_eq!(result.percent_complete, Decimal::from(28));
+    }
+
+    #[tokio::test]
+    async fn create_goal_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let deadline = NaiveDate::from_ymd_opt(2099, 12, 31).unwrap();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .times(1)
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .returning(move |_, _| {
+                Ok(Account {
+                    id: account_id,
+                    user_id,
+                    name: "savings".to_string(),
+                    balance: Decimal::from(0),
+                    currency: Currency::EUR,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        let mut goal_service = MockGoalService::new();
+        goal_service
+            .expect_insert()
+            .withf(move |goal: &Goal| {
+                goal.user_id == user_id
+                    && goal.target_amount == Decimal::new(500001, 2)
+                    && goal.account_ids == vec![account_id]
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            goal_service,
+            account_service,
+            MockExchangeRateService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .create_goal(
+                user_id,
+                "Emergency fund",
+                Decimal::new(5000009, 3),
+                Currency::EUR,
+                deadline,
+                vec![account_id, account_id],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.deadline, deadline);
+        assert_eq!(result.start_date, Utc::now().date_naive());
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_goal_deadline_passed() {
+        let user_id = uuid::Uuid::new_v4();
+
+        let use_case = get_mock_use_case(
+            MockGoalService::new(),
+            MockAccountService::new(),
+            MockExchangeRateService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .create_goal(
+                user_id,
+                "Emergency fund",
+                Decimal::from(5000),
+                Currency::EUR,
+                NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn update_goal_foreign_account() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let goal = get_goal(user_id, vec![]);
+        let goal_id = goal.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(|_, _| Err(Error::Repository(RepositoryErrorType::NotFound)));
+        let mut goal_service = MockGoalService::new();
+        goal_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(goal_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(goal));
+
+        let use_case = get_mock_use_case(
+            goal_service,
+            account_service,
+            MockExchangeRateService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .update_goal(
+                user_id,
+                goal_id,
+                "Emergency fund",
+                Decimal::from(5000),
+                Currency::EUR,
+                NaiveDate::from_ymd_opt(2099, 12, 31).unwrap(),
+                vec![account_id],
+            )
+            .await
+            .unwrap();
+    }
+}
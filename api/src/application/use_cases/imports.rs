@@ -0,0 +1,746 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{Days, NaiveTime, TimeZone, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::profile::{get_open_account, get_rule_set};
+use crate::application::services::{
+    accounts::AccountService, imports::ImportMappingService, rules::RuleService,
+};
+use crate::domain::entities::accounts::{Account, Movement, MovementFilter, MovementStatus};
+use crate::domain::entities::imports::{
+    fingerprint, CsvFormat, ImportMapping, ImportResult, ImportRow,
+};
+use crate::domain::entities::statements::StatementFormat;
+use crate::domain::error::{Error, Result};
+
+#[async_trait]
+pub trait ImportUseCaseTrait: Send + Sync {
+    async fn get_import_mappings(&self, user_id: Uuid) -> Result<Vec<ImportMapping>>;
+    async fn create_import_mapping(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        format: CsvFormat,
+    ) -> Result<ImportMapping>;
+    async fn delete_import_mapping(&self, user_id: Uuid, mapping_id: Uuid)
+        -> Result<ImportMapping>;
+    async fn preview_import(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        mapping_id: Uuid,
+        csv: &str,
+    ) -> Result<Vec<ImportRow>>;
+    async fn import_movements(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        mapping_id: Uuid,
+        csv: &str,
+    ) -> Result<ImportResult>;
+    async fn import_statement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        format: StatementFormat,
+        data: &str,
+    ) -> Result<ImportResult>;
+}
+
+pub struct ImportUseCase {
+    import_mapping_service: Box<dyn ImportMappingService>,
+    account_service: Box<dyn AccountService>,
+    rule_service: Box<dyn RuleService>,
+}
+
+impl ImportUseCase {
+    pub fn new(
+        import_mapping_service: Box<dyn ImportMappingService>,
+        account_service: Box<dyn AccountService>,
+        rule_service: Box<dyn RuleService>,
+    ) -> Self {
+        Self {
+            import_mapping_service,
+            account_service,
+            rule_service,
+        }
+    }
+
+    // Identical rows are only flagged as many times as they already appear in the account
+    async fn parse_import(
+        &self,
+        user_id: Uuid,
+        account: &Account,
+        mapping_id: Uuid,
+        csv: &str,
+    ) -> Result<Vec<ImportRow>> {
+        let mapping = self
+            .import_mapping_service
+            .find_by_id_and_user_id(mapping_id, user_id)
+            .await?;
+        let mut rows = mapping.format.parse(csv)?;
+        for row in rows.iter() {
+            account.currency.validate_amount(row.amount)?;
+        }
+        let (Some(from), Some(to)) = (
+            rows.iter().map(|row| row.date).min(),
+            rows.iter().map(|row| row.date).max(),
+        ) else {
+            return Err(Error::Validation(anyhow!("no movements to import")));
+        };
+
+        let to = to
+            .checked_add_days(Days::new(1))
+            .ok_or(Error::Validation(anyhow!("invalid date")))?;
+        let existing = self
+            .account_service
+            .find_movements(
+                account.id,
+                MovementFilter {
+                    from: Some(Utc.from_utc_datetime(&from.and_time(NaiveTime::MIN))),
+                    to: Some(Utc.from_utc_datetime(&to.and_time(NaiveTime::MIN))),
+                    ..MovementFilter::default()
+                },
+                None,
+                i64::MAX,
+            )
+            .await?;
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for movement in existing {
+            *counts
+                .entry(movement.fingerprint.unwrap_or_else(|| {
+                    fingerprint(
+                        movement.timestamp.date_naive(),
+                        movement.amount,
+                        &movement.title,
+                    )
+                }))
+                .or_default() += 1;
+        }
+        for row in rows.iter_mut() {
+            if let Some(count) = counts
+                .get_mut(&fingerprint(row.date, row.amount, &row.title))
+                .filter(|count| **count > 0)
+            {
+                *count -= 1;
+                row.duplicate = true;
+            }
+        }
+        Ok(rows)
+    }
+}
+
+#[async_trait]
+impl ImportUseCaseTrait for ImportUseCase {
+    async fn get_import_mappings(&self, user_id: Uuid) -> Result<Vec<ImportMapping>> {
+        let mappings = self
+            .import_mapping_service
+            .find_many_by_user_id(user_id)
+            .await?;
+        Ok(mappings)
+    }
+
+    async fn create_import_mapping(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        format: CsvFormat,
+    ) -> Result<ImportMapping> {
+        format.validate()?;
+        let mapping = self
+            .import_mapping_service
+            .insert(ImportMapping {
+                id: Uuid::new_v4(),
+                user_id,
+                name: name.to_string(),
+                format,
+            })
+            .await?;
+        Ok(mapping)
+    }
+
+    async fn delete_import_mapping(
+        &self,
+        user_id: Uuid,
+        mapping_id: Uuid,
+    ) -> Result<ImportMapping> {
+        let mapping = self
+            .import_mapping_service
+            .find_by_id_and_user_id(mapping_id, user_id)
+            .await?;
+        let mapping = self.import_mapping_service.delete(mapping).await?;
+        Ok(mapping)
+    }
+
+    async fn preview_import(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        mapping_id: Uuid,
+        csv: &str,
+    ) -> Result<Vec<ImportRow>> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        self.parse_import(user_id, &account, mapping_id, csv).await
+    }
+
+    async fn import_movements(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        mapping_id: Uuid,
+        csv: &str,
+    ) -> Result<ImportResult> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let rows = self
+            .parse_import(user_id, &account, mapping_id, csv)
+            .await?;
+        let (duplicates, rows): (Vec<_>, Vec<_>) = rows.into_iter().partition(|row| row.duplicate);
+
+        let rules = get_rule_set(self.rule_service.as_ref(), user_id).await?;
+        let movements: Vec<Movement> = rows
+            .into_iter()
+            .map(|row| {
+                rules.apply(Movement {
+                    id: Uuid::new_v4(),
+                    account_id,
+                    timestamp: Utc.from_utc_datetime(&row.date.and_time(NaiveTime::MIN)),
+                    fingerprint: Some(fingerprint(row.date, row.amount, &row.title)),
+                    title: row.title,
+                    amount: row.amount,
+                    category_id: None,
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Cleared,
+                    note: None,
+                    tags: vec![],
+                })
+            })
+            .collect();
+        let movements = match movements.is_empty() {
+            true => vec![],
+            false => {
+                self.account_service
+                    .insert_movements(account_id, movements)
+                    .await?
+            }
+        };
+        Ok(ImportResult {
+            movements,
+            skipped: duplicates.len(),
+        })
+    }
+
+    async fn import_statement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        format: StatementFormat,
+        data: &str,
+    ) -> Result<ImportResult> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let entries = format.parse(data)?;
+        if entries.is_empty() {
+            return Err(Error::Validation(anyhow!("no movements to import")));
+        }
+
+        let mut movements = vec![];
+        for entry in entries {
+            if let Some(currency) = entry
+                .currency
+                .filter(|currency| *currency != account.currency)
+            {
+                return Err(Error::Validation(anyhow!(
+                    "statement currency {currency} differs from the account currency"
+                )));
+            }
+            movements.push(Movement {
+                id: Uuid::new_v4(),
+                account_id,
+                timestamp: Utc.from_utc_datetime(&entry.date.and_time(NaiveTime::MIN)),
+                title: entry.title,
+                amount: account.currency.validate_amount(entry.amount)?,
+                category_id: None,
+                transfer_id: None,
+                external_id: Some(entry.external_id),
+                status: MovementStatus::Cleared,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            });
+        }
+        let rules = get_rule_set(self.rule_service.as_ref(), user_id).await?;
+        let movements: Vec<Movement> = movements
+            .into_iter()
+            .map(|movement| rules.apply(movement))
+            .collect();
+
+        let total = movements.len();
+        let movements = self
+            .account_service
+            .insert_movements(account_id, movements)
+            .await?;
+        Ok(ImportResult {
+            skipped: total - movements.len(),
+            movements,
+        })
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub ImportUseCase {}
+    #[async_trait]
+    impl ImportUseCaseTrait for ImportUseCase {
+        async fn get_import_mappings(&self, user_id: Uuid) -> Result<Vec<ImportMapping>>;
+        async fn create_import_mapping(
+            &self,
+            user_id: Uuid,
+            name: &str,
+            format: CsvFormat,
+        ) -> Result<ImportMapping>;
+        async fn delete_import_mapping(
+            &self,
+            user_id: Uuid,
+            mapping_id: Uuid,
+        ) -> Result<ImportMapping>;
+        async fn preview_import(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            mapping_id: Uuid,
+            csv: &str,
+        ) -> Result<Vec<ImportRow>>;
+        async fn import_movements(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            mapping_id: Uuid,
+            csv: &str,
+        ) -> Result<ImportResult>;
+        async fn import_statement(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            format: StatementFormat,
+            data: &str,
+        ) -> Result<ImportResult>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService, imports::MockImportMappingService, rules::MockRuleService,
+    };
+    use crate::domain::entities::accounts::{AccountKind, AccountTerms};
+    use crate::domain::entities::currencies::Currency;
+    use crate::domain::entities::rules::{Rule, RuleActions, RuleConditions};
+
+    fn get_mock_use_case(
+        import_mapping_service: MockImportMappingService,
+        account_service: MockAccountService,
+        rule_service: MockRuleService,
+    ) -> ImportUseCase {
+        ImportUseCase {
+            import_mapping_service: Box::new(import_mapping_service),
+            account_service: Box::new(account_service),
+            rule_service: Box::new(rule_service),
+        }
+    }
+
+    fn get_rule_service(user_id: Uuid, rules: Vec<Rule>) -> MockRuleService {
+        let mut rule_service = MockRuleService::new();
+        rule_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(rules));
+        rule_service
+    }
+
+    fn get_rule(user_id: Uuid, category_id: Uuid) -> Rule {
+        Rule {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "groceries".to_string(),
+            priority: 0,
+            conditions: RuleConditions {
+                title_contains: Some("grocer".to_string()),
+                ..Default::default()
+            },
+            actions: RuleActions {
+                category_id: Some(category_id),
+                add_tags: vec!["food".to_string()],
+                ..Default::default()
+            },
+        }
+    }
+
+    fn get_import_mapping(user_id: Uuid) -> ImportMapping {
+        ImportMapping {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "bank".to_string(),
+            format: CsvFormat {
+                delimiter: ';',
+                date_column: "Date".to_string(),
+                description_column: "Description".to_string(),
+                amount_column: None,
+                debit_column: Some("Debit".to_string()),
+                credit_column: Some("Credit".to_string()),
+                date_format: "%d/%m/%Y".to_string(),
+                decimal_separator: ',',
+            },
+        }
+    }
+
+    fn get_import_services(
+        user_id: Uuid,
+        account: Account,
+        mapping: ImportMapping,
+        existing: Vec<Movement>,
+    ) -> (MockAccountService, MockImportMappingService) {
+        let account_id = account.id;
+        let mapping_id = mapping.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        account_service
+            .expect_find_movements()
+            .withf(move |id, filter, cursor, _| {
+                *id == account_id
+                    && filter.from == Some(Utc.with_ymd_and_hms(2023, 1, 30, 0, 0, 0).unwrap())
+                    && filter.to == Some(Utc.with_ymd_and_hms(2023, 2, 2, 0, 0, 0).unwrap())
+                    && cursor.is_none()
+            })
+            .return_once(move |_, _, _, _| Ok(existing));
+
+        let mut import_mapping_service = MockImportMappingService::new();
+        import_mapping_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(mapping_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(mapping));
+
+        (account_service, import_mapping_service)
+    }
+
+    const IMPORT_CSV: &str = "Date;Description;Debit;Credit
+30/01/2023;Groceries;10,50;
+30/01/2023;Groceries;10,50;
+01/02/2023;Salary;;1.500,00
+";
+
+    #[tokio::test]
+    async fn preview_import_marks_duplicates() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(0),
+            currency: Currency::EUR,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account_id = account.id;
+        let mapping = get_import_mapping(user_id);
+        let mapping_id = mapping.id;
+        let existing = vec![Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: Utc.with_ymd_and_hms(2023, 1, 30, 18, 0, 0).unwrap(),
+            title: "  groceries ".to_string(),
+            amount: Decimal::new(-1050, 2),
+            category_id: Some(uuid::Uuid::new_v4()),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        }];
+        let (account_service, import_mapping_service) =
+            get_import_services(user_id, account, mapping, existing);
+
+        let use_case = get_mock_use_case(
+            import_mapping_service,
+            account_service,
+            MockRuleService::new(),
+        );
+
+        let result = use_case
+            .preview_import(user_id, account_id, mapping_id, IMPORT_CSV)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|row| (row.amount, row.duplicate))
+                .collect::<Vec<_>>(),
+            vec![
+                (Decimal::new(-1050, 2), true),
+                (Decimal::new(-1050, 2), false),
+                (Decimal::new(150000, 2), false),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn import_movements_skips_duplicates() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(0),
+            currency: Currency::EUR,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account_id = account.id;
+        let mapping = get_import_mapping(user_id);
+        let mapping_id = mapping.id;
+        let category_id = uuid::Uuid::new_v4();
+        let existing = vec![Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: Utc.with_ymd_and_hms(2023, 2, 1, 9, 0, 0).unwrap(),
+            title: "Salary".to_string(),
+            amount: Decimal::from(1500),
+            category_id: None,
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        }];
+        let (mut account_service, import_mapping_service) =
+            get_import_services(user_id, account, mapping, existing);
+        account_service
+            .expect_insert_movements()
+            .withf(move |id, movements| {
+                *id == account_id
+                    && movements.len() == 2
+                    && movements.iter().all(|movement| {
+                        movement.account_id == account_id
+                            && movement.amount == Decimal::new(-1050, 2)
+                            && movement.category_id == Some(category_id)
+                            && movement.tags == vec!["food".to_string()]
+                            && movement.timestamp
+                                == Utc.with_ymd_and_hms(2023, 1, 30, 0, 0, 0).unwrap()
+                    })
+            })
+            .return_once(|_, movements| Ok(movements));
+
+        let use_case = get_mock_use_case(
+            import_mapping_service,
+            account_service,
+            get_rule_service(user_id, vec![get_rule(user_id, category_id)]),
+        );
+
+        let result = use_case
+            .import_movements(user_id, account_id, mapping_id, IMPORT_CSV)
+            .await
+            .unwrap();
+
+        assert_eq!(result.movements.len(), 2);
+        assert_eq!(result.skipped, 1);
+    }
+
+    #[tokio::test]
+    async fn import_movements_twice_with_rename_rule() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(0),
+            currency: Currency::EUR,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account_id = account.id;
+        let mapping = get_import_mapping(user_id);
+        let mapping_id = mapping.id;
+        let mut rule = get_rule(user_id, uuid::Uuid::new_v4());
+        rule.actions.rename_title = Some("Supermarket".to_string());
+
+        let (mut account_service, import_mapping_service) =
+            get_import_services(user_id, account.clone(), mapping.clone(), vec![]);
+        account_service
+            .expect_insert_movements()
+            .return_once(|_, movements| Ok(movements));
+        let use_case = get_mock_use_case(
+            import_mapping_service,
+            account_service,
+            get_rule_service(user_id, vec![rule.clone()]),
+        );
+        let imported = use_case
+            .import_movements(user_id, account_id, mapping_id, IMPORT_CSV)
+            .await
+            .unwrap()
+            .movements;
+        assert_eq!(imported[0].title, "Supermarket");
+
+        let (account_service, import_mapping_service) =
+            get_import_services(user_id, account, mapping, imported);
+        let use_case = get_mock_use_case(
+            import_mapping_service,
+            account_service,
+            get_rule_service(user_id, vec![rule]),
+        );
+        let result = use_case
+            .import_movements(user_id, account_id, mapping_id, IMPORT_CSV)
+            .await
+            .unwrap();
+
+        assert!(result.movements.is_empty());
+        assert_eq!(result.skipped, 3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_import_mapping_invalid_format() {
+        let user_id = uuid::Uuid::new_v4();
+        let mut mapping = get_import_mapping(user_id);
+        mapping.format.amount_column = Some("Amount".to_string());
+
+        let use_case = get_mock_use_case(
+            MockImportMappingService::new(),
+            MockAccountService::new(),
+            MockRuleService::new(),
+        );
+
+        use_case
+            .create_import_mapping(user_id, "bank", mapping.format)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn import_statement_skips_imported() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account_id = account.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        account_service
+            .expect_insert_movements()
+            .withf(move |id, movements| {
+                *id == account_id
+                    && movements
+                        .iter()
+                        .map(|movement| movement.external_id.as_deref())
+                        .eq([
+                            Some("202301050001"),
+                            Some("202301310001"),
+                            Some("202301310002"),
+                        ])
+            })
+            .return_once(|_, mut movements| {
+                movements.remove(1);
+                Ok(movements)
+            });
+
+        let use_case = get_mock_use_case(
+            MockImportMappingService::new(),
+            account_service,
+            get_rule_service(user_id, vec![]),
+        );
+
+        let result = use_case
+            .import_statement(
+                user_id,
+                account_id,
+                StatementFormat::Ofx,
+                include_str!("../../../fixtures/statements/statement.ofx"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.movements.len(), 2);
+        assert_eq!(result.skipped, 1);
+        assert_eq!(
+            result.movements[0].timestamp,
+            Utc.with_ymd_and_hms(2023, 1, 5, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn import_statement_currency_mismatch() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account_id = account.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+
+        let use_case = get_mock_use_case(
+            MockImportMappingService::new(),
+            account_service,
+            MockRuleService::new(),
+        );
+
+        use_case
+            .import_statement(
+                user_id,
+                account_id,
+                StatementFormat::Camt053,
+                include_str!("../../../fixtures/statements/camt053.xml"),
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,499 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::profile::{ensure_not_reconciled, get_open_account};
+use crate::application::services::{
+    accounts::AccountService,
+    investments::{PriceService, TradeService},
+};
+use crate::domain::entities::accounts::{Account, AccountKind, Movement, MovementStatus};
+use crate::domain::entities::currencies::Currency;
+use crate::domain::entities::investments::{
+    normalize_symbol, validate_price, CostMethod, Portfolio, Price, PriceQuote, Trade, TradeKind,
+};
+use crate::domain::error::{Error, Result};
+
+#[async_trait]
+pub trait InvestmentUseCaseTrait: Send + Sync {
+    async fn get_trades(&self, user_id: Uuid, account_id: Uuid) -> Result<Vec<Trade>>;
+    #[allow(clippy::too_many_arguments)]
+    async fn create_trade(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        date: NaiveDate,
+        kind: TradeKind,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        fees: Decimal,
+    ) -> Result<Trade>;
+    async fn delete_trade(&self, user_id: Uuid, account_id: Uuid, trade_id: Uuid) -> Result<Trade>;
+    async fn get_portfolio(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        method: CostMethod,
+    ) -> Result<Portfolio>;
+    async fn get_prices(&self, user_id: Uuid) -> Result<Vec<Price>>;
+    async fn set_price(
+        &self,
+        user_id: Uuid,
+        date: NaiveDate,
+        symbol: &str,
+        currency: Currency,
+        price: Decimal,
+    ) -> Result<Price>;
+    async fn import_prices(&self, user_id: Uuid, csv: &str) -> Result<Vec<Price>>;
+    async fn delete_price(&self, user_id: Uuid, price_id: Uuid) -> Result<Price>;
+}
+
+fn ensure_investment(account: &Account) -> Result<()> {
+    match account.kind {
+        AccountKind::Investment => Ok(()),
+        _ => Err(Error::Validation(anyhow!(
+            "account is not an investment account"
+        ))),
+    }
+}
+
+pub(super) async fn with_market_value(
+    trade_service: &dyn TradeService,
+    price_service: &dyn PriceService,
+    account: Account,
+) -> Result<Account> {
+    if account.kind != AccountKind::Investment {
+        return Ok(account);
+    }
+    let portfolio = get_account_portfolio(
+        trade_service,
+        price_service,
+        &account,
+        CostMethod::default(),
+    )
+    .await?;
+    Ok(Account {
+        balance: portfolio.total,
+        ..account
+    })
+}
+
+async fn get_account_portfolio(
+    trade_service: &dyn TradeService,
+    price_service: &dyn PriceService,
+    account: &Account,
+    method: CostMethod,
+) -> Result<Portfolio> {
+    let trades = trade_service.find_trades(account.id).await?;
+    let prices = price_service
+        .find_latest(account.user_id, account.currency, Utc::now().date_naive())
+        .await?;
+    let portfolio = Portfolio::new(account, trades, &prices, method)?;
+    Ok(portfolio)
+}
+
+pub struct InvestmentUseCase {
+    trade_service: Box<dyn TradeService>,
+    price_service: Box<dyn PriceService>,
+    account_service: Box<dyn AccountService>,
+}
+
+impl InvestmentUseCase {
+    pub fn new(
+        trade_service: Box<dyn TradeService>,
+        price_service: Box<dyn PriceService>,
+        account_service: Box<dyn AccountService>,
+    ) -> Self {
+        Self {
+            trade_service,
+            price_service,
+            account_service,
+        }
+    }
+
+    fn new_price(
+        user_id: Uuid,
+        date: NaiveDate,
+        symbol: &str,
+        currency: Currency,
+        price: Decimal,
+    ) -> Result<Price> {
+        Ok(Price {
+            id: Uuid::new_v4(),
+            user_id,
+            symbol: normalize_symbol(symbol)?,
+            currency,
+            date,
+            price: validate_price(price)?,
+        })
+    }
+}
+
+#[async_trait]
+impl InvestmentUseCaseTrait for InvestmentUseCase {
+    async fn get_trades(&self, user_id: Uuid, account_id: Uuid) -> Result<Vec<Trade>> {
+        self.account_service
+            .find_by_id_and_user_id(account_id, user_id)
+            .await?;
+        let trades = self.trade_service.find_trades(account_id).await?;
+        Ok(trades)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn create_trade(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        date: NaiveDate,
+        kind: TradeKind,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        fees: Decimal,
+    ) -> Result<Trade> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        ensure_investment(&account)?;
+        if date > Utc::now().date_naive() {
+            return Err(Error::Validation(anyhow!(
+                "trade date must not be in the future"
+            )));
+        }
+        let trade = Trade {
+            id: Uuid::new_v4(),
+            account_id,
+            movement_id: Uuid::new_v4(),
+            date,
+            kind,
+            symbol: symbol.to_string(),
+            quantity,
+            price,
+            fees,
+        }
+        .validate(account.currency)?;
+
+        let mut trades = self.trade_service.find_trades(account_id).await?;
+        trades.push(trade.clone());
+        Portfolio::new(&account, trades, &[], CostMethod::default())?;
+
+        let movement = Movement {
+            id: trade.movement_id,
+            account_id,
+            timestamp: Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN)),
+            title: trade.title(),
+            category_id: None,
+            amount: trade.amount(account.currency),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let trade = self.trade_service.insert_trade(trade, movement).await?;
+        Ok(trade)
+    }
+
+    async fn delete_trade(&self, user_id: Uuid, account_id: Uuid, trade_id: Uuid) -> Result<Trade> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let trade = self.trade_service.find_trade(trade_id, account_id).await?;
+        let movement = self
+            .account_service
+            .find_movement(trade.movement_id, account_id)
+            .await?;
+        ensure_not_reconciled(&movement)?;
+
+        let trades = self
+            .trade_service
+            .find_trades(account_id)
+            .await?
+            .into_iter()
+            .filter(|other| other.id != trade.id)
+            .collect();
+        Portfolio::new(&account, trades, &[], CostMethod::default())?;
+
+        let trade = self.trade_service.delete_trade(trade).await?;
+        Ok(trade)
+    }
+
+    async fn get_portfolio(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        method: CostMethod,
+    ) -> Result<Portfolio> {
+        let account = self
+            .account_service
+            .find_by_id_and_user_id(account_id, user_id)
+            .await?;
+        ensure_investment(&account)?;
+        get_account_portfolio(
+            self.trade_service.as_ref(),
+            self.price_service.as_ref(),
+            &account,
+            method,
+        )
+        .await
+    }
+
+    async fn get_prices(&self, user_id: Uuid) -> Result<Vec<Price>> {
+        let prices = self.price_service.find_many_by_user_id(user_id).await?;
+        Ok(prices)
+    }
+
+    async fn set_price(
+        &self,
+        user_id: Uuid,
+        date: NaiveDate,
+        symbol: &str,
+        currency: Currency,
+        price: Decimal,
+    ) -> Result<Price> {
+        let price = Self::new_price(user_id, date, symbol, currency, price)?;
+        let mut prices = self.price_service.upsert_many(vec![price]).await?;
+        Ok(prices.remove(0))
+    }
+
+    async fn import_prices(&self, user_id: Uuid, csv: &str) -> Result<Vec<Price>> {
+        let mut prices = vec![];
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_bytes());
+        for (line, record) in reader.deserialize::<PriceQuote>().enumerate() {
+            let record = record.map_err(|err| {
+                Error::Validation(anyhow!("invalid price at line {}: {err}", line + 2))
+            })?;
+            prices.push(Self::new_price(
+                user_id,
+                record.date,
+                &record.symbol,
+                record.currency,
+                record.price,
+            )?);
+        }
+        if prices.is_empty() {
+            return Err(Error::Validation(anyhow!("no prices to import")));
+        }
+
+        let prices = self.price_service.upsert_many(prices).await?;
+        Ok(prices)
+    }
+
+    async fn delete_price(&self, user_id: Uuid, price_id: Uuid) -> Result<Price> {
+        let price = self
+            .price_service
+            .find_by_id_and_user_id(price_id, user_id)
+            .await?;
+        let price = self.price_service.delete(price).await?;
+        Ok(price)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub InvestmentUseCase {}
+    #[async_trait]
+    impl InvestmentUseCaseTrait for InvestmentUseCase {
+        async fn get_trades(&self, user_id: Uuid, account_id: Uuid) -> Result<Vec<Trade>>;
+        #[allow(clippy::too_many_arguments)]
+        async fn create_trade(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            date: NaiveDate,
+            kind: TradeKind,
+            symbol: &str,
+            quantity: Decimal,
+            price: Decimal,
+            fees: Decimal,
+        ) -> Result<Trade>;
+        async fn delete_trade(&self, user_id: Uuid, account_id: Uuid, trade_id: Uuid)
+            -> Result<Trade>;
+        async fn get_portfolio(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            method: CostMethod,
+        ) -> Result<Portfolio>;
+        async fn get_prices(&self, user_id: Uuid) -> Result<Vec<Price>>;
+        async fn set_price(
+            &self,
+            user_id: Uuid,
+            date: NaiveDate,
+            symbol: &str,
+            currency: Currency,
+            price: Decimal,
+        ) -> Result<Price>;
+        async fn import_prices(&self, user_id: Uuid, csv: &str) -> Result<Vec<Price>>;
+        async fn delete_price(&self, user_id: Uuid, price_id: Uuid) -> Result<Price>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService,
+        investments::{MockPriceService, MockTradeService},
+    };
+    use crate::domain::entities::accounts::AccountTerms;
+
+    fn get_mock_use_case(
+        trade_service: MockTradeService,
+        price_service: MockPriceService,
+        account_service: MockAccountService,
+    ) -> InvestmentUseCase {
+        InvestmentUseCase {
+            trade_service: Box::new(trade_service),
+            price_service: Box::new(price_service),
+            account_service: Box::new(account_service),
+        }
+    }
+
+    fn get_investment_account(user_id: Uuid) -> Account {
+        Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(500),
+            currency: Currency::USD,
+            archived: false,
+            opening_balance: Decimal::from(1500),
+            opening_date: None,
+            kind: AccountKind::Investment,
+            terms: AccountTerms::default(),
+        }
+    }
+
+    fn get_buy(account_id: Uuid, quantity: i64, price: i64) -> Trade {
+        Trade {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            movement_id: uuid::Uuid::new_v4(),
+            date: NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+            kind: TradeKind::Buy,
+            symbol: "AAPL".to_string(),
+            quantity: Decimal::from(quantity),
+            price: Decimal::from(price),
+            fees: Decimal::from(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_trade_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = get_investment_account(user_id);
+        let account_id = account.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        let mut trade_service = MockTradeService::new();
+        trade_service
+            .expect_find_trades()
+            .with(predicate::eq(account_id))
+            .return_once(|_| Ok(vec![]));
+        trade_service
+            .expect_insert_trade()
+            .withf(move |trade: &Trade, movement: &Movement| {
+                trade.symbol == "AAPL"
+                    && movement.id == trade.movement_id
+                    && movement.account_id == account_id
+                    && movement.title == "Buy 10 AAPL"
+                    && movement.category_id.is_none()
+                    && movement.amount == Decimal::from(-1005)
+            })
+            .return_once(|trade, _| Ok(trade));
+
+        let use_case = get_mock_use_case(trade_service, MockPriceService::new(), account_service);
+
+        let trade = use_case
+            .create_trade(
+                user_id,
+                account_id,
+                NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+                TradeKind::Buy,
+                " aapl",
+                Decimal::from(10),
+                Decimal::from(100),
+                Decimal::from(5),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(trade.account_id, account_id);
+        assert_eq!(trade.fees, Decimal::from(5));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_trade_oversold() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = get_investment_account(user_id);
+        let account_id = account.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        let mut trade_service = MockTradeService::new();
+        trade_service
+            .expect_find_trades()
+            .with(predicate::eq(account_id))
+            .return_once(move |_| Ok(vec![get_buy(account_id, 10, 100)]));
+
+        let use_case = get_mock_use_case(trade_service, MockPriceService::new(), account_service);
+
+        use_case
+            .create_trade(
+                user_id,
+                account_id,
+                NaiveDate::from_ymd_opt(2026, 1, 3).unwrap(),
+                TradeKind::Sell,
+                "AAPL",
+                Decimal::from(11),
+                Decimal::from(110),
+                Decimal::from(0),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn import_prices_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let csv = "date,symbol,currency,price\n2026-01-30,aapl,USD,150.25\n2026-01-30, VWCE.DE, EUR, 110\n";
+
+        let mut price_service = MockPriceService::new();
+        price_service
+            .expect_upsert_many()
+            .withf(move |x: &Vec<Price>| {
+                x.len() == 2
+                    && x[0].symbol == "AAPL"
+                    && x[0].price == Decimal::new(15025, 2)
+                    && x[1].symbol == "VWCE.DE"
+                    && x[1].currency == Currency::EUR
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            MockTradeService::new(),
+            price_service,
+            MockAccountService::new(),
+        );
+
+        let result = use_case.import_prices(user_id, csv).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+    }
+}
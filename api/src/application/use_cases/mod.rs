@@ -1,2 +1,14 @@
+pub mod analytics;
 pub mod auth;
+pub mod budgets;
+pub mod categories;
+pub mod exchange_rates;
+pub mod goals;
+pub mod imports;
+pub mod investments;
 pub mod profile;
+pub mod reconciliations;
+pub mod recurring_movements;
+pub mod rules;
+pub mod takeout;
+pub mod transfers;
@@ -1,40 +1,260 @@
+use anyhow::anyhow;
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::future;
+use futures::stream::{self, BoxStream, StreamExt};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
-use crate::application::services::accounts::AccountService;
-use crate::domain::entities::accounts::{Account, CategoryType, CurrencyType, Movement};
-use crate::domain::error::Result;
+use super::investments::with_market_value;
+use crate::application::services::{
+    accounts::AccountService,
+    analytics::AnalyticsService,
+    categories::CategoryService,
+    exchange_rates::ExchangeRateProvider,
+    investments::{PriceService, TradeService},
+    rules::RuleService,
+    users::UserService,
+};
+use crate::domain::entities::accounts::{
+    normalize_tags, Account, AccountKind, AccountTerms, BalanceDrift, Movement, MovementCursor,
+    MovementFilter, MovementPage, MovementSplit, MovementStatus, NetWorth, NetWorthEntry,
+    SplitLine, SplitMovement, Tag,
+};
+use crate::domain::entities::analytics::Interval;
+use crate::domain::entities::credit::{AmortisationSchedule, CardStatement};
+use crate::domain::entities::currencies::Currency;
+use crate::domain::entities::exports::{ExportFormat, Exporter};
+use crate::domain::entities::rules::RuleSet;
+use crate::domain::entities::users::User;
+use crate::domain::error::{Error, RepositoryErrorType, Result};
 
 #[async_trait]
 pub trait ProfileUseCaseTrait: Send + Sync {
     async fn get_accounts(&self, user_id: Uuid) -> Result<Vec<Account>>;
     async fn get_account(&self, user_id: Uuid, account_id: Uuid) -> Result<Account>;
+    #[allow(clippy::too_many_arguments)]
     async fn create_account(
         &self,
         user_id: Uuid,
         name: &str,
-        currency: CurrencyType,
+        currency: Currency,
+        opening_balance: Decimal,
+        opening_date: Option<NaiveDate>,
+        kind: AccountKind,
+        terms: AccountTerms,
     ) -> Result<Account>;
-    async fn get_movements(&self, user_id: Uuid, account_id: Uuid) -> Result<Vec<Movement>>;
+    async fn update_account(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        name: &str,
+        archived: bool,
+        terms: Option<AccountTerms>,
+    ) -> Result<Account>;
+    async fn delete_account(&self, user_id: Uuid, account_id: Uuid) -> Result<Account>;
+    async fn get_amortisation_schedule(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<AmortisationSchedule>;
+    async fn get_card_statement(&self, user_id: Uuid, account_id: Uuid) -> Result<CardStatement>;
+    async fn get_movements(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        filter: MovementFilter,
+        cursor: Option<MovementCursor>,
+        limit: u32,
+    ) -> Result<MovementPage>;
+    #[allow(clippy::too_many_arguments)]
     async fn create_movement(
         &self,
         user_id: Uuid,
         account_id: Uuid,
         title: &str,
-        category: CategoryType,
+        category_id: Uuid,
+        amount: Decimal,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<Movement>;
+    #[allow(clippy::too_many_arguments)]
+    async fn update_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
+        title: &str,
+        category_id: Uuid,
         amount: Decimal,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<Movement>;
+    async fn delete_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
     ) -> Result<Movement>;
+    async fn create_split_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        title: &str,
+        amount: Decimal,
+        lines: Vec<SplitLine>,
+    ) -> Result<SplitMovement>;
+    async fn split_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
+        lines: Vec<SplitLine>,
+    ) -> Result<SplitMovement>;
+    async fn get_net_worth(&self, user_id: Uuid) -> Result<NetWorth>;
+    async fn set_preferred_currency(&self, user_id: Uuid, currency: Currency) -> Result<User>;
+    async fn get_tags(&self, user_id: Uuid) -> Result<Vec<Tag>>;
+    async fn check_balances(&self, repair: bool) -> Result<Vec<BalanceDrift>>;
+    async fn export_movements(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        format: ExportFormat,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<BoxStream<'static, Result<String>>>;
+}
+
+pub(super) fn ensure_not_reconciled(movement: &Movement) -> Result<()> {
+    match movement.status {
+        MovementStatus::Reconciled => Err(Error::Validation(anyhow!("movement is reconciled"))),
+        _ => Ok(()),
+    }
+}
+
+fn normalize_note(note: Option<String>) -> Option<String> {
+    note.map(|note| note.trim().to_string())
+        .filter(|note| !note.is_empty())
+}
+
+pub(super) async fn get_open_account(
+    account_service: &dyn AccountService,
+    user_id: Uuid,
+    account_id: Uuid,
+) -> Result<Account> {
+    let account = account_service
+        .find_by_id_and_user_id(account_id, user_id)
+        .await?;
+    match account.archived {
+        true => Err(Error::Validation(anyhow!("account is archived"))),
+        false => Ok(account),
+    }
+}
+
+pub(super) async fn get_rule_set(rule_service: &dyn RuleService, user_id: Uuid) -> Result<RuleSet> {
+    let rules = rule_service.find_many_by_user_id(user_id).await?;
+    Ok(RuleSet::new(rules))
 }
 
 pub struct ProfileUseCase {
     account_service: Box<dyn AccountService>,
+    user_service: Box<dyn UserService>,
+    exchange_rate_provider: Box<dyn ExchangeRateProvider>,
+    category_service: Box<dyn CategoryService>,
+    analytics_service: Box<dyn AnalyticsService>,
+    rule_service: Box<dyn RuleService>,
+    trade_service: Box<dyn TradeService>,
+    price_service: Box<dyn PriceService>,
 }
 
 impl ProfileUseCase {
-    pub fn new(account_service: Box<dyn AccountService>) -> Self {
-        Self { account_service }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_service: Box<dyn AccountService>,
+        user_service: Box<dyn UserService>,
+        exchange_rate_provider: Box<dyn ExchangeRateProvider>,
+        category_service: Box<dyn CategoryService>,
+        analytics_service: Box<dyn AnalyticsService>,
+        rule_service: Box<dyn RuleService>,
+        trade_service: Box<dyn TradeService>,
+        price_service: Box<dyn PriceService>,
+    ) -> Self {
+        Self {
+            account_service,
+            user_service,
+            exchange_rate_provider,
+            category_service,
+            analytics_service,
+            rule_service,
+            trade_service,
+            price_service,
+        }
+    }
+
+    async fn find_account(&self, user_id: Uuid, account_id: Uuid) -> Result<Account> {
+        let account = self
+            .account_service
+            .find_by_id_and_user_id(account_id, user_id)
+            .await?;
+        Ok(account)
+    }
+
+    async fn get_standalone_movement(
+        &self,
+        account: &Account,
+        movement_id: Uuid,
+    ) -> Result<Movement> {
+        let movement = self
+            .account_service
+            .find_movement(movement_id, account.id)
+            .await?;
+        if movement.transfer_id.is_some() {
+            return Err(Error::Validation(anyhow!(
+                "movement belongs to a transfer, delete the transfer instead"
+            )));
+        }
+        if account.kind == AccountKind::Investment
+            && self.trade_service.is_trade_movement(movement.id).await?
+        {
+            return Err(Error::Validation(anyhow!(
+                "movement belongs to a trade, delete the trade instead"
+            )));
+        }
+        Ok(movement)
+    }
+
+    async fn new_splits(
+        &self,
+        user_id: Uuid,
+        account: &Account,
+        movement_id: Uuid,
+        amount: Decimal,
+        lines: Vec<SplitLine>,
+    ) -> Result<Vec<MovementSplit>> {
+        if lines.len() < 2 {
+            return Err(Error::Validation(anyhow!(
+                "a split movement needs at least two lines"
+            )));
+        }
+        if lines.iter().map(|line| line.amount).sum::<Decimal>() != amount {
+            return Err(Error::Validation(anyhow!(
+                "split amounts must sum up to the movement amount"
+            )));
+        }
+        let mut splits = vec![];
+        for line in lines {
+            self.category_service
+                .find_by_id_and_user_id(line.category_id, user_id)
+                .await?;
+            splits.push(MovementSplit {
+                id: Uuid::new_v4(),
+                movement_id,
+                category_id: line.category_id,
+                amount: account.currency.validate_amount(line.amount)?,
+                memo: line.memo,
+            });
+        }
+        Ok(splits)
     }
 }
 
@@ -42,64 +262,490 @@ impl ProfileUseCase {
 impl ProfileUseCaseTrait for ProfileUseCase {
     async fn get_accounts(&self, user_id: Uuid) -> Result<Vec<Account>> {
         let accounts = self.account_service.find_many_by_user_id(user_id).await?;
-        Ok(accounts)
+        let mut displayed = vec![];
+        for account in accounts {
+            displayed.push(
+                with_market_value(
+                    self.trade_service.as_ref(),
+                    self.price_service.as_ref(),
+                    account,
+                )
+                .await?,
+            );
+        }
+        Ok(displayed)
     }
 
     async fn get_account(&self, user_id: Uuid, account_id: Uuid) -> Result<Account> {
-        let account = self
-            .account_service
-            .find_by_id_and_user_id(account_id, user_id)
-            .await?;
-        Ok(account)
+        let account = self.find_account(user_id, account_id).await?;
+        with_market_value(
+            self.trade_service.as_ref(),
+            self.price_service.as_ref(),
+            account,
+        )
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn create_account(
         &self,
         user_id: Uuid,
         name: &str,
-        currency: CurrencyType,
+        currency: Currency,
+        opening_balance: Decimal,
+        opening_date: Option<NaiveDate>,
+        kind: AccountKind,
+        terms: AccountTerms,
     ) -> Result<Account> {
+        let terms = terms.validate(kind, currency)?;
+        if kind == AccountKind::Loan && opening_date.is_none() {
+            return Err(Error::Validation(anyhow!(
+                "opening_date is required for loans"
+            )));
+        }
+        let opening_balance = kind.validate_opening_balance(currency.round(opening_balance))?;
         let account = self
             .account_service
             .insert(Account {
                 id: Uuid::new_v4(),
                 user_id,
-                balance: Decimal::from(0),
+                balance: opening_balance,
                 name: name.to_string(),
                 currency,
+                archived: false,
+                opening_balance,
+                opening_date,
+                kind,
+                terms,
+            })
+            .await?;
+        Ok(account)
+    }
+
+    async fn update_account(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        name: &str,
+        archived: bool,
+        terms: Option<AccountTerms>,
+    ) -> Result<Account> {
+        let account = self.find_account(user_id, account_id).await?;
+        let terms = match terms {
+            Some(terms) => terms.validate(account.kind, account.currency)?,
+            None => account.terms,
+        };
+        let account = self
+            .account_service
+            .update_details(Account {
+                name: name.to_string(),
+                archived,
+                terms,
+                ..account
             })
             .await?;
+        with_market_value(
+            self.trade_service.as_ref(),
+            self.price_service.as_ref(),
+            account,
+        )
+        .await
+    }
+
+    async fn delete_account(&self, user_id: Uuid, account_id: Uuid) -> Result<Account> {
+        let account = self.find_account(user_id, account_id).await?;
+        if self.account_service.has_movements(account_id).await? {
+            return Err(Error::Validation(anyhow!(
+                "account has movements, archive it instead"
+            )));
+        }
+        let account = self.account_service.delete(account).await?;
         Ok(account)
     }
 
-    async fn get_movements(&self, user_id: Uuid, account_id: Uuid) -> Result<Vec<Movement>> {
-        self.get_account(user_id, account_id).await?;
-        let movements = self.account_service.find_movements(account_id).await?;
-        Ok(movements)
+    async fn get_amortisation_schedule(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<AmortisationSchedule> {
+        let account = self.find_account(user_id, account_id).await?;
+        let terms = account.terms;
+        match (
+            account.kind,
+            terms.loan_principal,
+            terms.apr,
+            terms.loan_term_months,
+            account.opening_date,
+        ) {
+            (AccountKind::Loan, Some(principal), Some(apr), Some(months), Some(start)) => {
+                Ok(AmortisationSchedule::new(
+                    account.id,
+                    account.currency,
+                    principal,
+                    apr,
+                    months.unsigned_abs(),
+                    start,
+                ))
+            }
+            _ => Err(Error::Validation(anyhow!("account is not a loan"))),
+        }
+    }
+
+    async fn get_card_statement(&self, user_id: Uuid, account_id: Uuid) -> Result<CardStatement> {
+        let account = self.find_account(user_id, account_id).await?;
+        let terms = account.terms;
+        let (credit_limit, statement_day) =
+            match (account.kind, terms.credit_limit, terms.statement_day) {
+                (AccountKind::CreditCard, Some(credit_limit), Some(statement_day)) => {
+                    (credit_limit, statement_day)
+                }
+                _ => return Err(Error::Validation(anyhow!("account is not a credit card"))),
+            };
+
+        let statement_date = CardStatement::last_statement_date(
+            statement_day.unsigned_abs().into(),
+            Utc::now().date_naive(),
+        );
+        let statement_balance = self
+            .analytics_service
+            .find_balances(account.id, statement_date, statement_date, Interval::Day)
+            .await?
+            .last()
+            .map(|point| point.balance)
+            .unwrap_or_default();
+        Ok(CardStatement::new(
+            account.id,
+            account.currency,
+            statement_date,
+            statement_balance,
+            account.balance,
+            credit_limit,
+            terms.apr.unwrap_or_default(),
+        ))
+    }
+
+    async fn get_movements(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        filter: MovementFilter,
+        cursor: Option<MovementCursor>,
+        limit: u32,
+    ) -> Result<MovementPage> {
+        self.find_account(user_id, account_id).await?;
+        let filter = MovementFilter {
+            tags: filter.tags.map(normalize_tags).transpose()?,
+            ..filter
+        };
+
+        // Fetch one more row than requested to know whether there is a next page
+        let mut movements = self
+            .account_service
+            .find_movements(account_id, filter, cursor, i64::from(limit) + 1)
+            .await?;
+
+        let next_cursor = match movements.len() > limit as usize {
+            true => {
+                movements.truncate(limit as usize);
+                movements
+                    .last()
+                    .map(|movement| MovementCursor::from(movement).to_string())
+            }
+            false => None,
+        };
+
+        let splits = self
+            .account_service
+            .find_splits(movements.iter().map(|movement| movement.id).collect())
+            .await?;
+        Ok(MovementPage {
+            movements,
+            next_cursor,
+            splits,
+        })
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn create_movement(
         &self,
         user_id: Uuid,
         account_id: Uuid,
         title: &str,
-        category: CategoryType,
+        category_id: Uuid,
         amount: Decimal,
+        note: Option<String>,
+        tags: Vec<String>,
     ) -> Result<Movement> {
-        self.get_account(user_id, account_id).await?;
-        let movement = self
-            .account_service
-            .insert_movement(Movement {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let amount = account.currency.validate_amount(amount)?;
+        let tags = normalize_tags(tags)?;
+        self.category_service
+            .find_by_id_and_user_id(category_id, user_id)
+            .await?;
+        let movement = get_rule_set(self.rule_service.as_ref(), user_id)
+            .await?
+            .apply(Movement {
                 id: Uuid::new_v4(),
                 account_id,
                 timestamp: Utc::now(),
                 title: title.to_string(),
-                category,
+                category_id: Some(category_id),
+                amount,
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: normalize_note(note),
+                tags,
+                fingerprint: None,
+            });
+        let movement = self.account_service.insert_movement(movement).await?;
+        Ok(movement)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn update_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
+        title: &str,
+        category_id: Uuid,
+        amount: Decimal,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<Movement> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let amount = account.currency.validate_amount(amount)?;
+        let tags = normalize_tags(tags)?;
+        self.category_service
+            .find_by_id_and_user_id(category_id, user_id)
+            .await?;
+        let movement = self.get_standalone_movement(&account, movement_id).await?;
+        ensure_not_reconciled(&movement)?;
+        if !self
+            .account_service
+            .find_splits(vec![movement.id])
+            .await?
+            .is_empty()
+        {
+            return Err(Error::Validation(anyhow!(
+                "movement is split, update its splits instead"
+            )));
+        }
+        let movement = self
+            .account_service
+            .update_movement(Movement {
+                title: title.to_string(),
+                category_id: Some(category_id),
                 amount,
+                note: normalize_note(note),
+                tags,
+                ..movement
             })
             .await?;
         Ok(movement)
     }
+
+    async fn delete_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
+    ) -> Result<Movement> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let movement = self.get_standalone_movement(&account, movement_id).await?;
+        ensure_not_reconciled(&movement)?;
+        let movement = self.account_service.delete_movement(movement).await?;
+        Ok(movement)
+    }
+
+    async fn create_split_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        title: &str,
+        amount: Decimal,
+        lines: Vec<SplitLine>,
+    ) -> Result<SplitMovement> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let amount = account.currency.validate_amount(amount)?;
+        let movement_id = Uuid::new_v4();
+        let splits = self
+            .new_splits(user_id, &account, movement_id, amount, lines)
+            .await?;
+        let split_movement = self
+            .account_service
+            .insert_split_movement(
+                Movement {
+                    id: movement_id,
+                    account_id,
+                    timestamp: Utc::now(),
+                    title: title.to_string(),
+                    category_id: None,
+                    amount,
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                splits,
+            )
+            .await?;
+        Ok(split_movement)
+    }
+
+    async fn split_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
+        lines: Vec<SplitLine>,
+    ) -> Result<SplitMovement> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let movement = self.get_standalone_movement(&account, movement_id).await?;
+        ensure_not_reconciled(&movement)?;
+        let splits = self
+            .new_splits(user_id, &account, movement.id, movement.amount, lines)
+            .await?;
+        let split_movement = self.account_service.update_splits(movement, splits).await?;
+        Ok(split_movement)
+    }
+
+    async fn get_net_worth(&self, user_id: Uuid) -> Result<NetWorth> {
+        let user = self.user_service.find_by_id(user_id).await?;
+        let accounts = self.get_accounts(user_id).await?;
+
+        let mut balances: Vec<(Currency, Decimal)> = vec![];
+        for account in accounts {
+            match balances
+                .iter_mut()
+                .find(|(currency, _)| *currency == account.currency)
+            {
+                Some((_, balance)) => *balance += account.balance,
+                None => balances.push((account.currency, account.balance)),
+            }
+        }
+
+        let mut net_worth = NetWorth {
+            currency: user.preferred_currency,
+            total: Decimal::from(0),
+            rate_date: None,
+            breakdown: vec![],
+        };
+        for (currency, balance) in balances {
+            let rate = match currency == user.preferred_currency {
+                true => Decimal::from(1),
+                false => {
+                    let quote = self
+                        .exchange_rate_provider
+                        .get_quote(user_id, currency, user.preferred_currency)
+                        .await
+                        .map_err(|err| match err {
+                            Error::Repository(RepositoryErrorType::NotFound) => Error::Validation(
+                                anyhow!("no exchange rate to the preferred currency"),
+                            ),
+                            err => err,
+                        })?;
+                    // The oldest rate is reported, so the total is never fresher than stated
+                    net_worth.rate_date = Some(match net_worth.rate_date {
+                        Some(date) => date.min(quote.date),
+                        None => quote.date,
+                    });
+                    quote.rate
+                }
+            };
+            let converted = user.preferred_currency.round(balance * rate);
+            net_worth.total += converted;
+            net_worth.breakdown.push(NetWorthEntry {
+                currency,
+                balance,
+                rate,
+                converted,
+            });
+        }
+
+        Ok(net_worth)
+    }
+
+    async fn set_preferred_currency(&self, user_id: Uuid, currency: Currency) -> Result<User> {
+        let user = self.user_service.find_by_id(user_id).await?;
+        let user = self
+            .user_service
+            .update(User {
+                preferred_currency: currency,
+                ..user
+            })
+            .await?;
+        Ok(user)
+    }
+
+    async fn get_tags(&self, user_id: Uuid) -> Result<Vec<Tag>> {
+        let tags = self.account_service.find_tags(user_id).await?;
+        Ok(tags)
+    }
+
+    async fn check_balances(&self, repair: bool) -> Result<Vec<BalanceDrift>> {
+        let drifts = self.account_service.find_balance_drifts().await?;
+        if repair {
+            for drift in drifts.iter() {
+                self.account_service
+                    .repair_balance(drift.account_id)
+                    .await?;
+            }
+        }
+        Ok(drifts)
+    }
+
+    async fn export_movements(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        format: ExportFormat,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<BoxStream<'static, Result<String>>> {
+        if from.is_some() && to.is_some() && from > to {
+            return Err(Error::Validation(anyhow!("from must not be after to")));
+        }
+
+        let account = self.find_account(user_id, account_id).await?;
+        let categories = self
+            .category_service
+            .find_many_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|category| (category.id, category.name))
+            .collect();
+        let balance = match to {
+            Some(to) => self.account_service.find_balance_at(account_id, to).await?,
+            None => account.balance,
+        };
+        let exporter = Exporter {
+            format,
+            account,
+            categories,
+            from,
+            to,
+            balance,
+            generated_at: Utc::now(),
+        };
+
+        let header = exporter.header()?;
+        let footer = exporter.footer();
+        let rows = self
+            .account_service
+            .stream_movements(account_id, from, to)
+            .enumerate()
+            .map(move |(index, movement)| {
+                movement.and_then(|movement| exporter.row(&movement, index))
+            });
+        Ok(stream::once(future::ready(Ok(header)))
+            .chain(rows)
+            .chain(stream::once(future::ready(Ok(footer))))
+            .boxed())
+    }
 }
 
 #[cfg(test)]
@@ -111,36 +757,162 @@ mock! {
     impl ProfileUseCaseTrait for ProfileUseCase {
         async fn get_accounts(&self, user_id: Uuid) -> Result<Vec<Account>>;
         async fn get_account(&self, user_id: Uuid, account_id: Uuid) -> Result<Account>;
+        #[allow(clippy::too_many_arguments)]
         async fn create_account(
             &self,
             user_id: Uuid,
             name: &str,
-            currency: CurrencyType,
+            currency: Currency,
+            opening_balance: Decimal,
+            opening_date: Option<NaiveDate>,
+            kind: AccountKind,
+            terms: AccountTerms,
+        ) -> Result<Account>;
+        async fn update_account(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            name: &str,
+            archived: bool,
+            terms: Option<AccountTerms>,
         ) -> Result<Account>;
-        async fn get_movements(&self, user_id: Uuid, account_id: Uuid) -> Result<Vec<Movement>>;
+        async fn delete_account(&self, user_id: Uuid, account_id: Uuid) -> Result<Account>;
+        async fn get_amortisation_schedule(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+        ) -> Result<AmortisationSchedule>;
+        async fn get_card_statement(&self, user_id: Uuid, account_id: Uuid) -> Result<CardStatement>;
+        async fn get_movements(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            filter: MovementFilter,
+            cursor: Option<MovementCursor>,
+            limit: u32,
+        ) -> Result<MovementPage>;
+        #[allow(clippy::too_many_arguments)]
         async fn create_movement(
             &self,
             user_id: Uuid,
             account_id: Uuid,
             title: &str,
-            category: CategoryType,
+            category_id: Uuid,
             amount: Decimal,
+            note: Option<String>,
+            tags: Vec<String>,
+        ) -> Result<Movement>;
+        #[allow(clippy::too_many_arguments)]
+        async fn update_movement(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            movement_id: Uuid,
+            title: &str,
+            category_id: Uuid,
+            amount: Decimal,
+            note: Option<String>,
+            tags: Vec<String>,
+        ) -> Result<Movement>;
+        async fn delete_movement(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            movement_id: Uuid,
         ) -> Result<Movement>;
+        async fn create_split_movement(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            title: &str,
+            amount: Decimal,
+            lines: Vec<SplitLine>,
+        ) -> Result<SplitMovement>;
+        async fn split_movement(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            movement_id: Uuid,
+            lines: Vec<SplitLine>,
+        ) -> Result<SplitMovement>;
+        async fn get_net_worth(&self, user_id: Uuid) -> Result<NetWorth>;
+        async fn set_preferred_currency(&self, user_id: Uuid, currency: Currency) -> Result<User>;
+        async fn get_tags(&self, user_id: Uuid) -> Result<Vec<Tag>>;
+        async fn check_balances(&self, repair: bool) -> Result<Vec<BalanceDrift>>;
+        async fn export_movements(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            format: ExportFormat,
+            from: Option<DateTime<Utc>>,
+            to: Option<DateTime<Utc>>,
+        ) -> Result<BoxStream<'static, Result<String>>>;
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use chrono::{Datelike, TimeZone};
     use mockall::predicate;
     use rust_decimal::Decimal;
     use tokio;
 
     use super::*;
-    use crate::application::services::accounts::MockAccountService;
+    use crate::application::services::{
+        accounts::MockAccountService,
+        analytics::MockAnalyticsService,
+        categories::MockCategoryService,
+        exchange_rates::MockExchangeRateProvider,
+        investments::{MockPriceService, MockTradeService},
+        rules::MockRuleService,
+        users::MockUserService,
+    };
+    use crate::domain::entities::analytics::BalancePoint;
+    use crate::domain::entities::categories::Category;
+    use crate::domain::entities::exchange_rates::Quote;
+    use crate::domain::entities::investments::{Price, Trade, TradeKind};
+    use crate::domain::entities::rules::{Rule, RuleActions, RuleConditions};
 
-    fn get_mock_use_case(accounts_service: MockAccountService) -> ProfileUseCase {
+    #[allow(clippy::too_many_arguments)]
+    fn get_mock_use_case(
+        accounts_service: MockAccountService,
+        user_service: MockUserService,
+        exchange_rate_provider: MockExchangeRateProvider,
+        category_service: MockCategoryService,
+        analytics_service: MockAnalyticsService,
+        rule_service: MockRuleService,
+        trade_service: MockTradeService,
+        price_service: MockPriceService,
+    ) -> ProfileUseCase {
         ProfileUseCase {
             account_service: Box::new(accounts_service),
+            user_service: Box::new(user_service),
+            exchange_rate_provider: Box::new(exchange_rate_provider),
+            category_service: Box::new(category_service),
+            analytics_service: Box::new(analytics_service),
+            rule_service: Box::new(rule_service),
+            trade_service: Box::new(trade_service),
+            price_service: Box::new(price_service),
+        }
+    }
+
+    fn get_rule_service(user_id: Uuid, rules: Vec<Rule>) -> MockRuleService {
+        let mut rule_service = MockRuleService::new();
+        rule_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(rules));
+        rule_service
+    }
+
+    fn get_category(id: Uuid, user_id: Uuid, parent_id: Option<Uuid>) -> Category {
+        Category {
+            id,
+            user_id,
+            parent_id,
+            name: "name".to_string(),
+            color: None,
+            icon: None,
         }
     }
 
@@ -149,10 +921,15 @@ mod tests {
         let user_id = uuid::Uuid::new_v4();
         let accounts = vec![Account {
             balance: Decimal::from(0),
-            currency: CurrencyType::Usd,
+            currency: Currency::USD,
+            archived: false,
             id: uuid::Uuid::new_v4(),
             user_id,
             name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
         }];
         let accounts2 = accounts.clone();
 
@@ -162,7 +939,16 @@ mod tests {
             .with(predicate::eq(user_id))
             .return_once(move |_| Ok(accounts));
 
-        let use_case = get_mock_use_case(account_service);
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
 
         let result = use_case.get_accounts(user_id).await.unwrap();
         assert_eq!(result, accounts2);
@@ -172,13 +958,19 @@ mod tests {
     async fn create_account_successful() {
         let user_id = uuid::Uuid::new_v4();
         let name = "name".to_string();
-        let currency = CurrencyType::Usd;
+        let currency = Currency::USD;
+        let opening_date = NaiveDate::from_ymd_opt(2023, 1, 1);
         let account = Account {
-            balance: Decimal::from(0),
-            currency: currency.clone(),
+            balance: Decimal::new(10050, 2),
+            currency,
+            archived: false,
             id: uuid::Uuid::new_v4(),
             user_id,
             name: name.clone(),
+            opening_balance: Decimal::new(10050, 2),
+            opening_date,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
         };
         let account2 = account.clone();
         let account3 = account.clone();
@@ -190,13 +982,33 @@ mod tests {
                 x.name == account.name
                     && x.currency == account.currency
                     && x.user_id == account.user_id
+                    && x.balance == account.opening_balance
+                    && x.opening_balance == account.opening_balance
+                    && x.opening_date == account.opening_date
             })
             .return_once(move |_| Ok(account2));
 
-        let use_case = get_mock_use_case(account_service);
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
 
         let result = use_case
-            .create_account(user_id, &name, currency)
+            .create_account(
+                user_id,
+                &name,
+                currency,
+                Decimal::new(100499, 3),
+                opening_date,
+                AccountKind::Checking,
+                AccountTerms::default(),
+            )
             .await
             .unwrap();
 
@@ -209,10 +1021,15 @@ mod tests {
         let account_id = uuid::Uuid::new_v4();
         let account = Account {
             balance: Decimal::from(0),
-            currency: CurrencyType::Usd,
+            currency: Currency::USD,
+            archived: false,
             id: account_id,
             user_id,
             name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
         };
         let account2 = account.clone();
 
@@ -222,7 +1039,16 @@ mod tests {
             .with(predicate::eq(account_id), predicate::eq(user_id))
             .return_once(move |_, _| Ok(account2));
 
-        let use_case = get_mock_use_case(account_service);
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
 
         let result = use_case.get_account(user_id, account_id).await.unwrap();
 
@@ -234,16 +1060,22 @@ mod tests {
         let user_id = uuid::Uuid::new_v4();
         let account_id = uuid::Uuid::new_v4();
         let title = "title".to_string();
-        let category = CategoryType::Generic;
+        let category_id = uuid::Uuid::new_v4();
         let amount = Decimal::from(0);
         let movement_id = uuid::Uuid::new_v4();
         let movement = Movement {
             account_id,
             id: movement_id,
             amount,
-            category: category.clone(),
+            category_id: Some(category_id),
             timestamp: chrono::Utc::now(),
             title: title.clone(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: Some("weekly groceries".to_string()),
+            tags: vec!["food".to_string(), "home".to_string()],
+            fingerprint: None,
         };
         let movement2 = movement.clone();
         let movement3 = movement.clone();
@@ -255,10 +1087,15 @@ mod tests {
             .return_once(move |_, _| {
                 Ok(Account {
                     balance: Decimal::from(0),
-                    currency: CurrencyType::Usd,
+                    currency: Currency::USD,
+                    archived: false,
                     id: account_id,
                     user_id,
                     name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
                 })
             });
         account_service
@@ -267,13 +1104,38 @@ mod tests {
                 x.title == movement.title
                     && x.amount == movement.amount
                     && x.account_id == movement.account_id
+                    && x.category_id == movement.category_id
+                    && x.note == movement.note
+                    && x.tags == movement.tags
             })
             .return_once(move |_| Ok(movement2));
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(get_category(category_id, user_id, None)));
 
-        let use_case = get_mock_use_case(account_service);
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            category_service,
+            MockAnalyticsService::new(),
+            get_rule_service(user_id, vec![]),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
 
         let result = use_case
-            .create_movement(user_id, account_id, &title, category, amount)
+            .create_movement(
+                user_id,
+                account_id,
+                &title,
+                category_id,
+                amount,
+                Some(" weekly groceries ".to_string()),
+                vec!["Home".to_string(), "food".to_string(), "home".to_string()],
+            )
             .await
             .unwrap();
 
@@ -288,11 +1150,25 @@ mod tests {
             account_id,
             id: uuid::Uuid::new_v4(),
             amount: Decimal::from(0),
-            category: CategoryType::Generic,
+            category_id: None,
             timestamp: chrono::Utc::now(),
             title: "title".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
         }];
         let movements2 = movements.clone();
+        let splits = vec![MovementSplit {
+            id: uuid::Uuid::new_v4(),
+            movement_id: movements[0].id,
+            category_id: uuid::Uuid::new_v4(),
+            amount: Decimal::from(0),
+            memo: None,
+        }];
+        let splits2 = splits.clone();
 
         let mut account_service = MockAccountService::new();
         account_service
@@ -301,21 +1177,1730 @@ mod tests {
             .return_once(move |_, _| {
                 Ok(Account {
                     balance: Decimal::from(0),
-                    currency: CurrencyType::Usd,
+                    currency: Currency::USD,
+                    archived: false,
                     id: account_id,
                     user_id,
                     name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
                 })
             });
         account_service
             .expect_find_movements()
-            .with(predicate::eq(account_id))
-            .return_once(move |_| Ok(movements));
+            .with(
+                predicate::eq(account_id),
+                predicate::eq(MovementFilter::default()),
+                predicate::eq(None),
+                predicate::eq(51),
+            )
+            .return_once(move |_, _, _, _| Ok(movements));
+        account_service
+            .expect_find_splits()
+            .with(predicate::eq(vec![movements2[0].id]))
+            .return_once(move |_| Ok(splits));
 
-        let use_case = get_mock_use_case(account_service);
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .get_movements(user_id, account_id, MovementFilter::default(), None, 50)
+            .await
+            .unwrap();
 
-        let result = use_case.get_movements(user_id, account_id).await.unwrap();
+        assert_eq!(
+            result,
+            MovementPage {
+                movements: movements2,
+                next_cursor: None,
+                splits: splits2
+            }
+        );
+    }
 
-        assert_eq!(result, movements2);
+    #[tokio::test]
+    async fn get_movements_next_cursor() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movements: Vec<Movement> = (0..3)
+            .map(|_| Movement {
+                account_id,
+                id: uuid::Uuid::new_v4(),
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: "title".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .collect();
+        let movements2 = movements.clone();
+        let cursor = MovementCursor {
+            timestamp: chrono::Utc::now(),
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_find_movements()
+            .with(
+                predicate::eq(account_id),
+                predicate::eq(MovementFilter::default()),
+                predicate::eq(Some(cursor.clone())),
+                predicate::eq(3),
+            )
+            .return_once(move |_, _, _, _| Ok(movements));
+        account_service
+            .expect_find_splits()
+            .with(predicate::eq(vec![movements2[0].id, movements2[1].id]))
+            .return_once(|_| Ok(vec![]));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .get_movements(
+                user_id,
+                account_id,
+                MovementFilter::default(),
+                Some(cursor),
+                2,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.movements, movements2[..2].to_vec());
+        assert_eq!(
+            result.next_cursor,
+            Some(MovementCursor::from(&movements2[1]).to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn update_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            account_id,
+            id: movement_id,
+            amount: Decimal::from(0),
+            category_id: None,
+            timestamp: chrono::Utc::now(),
+            title: "title".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let updated = Movement {
+            amount: Decimal::from(10),
+            category_id: Some(category_id),
+            title: "new title".to_string(),
+            ..movement.clone()
+        };
+        let updated2 = updated.clone();
+        let updated3 = updated.clone();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_find_movement()
+            .with(predicate::eq(movement_id), predicate::eq(account_id))
+            .return_once(move |_, _| Ok(movement));
+        account_service
+            .expect_find_splits()
+            .with(predicate::eq(vec![movement_id]))
+            .return_once(|_| Ok(vec![]));
+        account_service
+            .expect_update_movement()
+            .with(predicate::eq(updated))
+            .return_once(move |_| Ok(updated2));
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(get_category(category_id, user_id, None)));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            category_service,
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .update_movement(
+                user_id,
+                account_id,
+                movement_id,
+                "new title",
+                category_id,
+                Decimal::from(10),
+                None,
+                vec![],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, updated3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn update_movement_split() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            account_id,
+            id: movement_id,
+            amount: Decimal::from(-20),
+            category_id: None,
+            timestamp: chrono::Utc::now(),
+            title: "title".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_find_movement()
+            .return_once(move |_, _| Ok(movement));
+        account_service.expect_find_splits().return_once(move |_| {
+            Ok(vec![MovementSplit {
+                id: uuid::Uuid::new_v4(),
+                movement_id,
+                category_id,
+                amount: Decimal::from(-20),
+                memo: None,
+            }])
+        });
+        account_service.expect_update_movement().never();
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .return_once(move |_, _| Ok(get_category(category_id, user_id, None)));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            category_service,
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .update_movement(
+                user_id,
+                account_id,
+                movement_id,
+                "new title",
+                category_id,
+                Decimal::from(-20),
+                None,
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            account_id,
+            id: movement_id,
+            amount: Decimal::from(0),
+            category_id: None,
+            timestamp: chrono::Utc::now(),
+            title: "title".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let movement2 = movement.clone();
+        let movement3 = movement.clone();
+        let movement4 = movement.clone();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_find_movement()
+            .with(predicate::eq(movement_id), predicate::eq(account_id))
+            .return_once(move |_, _| Ok(movement));
+        account_service
+            .expect_delete_movement()
+            .with(predicate::eq(movement2))
+            .return_once(move |_| Ok(movement3));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .delete_movement(user_id, account_id, movement_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result, movement4);
+    }
+
+    #[tokio::test]
+    async fn update_account_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let account = Account {
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: false,
+            id: account_id,
+            user_id,
+            name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let updated = Account {
+            name: "new name".to_string(),
+            archived: true,
+            ..account.clone()
+        };
+        let updated2 = updated.clone();
+        let updated3 = updated.clone();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        account_service
+            .expect_update_details()
+            .with(predicate::eq(updated))
+            .return_once(move |_| Ok(updated2));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .update_account(user_id, account_id, "new name", true, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result, updated3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_account_loan_without_opening_date() {
+        let use_case = get_mock_use_case(
+            MockAccountService::new(),
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .create_account(
+                uuid::Uuid::new_v4(),
+                "mortgage",
+                Currency::EUR,
+                Decimal::from(-200000),
+                None,
+                AccountKind::Loan,
+                AccountTerms {
+                    apr: Some(Decimal::new(35, 1)),
+                    loan_principal: Some(Decimal::from(200000)),
+                    loan_term_months: Some(360),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_account_loan_positive_opening_balance() {
+        let mut account_service = MockAccountService::new();
+        account_service.expect_insert().never();
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .create_account(
+                uuid::Uuid::new_v4(),
+                "mortgage",
+                Currency::EUR,
+                Decimal::from(200000),
+                NaiveDate::from_ymd_opt(2026, 1, 1),
+                AccountKind::Loan,
+                AccountTerms {
+                    apr: Some(Decimal::new(35, 1)),
+                    loan_principal: Some(Decimal::from(200000)),
+                    loan_term_months: Some(360),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    fn get_credit_card(user_id: Uuid) -> Account {
+        Account {
+            balance: Decimal::from(-1500),
+            currency: Currency::EUR,
+            archived: false,
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "card".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::CreditCard,
+            terms: AccountTerms {
+                credit_limit: Some(Decimal::from(2000)),
+                apr: Some(Decimal::from(18)),
+                statement_day: Some(1),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn get_card_statement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = get_credit_card(user_id);
+        let account_id = account.id;
+        let statement_date = Utc::now().date_naive().with_day(1).unwrap();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        let mut analytics_service = MockAnalyticsService::new();
+        analytics_service
+            .expect_find_balances()
+            .with(
+                predicate::eq(account_id),
+                predicate::eq(statement_date),
+                predicate::eq(statement_date),
+                predicate::eq(Interval::Day),
+            )
+            .return_once(move |_, _, _, _| {
+                Ok(vec![BalancePoint {
+                    period: statement_date,
+                    balance: Decimal::from(-1200),
+                }])
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            analytics_service,
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .get_card_statement(user_id, account_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result.statement_date, statement_date);
+        assert_eq!(result.statement_balance, Decimal::from(1200));
+        assert_eq!(result.minimum_payment, Decimal::from(30));
+        assert_eq!(result.available_credit, Decimal::from(500));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn get_amortisation_schedule_not_a_loan() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = get_credit_card(user_id);
+        let account_id = account.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .get_amortisation_schedule(user_id, account_id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_account_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let account = Account {
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: false,
+            id: account_id,
+            user_id,
+            name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account2 = account.clone();
+        let account3 = account.clone();
+        let account4 = account.clone();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        account_service
+            .expect_has_movements()
+            .with(predicate::eq(account_id))
+            .return_once(|_| Ok(false));
+        account_service
+            .expect_delete()
+            .with(predicate::eq(account2))
+            .return_once(move |_| Ok(account3));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case.delete_account(user_id, account_id).await.unwrap();
+
+        assert_eq!(result, account4);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn delete_account_with_movements() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_has_movements()
+            .with(predicate::eq(account_id))
+            .return_once(|_| Ok(true));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case.delete_account(user_id, account_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_movement_archived_account() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: true,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .create_movement(
+                user_id,
+                account_id,
+                "title",
+                uuid::Uuid::new_v4(),
+                Decimal::from(0),
+                None,
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn delete_movement_transfer_leg() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_find_movement()
+            .with(predicate::eq(movement_id), predicate::eq(account_id))
+            .return_once(move |_, _| {
+                Ok(Movement {
+                    account_id,
+                    id: movement_id,
+                    amount: Decimal::from(10),
+                    category_id: None,
+                    timestamp: chrono::Utc::now(),
+                    title: "title".to_string(),
+                    transfer_id: Some(uuid::Uuid::new_v4()),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .delete_movement(user_id, account_id, movement_id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_net_worth_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = Account {
+            balance: Decimal::from(10),
+            currency: Currency::USD,
+            archived: false,
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let accounts = vec![
+            account.clone(),
+            Account {
+                id: uuid::Uuid::new_v4(),
+                balance: Decimal::from(5),
+                ..account.clone()
+            },
+            Account {
+                id: uuid::Uuid::new_v4(),
+                balance: Decimal::from(20),
+                currency: Currency::EUR,
+                ..account
+            },
+        ];
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(accounts));
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_find_by_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| {
+                Ok(User {
+                    id: user_id,
+                    email: "email".to_string(),
+                    preferred_currency: Currency::EUR,
+                })
+            });
+        let mut exchange_rate_provider = MockExchangeRateProvider::new();
+        exchange_rate_provider
+            .expect_get_quote()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(Currency::USD),
+                predicate::eq(Currency::EUR),
+            )
+            .return_once(|_, from, to| {
+                Ok(Quote {
+                    date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                    from,
+                    to,
+                    rate: Decimal::new(9, 1),
+                })
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            user_service,
+            exchange_rate_provider,
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case.get_net_worth(user_id).await.unwrap();
+
+        assert_eq!(
+            result,
+            NetWorth {
+                currency: Currency::EUR,
+                total: Decimal::new(335, 1),
+                rate_date: NaiveDate::from_ymd_opt(2023, 1, 31),
+                breakdown: vec![
+                    NetWorthEntry {
+                        currency: Currency::USD,
+                        balance: Decimal::from(15),
+                        rate: Decimal::new(9, 1),
+                        converted: Decimal::new(135, 1),
+                    },
+                    NetWorthEntry {
+                        currency: Currency::EUR,
+                        balance: Decimal::from(20),
+                        rate: Decimal::from(1),
+                        converted: Decimal::from(20),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn get_net_worth_missing_exchange_rate() {
+        let user_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| {
+                Ok(vec![Account {
+                    balance: Decimal::from(10),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: uuid::Uuid::new_v4(),
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                }])
+            });
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_find_by_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| {
+                Ok(User {
+                    id: user_id,
+                    email: "email".to_string(),
+                    preferred_currency: Currency::EUR,
+                })
+            });
+        let mut exchange_rate_provider = MockExchangeRateProvider::new();
+        exchange_rate_provider
+            .expect_get_quote()
+            .return_once(|_, _, _| Err(Error::Repository(RepositoryErrorType::NotFound)));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            user_service,
+            exchange_rate_provider,
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case.get_net_worth(user_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn set_preferred_currency_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let user = User {
+            id: user_id,
+            email: "email".to_string(),
+            preferred_currency: Currency::USD,
+        };
+        let updated = User {
+            preferred_currency: Currency::EUR,
+            ..user.clone()
+        };
+        let updated2 = updated.clone();
+        let updated3 = updated.clone();
+
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_find_by_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(user));
+        user_service
+            .expect_update()
+            .with(predicate::eq(updated))
+            .return_once(move |_| Ok(updated2));
+
+        let use_case = get_mock_use_case(
+            MockAccountService::new(),
+            user_service,
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .set_preferred_currency(user_id, Currency::EUR)
+            .await
+            .unwrap();
+
+        assert_eq!(result, updated3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_movement_invalid_amount_precision() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::JPY,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .create_movement(
+                user_id,
+                account_id,
+                "title",
+                uuid::Uuid::new_v4(),
+                Decimal::new(1055, 1),
+                None,
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_movement_invalid_tag() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .create_movement(
+                user_id,
+                account_id,
+                "title",
+                uuid::Uuid::new_v4(),
+                Decimal::from(10),
+                None,
+                vec!["eating out".to_string()],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn export_movements_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: true,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account_id = account.id;
+        let category = get_category(uuid::Uuid::new_v4(), user_id, None);
+        let movement = Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: Utc.with_ymd_and_hms(2023, 1, 5, 10, 30, 0).unwrap(),
+            title: "title".to_string(),
+            category_id: Some(category.id),
+            amount: Decimal::new(-5, 0),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let movement_id = movement.id;
+        let from = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        account_service
+            .expect_stream_movements()
+            .with(
+                predicate::eq(account_id),
+                predicate::eq(Some(from)),
+                predicate::eq(None),
+            )
+            .return_once(move |_, _, _| stream::iter(vec![Ok(movement)]).boxed());
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(vec![category]));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            category_service,
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result: Vec<String> = use_case
+            .export_movements(user_id, account_id, ExportFormat::Csv, Some(from), None)
+            .await
+            .unwrap()
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(
+            result.concat(),
+            format!(
+                "id,timestamp,title,amount,currency,category,transfer_id\n\
+                {movement_id},2023-01-05T10:30:00Z,title,-5.00,USD,name,\n"
+            )
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn export_movements_invalid_range() {
+        let use_case = get_mock_use_case(
+            MockAccountService::new(),
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let _ = use_case
+            .export_movements(
+                uuid::Uuid::new_v4(),
+                uuid::Uuid::new_v4(),
+                ExportFormat::Json,
+                Some(Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap()),
+                Some(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap()),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_balances_repair() {
+        let drifts = vec![BalanceDrift {
+            account_id: uuid::Uuid::new_v4(),
+            user_id: uuid::Uuid::new_v4(),
+            balance: Decimal::from(10),
+            expected: Decimal::from(5),
+        }];
+        let drifts2 = drifts.clone();
+        let account_id = drifts[0].account_id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_balance_drifts()
+            .return_once(|| Ok(drifts));
+        account_service
+            .expect_repair_balance()
+            .with(predicate::eq(account_id))
+            .times(1)
+            .returning(move |account_id| {
+                Ok(Account {
+                    id: account_id,
+                    user_id: uuid::Uuid::new_v4(),
+                    name: "name".to_string(),
+                    balance: Decimal::from(5),
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        assert_eq!(use_case.check_balances(true).await.unwrap(), drifts2);
+    }
+
+    #[tokio::test]
+    async fn check_balances_report_only() {
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_balance_drifts()
+            .return_once(|| {
+                Ok(vec![BalanceDrift {
+                    account_id: uuid::Uuid::new_v4(),
+                    user_id: uuid::Uuid::new_v4(),
+                    balance: Decimal::from(10),
+                    expected: Decimal::from(5),
+                }])
+            });
+        account_service.expect_repair_balance().never();
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        assert_eq!(use_case.check_balances(false).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn update_movement_reconciled() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: chrono::Utc::now(),
+            title: "title".to_string(),
+            category_id: Some(category_id),
+            amount: Decimal::from(10),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Reconciled,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let movement_id = movement.id;
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_find_movement()
+            .return_once(|_, _| Ok(movement));
+        account_service.expect_update_movement().never();
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .return_once(move |_, _| Ok(get_category(category_id, user_id, None)));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            category_service,
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .update_movement(
+                user_id,
+                account_id,
+                movement_id,
+                "title",
+                category_id,
+                Decimal::from(20),
+                None,
+                vec![],
+            )
+            .await
+            .unwrap();
+    }
+
+    fn get_split_lines(first: Uuid, second: Uuid) -> Vec<SplitLine> {
+        vec![
+            SplitLine {
+                category_id: first,
+                amount: Decimal::new(-1250, 2),
+                memo: Some("groceries".to_string()),
+            },
+            SplitLine {
+                category_id: second,
+                amount: Decimal::new(-750, 2),
+                memo: None,
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn create_split_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let (first, second) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_insert_split_movement()
+            .withf(move |movement, splits| {
+                movement.amount == Decimal::from(-20)
+                    && movement.category_id.is_none()
+                    && splits.len() == 2
+                    && splits.iter().all(|split| split.movement_id == movement.id)
+                    && splits[0].category_id == first
+                    && splits[1].category_id == second
+            })
+            .return_once(|movement, splits| Ok(SplitMovement { movement, splits }));
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .times(2)
+            .returning(move |id, _| Ok(get_category(id, user_id, None)));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            category_service,
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .create_split_movement(
+                user_id,
+                account_id,
+                "supermarket",
+                Decimal::from(-20),
+                get_split_lines(first, second),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.movement.title, "supermarket");
+        assert_eq!(result.splits[0].memo, Some("groceries".to_string()));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn split_movement_amount_mismatch() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: chrono::Utc::now(),
+            title: "supermarket".to_string(),
+            category_id: None,
+            amount: Decimal::from(-25),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Cleared,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let movement_id = movement.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .return_once(move |_, _| {
+                Ok(Account {
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_find_movement()
+            .with(predicate::eq(movement_id), predicate::eq(account_id))
+            .return_once(|_, _| Ok(movement));
+        account_service.expect_update_splits().never();
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        use_case
+            .split_movement(
+                user_id,
+                account_id,
+                movement_id,
+                get_split_lines(uuid::Uuid::new_v4(), uuid::Uuid::new_v4()),
+            )
+            .await
+            .unwrap();
+    }
+
+    fn get_rule(user_id: Uuid, category_id: Uuid) -> Rule {
+        Rule {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "groceries".to_string(),
+            priority: 0,
+            conditions: RuleConditions {
+                title_contains: Some("grocer".to_string()),
+                ..Default::default()
+            },
+            actions: RuleActions {
+                category_id: Some(category_id),
+                add_tags: vec!["food".to_string()],
+                ..Default::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn create_movement_applies_rules() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let generic_id = uuid::Uuid::new_v4();
+        let groceries_id = uuid::Uuid::new_v4();
+        let rule = get_rule(user_id, groceries_id);
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_insert_movement()
+            .withf(move |movement: &Movement| {
+                movement.category_id == Some(groceries_id)
+                    && movement.tags == vec!["food".to_string(), "home".to_string()]
+            })
+            .return_once(Ok);
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(generic_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(get_category(generic_id, user_id, None)));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            category_service,
+            MockAnalyticsService::new(),
+            get_rule_service(user_id, vec![rule]),
+            MockTradeService::new(),
+            MockPriceService::new(),
+        );
+
+        let result = use_case
+            .create_movement(
+                user_id,
+                account_id,
+                "Groceries",
+                generic_id,
+                Decimal::from(-20),
+                None,
+                vec!["home".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.category_id, Some(groceries_id));
+    }
+
+    fn get_investment_account(user_id: Uuid) -> Account {
+        Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(500),
+            currency: Currency::USD,
+            archived: false,
+            opening_balance: Decimal::from(1500),
+            opening_date: None,
+            kind: AccountKind::Investment,
+            terms: AccountTerms::default(),
+        }
+    }
+
+    fn get_buy(account_id: Uuid, quantity: i64, price: i64) -> Trade {
+        Trade {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            movement_id: uuid::Uuid::new_v4(),
+            date: NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+            kind: TradeKind::Buy,
+            symbol: "AAPL".to_string(),
+            quantity: Decimal::from(quantity),
+            price: Decimal::from(price),
+            fees: Decimal::from(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_account_investment_market_value() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = get_investment_account(user_id);
+        let account_id = account.id;
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        let mut trade_service = MockTradeService::new();
+        trade_service
+            .expect_find_trades()
+            .with(predicate::eq(account_id))
+            .return_once(move |_| Ok(vec![get_buy(account_id, 10, 100)]));
+        let mut price_service = MockPriceService::new();
+        price_service
+            .expect_find_latest()
+            .withf(move |id, currency, _| *id == user_id && *currency == Currency::USD)
+            .return_once(move |_, _, _| {
+                Ok(vec![Price {
+                    id: uuid::Uuid::new_v4(),
+                    user_id,
+                    symbol: "AAPL".to_string(),
+                    currency: Currency::USD,
+                    date: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+                    price: Decimal::from(120),
+                }])
+            });
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            trade_service,
+            price_service,
+        );
+
+        let account = use_case.get_account(user_id, account_id).await.unwrap();
+
+        assert_eq!(account.balance, Decimal::from(1700));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn delete_movement_trade() {
+        let user_id = uuid::Uuid::new_v4();
+        let account = get_investment_account(user_id);
+        let account_id = account.id;
+        let movement_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(account));
+        account_service
+            .expect_find_movement()
+            .with(predicate::eq(movement_id), predicate::eq(account_id))
+            .return_once(move |_, _| {
+                Ok(Movement {
+                    account_id,
+                    id: movement_id,
+                    amount: Decimal::from(-1000),
+                    category_id: None,
+                    timestamp: chrono::Utc::now(),
+                    title: "Buy 10 AAPL".to_string(),
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+            });
+        let mut trade_service = MockTradeService::new();
+        trade_service
+            .expect_is_trade_movement()
+            .with(predicate::eq(movement_id))
+            .return_once(|_| Ok(true));
+
+        let use_case = get_mock_use_case(
+            account_service,
+            MockUserService::new(),
+            MockExchangeRateProvider::new(),
+            MockCategoryService::new(),
+            MockAnalyticsService::new(),
+            MockRuleService::new(),
+            trade_service,
+            MockPriceService::new(),
+        );
+
+        use_case
+            .delete_movement(user_id, account_id, movement_id)
+            .await
+            .unwrap();
     }
 }
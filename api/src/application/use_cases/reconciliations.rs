@@ -0,0 +1,441 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::profile::{ensure_not_reconciled, get_open_account};
+use crate::application::services::{
+    accounts::AccountService, reconciliations::ReconciliationService,
+};
+use crate::domain::entities::accounts::{Movement, MovementStatus};
+use crate::domain::entities::reconciliations::{Reconciliation, ReconciliationReport};
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+#[async_trait]
+pub trait ReconciliationUseCaseTrait: Send + Sync {
+    async fn start_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        statement_date: NaiveDate,
+        statement_balance: Decimal,
+    ) -> Result<ReconciliationReport>;
+    async fn get_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<ReconciliationReport>;
+    async fn finish_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<Reconciliation>;
+    async fn cancel_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<Reconciliation>;
+    async fn set_movement_status(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
+        status: MovementStatus,
+    ) -> Result<Movement>;
+}
+
+pub struct ReconciliationUseCase {
+    reconciliation_service: Box<dyn ReconciliationService>,
+    account_service: Box<dyn AccountService>,
+}
+
+impl ReconciliationUseCase {
+    pub fn new(
+        reconciliation_service: Box<dyn ReconciliationService>,
+        account_service: Box<dyn AccountService>,
+    ) -> Self {
+        Self {
+            reconciliation_service,
+            account_service,
+        }
+    }
+
+    async fn get_reconciliation_report(
+        &self,
+        reconciliation: Reconciliation,
+    ) -> Result<ReconciliationReport> {
+        let cleared_balance = self
+            .reconciliation_service
+            .find_cleared_balance(reconciliation.account_id, reconciliation.statement_date)
+            .await?;
+        let movements = self
+            .reconciliation_service
+            .find_unreconciled_movements(reconciliation.account_id, reconciliation.statement_date)
+            .await?;
+        Ok(ReconciliationReport {
+            difference: reconciliation.statement_balance - cleared_balance,
+            reconciliation,
+            cleared_balance,
+            movements,
+        })
+    }
+}
+
+#[async_trait]
+impl ReconciliationUseCaseTrait for ReconciliationUseCase {
+    async fn start_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        statement_date: NaiveDate,
+        statement_balance: Decimal,
+    ) -> Result<ReconciliationReport> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let statement_balance = account.currency.validate_amount(statement_balance)?;
+        let reconciliation = self
+            .reconciliation_service
+            .insert(Reconciliation {
+                id: Uuid::new_v4(),
+                account_id,
+                statement_date,
+                statement_balance,
+                finished_at: None,
+            })
+            .await
+            .map_err(|err| match err {
+                Error::Repository(RepositoryErrorType::Conflict) => {
+                    Error::Validation(anyhow!("account already has a reconciliation in progress"))
+                }
+                err => err,
+            })?;
+        self.get_reconciliation_report(reconciliation).await
+    }
+
+    async fn get_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<ReconciliationReport> {
+        self.account_service
+            .find_by_id_and_user_id(account_id, user_id)
+            .await?;
+        let reconciliation = self
+            .reconciliation_service
+            .find_open_by_account_id(account_id)
+            .await?;
+        self.get_reconciliation_report(reconciliation).await
+    }
+
+    async fn finish_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<Reconciliation> {
+        get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let reconciliation = self
+            .reconciliation_service
+            .find_open_by_account_id(account_id)
+            .await?;
+        let report = self.get_reconciliation_report(reconciliation).await?;
+        if !report.difference.is_zero() {
+            return Err(Error::Validation(anyhow!(
+                "cleared balance differs from the statement balance by {}",
+                report.difference
+            )));
+        }
+        let reconciliation = self
+            .reconciliation_service
+            .finish(report.reconciliation)
+            .await?;
+        Ok(reconciliation)
+    }
+
+    async fn cancel_reconciliation(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<Reconciliation> {
+        self.account_service
+            .find_by_id_and_user_id(account_id, user_id)
+            .await?;
+        let reconciliation = self
+            .reconciliation_service
+            .find_open_by_account_id(account_id)
+            .await?;
+        let reconciliation = self.reconciliation_service.delete(reconciliation).await?;
+        Ok(reconciliation)
+    }
+
+    async fn set_movement_status(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        movement_id: Uuid,
+        status: MovementStatus,
+    ) -> Result<Movement> {
+        if status == MovementStatus::Reconciled {
+            return Err(Error::Validation(anyhow!(
+                "movements are reconciled by finishing a reconciliation"
+            )));
+        }
+        get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let movement = self
+            .account_service
+            .find_movement(movement_id, account_id)
+            .await?;
+        ensure_not_reconciled(&movement)?;
+        let movement = self
+            .account_service
+            .update_movement(Movement { status, ..movement })
+            .await?;
+        Ok(movement)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub ReconciliationUseCase {}
+    #[async_trait]
+    impl ReconciliationUseCaseTrait for ReconciliationUseCase {
+        async fn start_reconciliation(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            statement_date: NaiveDate,
+            statement_balance: Decimal,
+        ) -> Result<ReconciliationReport>;
+        async fn get_reconciliation(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+        ) -> Result<ReconciliationReport>;
+        async fn finish_reconciliation(&self, user_id: Uuid, account_id: Uuid)
+            -> Result<Reconciliation>;
+        async fn cancel_reconciliation(&self, user_id: Uuid, account_id: Uuid)
+            -> Result<Reconciliation>;
+        async fn set_movement_status(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            movement_id: Uuid,
+            status: MovementStatus,
+        ) -> Result<Movement>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService, reconciliations::MockReconciliationService,
+    };
+    use crate::domain::entities::accounts::{Account, AccountKind, AccountTerms};
+    use crate::domain::entities::currencies::Currency;
+
+    fn get_mock_use_case(
+        reconciliation_service: MockReconciliationService,
+        account_service: MockAccountService,
+    ) -> ReconciliationUseCase {
+        ReconciliationUseCase {
+            reconciliation_service: Box::new(reconciliation_service),
+            account_service: Box::new(account_service),
+        }
+    }
+
+    fn get_reconciliation(account_id: Uuid) -> Reconciliation {
+        Reconciliation {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            statement_date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            statement_balance: Decimal::from(100),
+            finished_at: None,
+        }
+    }
+
+    fn get_reconciliation_services(
+        user_id: Uuid,
+        reconciliation: Reconciliation,
+        cleared_balance: Decimal,
+    ) -> (MockAccountService, MockReconciliationService) {
+        let account_id = reconciliation.account_id;
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        let mut reconciliation_service = MockReconciliationService::new();
+        reconciliation_service
+            .expect_find_open_by_account_id()
+            .with(predicate::eq(account_id))
+            .return_once(move |_| Ok(reconciliation));
+        reconciliation_service
+            .expect_find_cleared_balance()
+            .return_once(move |_, _| Ok(cleared_balance));
+        reconciliation_service
+            .expect_find_unreconciled_movements()
+            .return_once(|_, _| Ok(vec![]));
+        (account_service, reconciliation_service)
+    }
+
+    #[tokio::test]
+    async fn start_reconciliation_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let reconciliation = get_reconciliation(uuid::Uuid::new_v4());
+        let (account_service, mut reconciliation_service) =
+            get_reconciliation_services(user_id, reconciliation.clone(), Decimal::from(70));
+        reconciliation_service
+            .expect_insert()
+            .withf(|reconciliation| reconciliation.finished_at.is_none())
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(reconciliation_service, account_service);
+
+        let report = use_case
+            .start_reconciliation(
+                user_id,
+                reconciliation.account_id,
+                reconciliation.statement_date,
+                reconciliation.statement_balance,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.cleared_balance, Decimal::from(70));
+        assert_eq!(report.difference, Decimal::from(30));
+        assert_eq!(
+            report.reconciliation.statement_balance,
+            reconciliation.statement_balance
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn start_reconciliation_in_progress() {
+        let user_id = uuid::Uuid::new_v4();
+        let reconciliation = get_reconciliation(uuid::Uuid::new_v4());
+        let (account_service, mut reconciliation_service) =
+            get_reconciliation_services(user_id, reconciliation.clone(), Decimal::from(0));
+        reconciliation_service
+            .expect_insert()
+            .return_once(|_| Err(Error::Repository(RepositoryErrorType::Conflict)));
+
+        let use_case = get_mock_use_case(reconciliation_service, account_service);
+
+        use_case
+            .start_reconciliation(
+                user_id,
+                reconciliation.account_id,
+                reconciliation.statement_date,
+                reconciliation.statement_balance,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn finish_reconciliation_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let reconciliation = get_reconciliation(uuid::Uuid::new_v4());
+        let finished = Reconciliation {
+            finished_at: Some(chrono::Utc::now()),
+            ..reconciliation.clone()
+        };
+        let finished2 = finished.clone();
+        let (account_service, mut reconciliation_service) =
+            get_reconciliation_services(user_id, reconciliation.clone(), Decimal::from(100));
+        reconciliation_service
+            .expect_finish()
+            .with(predicate::eq(reconciliation.clone()))
+            .return_once(|_| Ok(finished));
+
+        let use_case = get_mock_use_case(reconciliation_service, account_service);
+
+        let result = use_case
+            .finish_reconciliation(user_id, reconciliation.account_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result, finished2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn finish_reconciliation_with_difference() {
+        let user_id = uuid::Uuid::new_v4();
+        let reconciliation = get_reconciliation(uuid::Uuid::new_v4());
+        let (account_service, mut reconciliation_service) =
+            get_reconciliation_services(user_id, reconciliation.clone(), Decimal::from(90));
+        reconciliation_service.expect_finish().never();
+
+        let use_case = get_mock_use_case(reconciliation_service, account_service);
+
+        use_case
+            .finish_reconciliation(user_id, reconciliation.account_id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn set_movement_status_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let reconciliation = get_reconciliation(uuid::Uuid::new_v4());
+        let account_id = reconciliation.account_id;
+        let movement = Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: chrono::Utc::now(),
+            title: "title".to_string(),
+            category_id: None,
+            amount: Decimal::from(10),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let cleared = Movement {
+            status: MovementStatus::Cleared,
+            ..movement.clone()
+        };
+        let cleared2 = cleared.clone();
+        let (mut account_service, _) =
+            get_reconciliation_services(user_id, reconciliation, Decimal::from(0));
+        account_service
+            .expect_find_movement()
+            .with(predicate::eq(movement.id), predicate::eq(account_id))
+            .return_once(|_, _| Ok(movement));
+        account_service
+            .expect_update_movement()
+            .with(predicate::eq(cleared))
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(MockReconciliationService::new(), account_service);
+
+        let result = use_case
+            .set_movement_status(user_id, account_id, cleared2.id, MovementStatus::Cleared)
+            .await
+            .unwrap();
+
+        assert_eq!(result, cleared2);
+    }
+}
@@ -0,0 +1,553 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::profile::get_open_account;
+use crate::application::services::{
+    accounts::AccountService, categories::CategoryService,
+    recurring_movements::RecurringMovementService,
+};
+use crate::domain::entities::accounts::{Movement, MovementStatus};
+use crate::domain::entities::recurring_movements::{Frequency, RecurringMovement};
+use crate::domain::error::{Error, Result};
+
+#[async_trait]
+pub trait RecurringMovementUseCaseTrait: Send + Sync {
+    async fn get_recurring_movements(&self, user_id: Uuid) -> Result<Vec<RecurringMovement>>;
+    #[allow(clippy::too_many_arguments)]
+    async fn create_recurring_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        title: &str,
+        category_id: Uuid,
+        amount: Decimal,
+        frequency: Frequency,
+        start_date: NaiveDate,
+    ) -> Result<RecurringMovement>;
+    async fn set_recurring_movement_paused(
+        &self,
+        user_id: Uuid,
+        recurring_movement_id: Uuid,
+        paused: bool,
+    ) -> Result<RecurringMovement>;
+    async fn delete_recurring_movement(
+        &self,
+        user_id: Uuid,
+        recurring_movement_id: Uuid,
+    ) -> Result<RecurringMovement>;
+    async fn materialise_recurring_movements(&self, date: NaiveDate) -> Result<usize>;
+}
+
+pub struct RecurringMovementUseCase {
+    recurring_movement_service: Box<dyn RecurringMovementService>,
+    account_service: Box<dyn AccountService>,
+    category_service: Box<dyn CategoryService>,
+}
+
+impl RecurringMovementUseCase {
+    pub fn new(
+        recurring_movement_service: Box<dyn RecurringMovementService>,
+        account_service: Box<dyn AccountService>,
+        category_service: Box<dyn CategoryService>,
+    ) -> Self {
+        Self {
+            recurring_movement_service,
+            account_service,
+            category_service,
+        }
+    }
+
+    async fn materialise_occurrences(
+        &self,
+        mut recurring_movement: RecurringMovement,
+        date: NaiveDate,
+        count: &mut usize,
+    ) -> Result<()> {
+        while recurring_movement.next_date <= date {
+            let next_date = recurring_movement
+                .occurrence_after(recurring_movement.next_date)
+                .ok_or(Error::Validation(anyhow!(
+                    "recurring movement has no further occurrences"
+                )))?;
+            let movement = Movement {
+                id: Uuid::new_v4(),
+                account_id: recurring_movement.account_id,
+                timestamp: Utc
+                    .from_utc_datetime(&recurring_movement.next_date.and_time(NaiveTime::MIN)),
+                title: recurring_movement.title.clone(),
+                amount: recurring_movement.amount,
+                category_id: Some(recurring_movement.category_id),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            };
+
+            match self
+                .recurring_movement_service
+                .materialise(recurring_movement.clone(), movement, next_date)
+                .await?
+            {
+                Some(_) => *count += 1,
+                None => break,
+            }
+            recurring_movement.next_date = next_date;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RecurringMovementUseCaseTrait for RecurringMovementUseCase {
+    async fn get_recurring_movements(&self, user_id: Uuid) -> Result<Vec<RecurringMovement>> {
+        let recurring_movements = self
+            .recurring_movement_service
+            .find_many_by_user_id(user_id)
+            .await?;
+        Ok(recurring_movements)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn create_recurring_movement(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        title: &str,
+        category_id: Uuid,
+        amount: Decimal,
+        frequency: Frequency,
+        start_date: NaiveDate,
+    ) -> Result<RecurringMovement> {
+        let account = get_open_account(self.account_service.as_ref(), user_id, account_id).await?;
+        let amount = account.currency.validate_amount(amount)?;
+        self.category_service
+            .find_by_id_and_user_id(category_id, user_id)
+            .await?;
+
+        let recurring_movement = self
+            .recurring_movement_service
+            .insert(RecurringMovement {
+                id: Uuid::new_v4(),
+                user_id,
+                account_id,
+                category_id,
+                title: title.to_string(),
+                amount,
+                frequency,
+                start_date,
+                next_date: start_date,
+                paused: false,
+            })
+            .await?;
+        Ok(recurring_movement)
+    }
+
+    async fn set_recurring_movement_paused(
+        &self,
+        user_id: Uuid,
+        recurring_movement_id: Uuid,
+        paused: bool,
+    ) -> Result<RecurringMovement> {
+        let recurring_movement = self
+            .recurring_movement_service
+            .find_by_id_and_user_id(recurring_movement_id, user_id)
+            .await?;
+
+        // Occurrences that fell due while paused are skipped rather than created on resume
+        let today = Utc::now().date_naive();
+        let next_date = match !paused && recurring_movement.next_date < today {
+            true => today
+                .pred_opt()
+                .and_then(|yesterday| recurring_movement.occurrence_after(yesterday))
+                .ok_or(Error::Validation(anyhow!(
+                    "recurring movement has no further occurrences"
+                )))?,
+            false => recurring_movement.next_date,
+        };
+
+        let recurring_movement = self
+            .recurring_movement_service
+            .update(RecurringMovement {
+                paused,
+                next_date,
+                ..recurring_movement
+            })
+            .await?;
+        Ok(recurring_movement)
+    }
+
+    async fn delete_recurring_movement(
+        &self,
+        user_id: Uuid,
+        recurring_movement_id: Uuid,
+    ) -> Result<RecurringMovement> {
+        let recurring_movement = self
+            .recurring_movement_service
+            .find_by_id_and_user_id(recurring_movement_id, user_id)
+            .await?;
+        let recurring_movement = self
+            .recurring_movement_service
+            .delete(recurring_movement)
+            .await?;
+        Ok(recurring_movement)
+    }
+
+    async fn materialise_recurring_movements(&self, date: NaiveDate) -> Result<usize> {
+        let mut count = 0;
+        for recurring_movement in self.recurring_movement_service.find_due(date).await? {
+            let id = recurring_movement.id;
+            if let Err(err) = self
+                .materialise_occurrences(recurring_movement, date, &mut count)
+                .await
+            {
+                tracing::error!("Error creating movements of recurring movement {id}: {err:?}");
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub RecurringMovementUseCase {}
+    #[async_trait]
+    impl RecurringMovementUseCaseTrait for RecurringMovementUseCase {
+        async fn get_recurring_movements(&self, user_id: Uuid) -> Result<Vec<RecurringMovement>>;
+        #[allow(clippy::too_many_arguments)]
+        async fn create_recurring_movement(
+            &self,
+            user_id: Uuid,
+            account_id: Uuid,
+            title: &str,
+            category_id: Uuid,
+            amount: Decimal,
+            frequency: Frequency,
+            start_date: NaiveDate,
+        ) -> Result<RecurringMovement>;
+        async fn set_recurring_movement_paused(
+            &self,
+            user_id: Uuid,
+            recurring_movement_id: Uuid,
+            paused: bool,
+        ) -> Result<RecurringMovement>;
+        async fn delete_recurring_movement(
+            &self,
+            user_id: Uuid,
+            recurring_movement_id: Uuid,
+        ) -> Result<RecurringMovement>;
+        async fn materialise_recurring_movements(&self, date: NaiveDate) -> Result<usize>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Months;
+    use mockall::{predicate, Sequence};
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService, categories::MockCategoryService,
+        recurring_movements::MockRecurringMovementService,
+    };
+    use crate::domain::entities::accounts::{Account, AccountKind, AccountTerms};
+    use crate::domain::entities::categories::Category;
+    use crate::domain::entities::currencies::Currency;
+
+    fn get_mock_use_case(
+        recurring_movement_service: MockRecurringMovementService,
+        account_service: MockAccountService,
+        category_service: MockCategoryService,
+    ) -> RecurringMovementUseCase {
+        RecurringMovementUseCase {
+            recurring_movement_service: Box::new(recurring_movement_service),
+            account_service: Box::new(account_service),
+            category_service: Box::new(category_service),
+        }
+    }
+
+    fn get_category(id: Uuid, user_id: Uuid, parent_id: Option<Uuid>) -> Category {
+        Category {
+            id,
+            user_id,
+            parent_id,
+            name: "name".to_string(),
+            color: None,
+            icon: None,
+        }
+    }
+
+    fn get_recurring_movement(user_id: Uuid, next_date: NaiveDate) -> RecurringMovement {
+        RecurringMovement {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            account_id: uuid::Uuid::new_v4(),
+            category_id: uuid::Uuid::new_v4(),
+            title: "rent".to_string(),
+            amount: Decimal::from(-800),
+            frequency: Frequency::Monthly,
+            start_date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            next_date,
+            paused: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_recurring_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 27).unwrap();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(account_id), predicate::eq(user_id))
+            .return_once(move |_, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id: account_id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(get_category(category_id, user_id, None)));
+        let mut recurring_movement_service = MockRecurringMovementService::new();
+        recurring_movement_service
+            .expect_insert()
+            .withf(move |x: &RecurringMovement| {
+                x.account_id == account_id
+                    && x.category_id == category_id
+                    && x.next_date == start_date
+                    && x.frequency == Frequency::Monthly
+                    && !x.paused
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            recurring_movement_service,
+            account_service,
+            category_service,
+        );
+
+        let result = use_case
+            .create_recurring_movement(
+                user_id,
+                account_id,
+                "salary",
+                category_id,
+                Decimal::from(2000),
+                Frequency::Monthly,
+                start_date,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.start_date, start_date);
+    }
+
+    #[tokio::test]
+    async fn set_recurring_movement_resumed_skips_missed() {
+        let user_id = uuid::Uuid::new_v4();
+        let recurring_movement = RecurringMovement {
+            paused: true,
+            ..get_recurring_movement(user_id, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap())
+        };
+        let recurring_movement_id = recurring_movement.id;
+        let today = Utc::now().date_naive();
+
+        let mut recurring_movement_service = MockRecurringMovementService::new();
+        recurring_movement_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(recurring_movement_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(recurring_movement));
+        recurring_movement_service
+            .expect_update()
+            .withf(move |x: &RecurringMovement| {
+                !x.paused
+                    && x.next_date >= today
+                    && x.next_date <= today.checked_add_months(Months::new(1)).unwrap()
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            recurring_movement_service,
+            MockAccountService::new(),
+            MockCategoryService::new(),
+        );
+
+        use_case
+            .set_recurring_movement_paused(user_id, recurring_movement_id, false)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_recurring_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let recurring_movement =
+            get_recurring_movement(user_id, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+        let recurring_movement_id = recurring_movement.id;
+        let recurring_movement2 = recurring_movement.clone();
+        let recurring_movement3 = recurring_movement.clone();
+
+        let mut recurring_movement_service = MockRecurringMovementService::new();
+        recurring_movement_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(recurring_movement_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(recurring_movement));
+        recurring_movement_service
+            .expect_delete()
+            .with(predicate::eq(recurring_movement2))
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            recurring_movement_service,
+            MockAccountService::new(),
+            MockCategoryService::new(),
+        );
+
+        let result = use_case
+            .delete_recurring_movement(user_id, recurring_movement_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result, recurring_movement3);
+    }
+
+    #[tokio::test]
+    async fn materialise_recurring_movements_catches_up() {
+        let user_id = uuid::Uuid::new_v4();
+        let recurring_movement =
+            get_recurring_movement(user_id, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+        let today = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+
+        let mut recurring_movement_service = MockRecurringMovementService::new();
+        recurring_movement_service
+            .expect_find_due()
+            .with(predicate::eq(today))
+            .return_once(move |_| Ok(vec![recurring_movement]));
+        let mut sequence = Sequence::new();
+        for (date, next_date) in [
+            ((2023, 1, 31), (2023, 2, 28)),
+            ((2023, 2, 28), (2023, 3, 31)),
+        ] {
+            let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+            let next_date = NaiveDate::from_ymd_opt(next_date.0, next_date.1, next_date.2).unwrap();
+            recurring_movement_service
+                .expect_materialise()
+                .withf(
+                    move |x: &RecurringMovement, movement: &Movement, next: &NaiveDate| {
+                        x.next_date == date
+                            && movement.timestamp.date_naive() == date
+                            && movement.amount == Decimal::from(-800)
+                            && *next == next_date
+                    },
+                )
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(|_, movement, _| Ok(Some(movement)));
+        }
+
+        let use_case = get_mock_use_case(
+            recurring_movement_service,
+            MockAccountService::new(),
+            MockCategoryService::new(),
+        );
+
+        let result = use_case
+            .materialise_recurring_movements(today)
+            .await
+            .unwrap();
+
+        assert_eq!(result, 2);
+    }
+
+    #[tokio::test]
+    async fn materialise_recurring_movements_already_done() {
+        let user_id = uuid::Uuid::new_v4();
+        let recurring_movement =
+            get_recurring_movement(user_id, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+        let today = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+
+        let mut recurring_movement_service = MockRecurringMovementService::new();
+        recurring_movement_service
+            .expect_find_due()
+            .with(predicate::eq(today))
+            .return_once(move |_| Ok(vec![recurring_movement]));
+        recurring_movement_service
+            .expect_materialise()
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+
+        let use_case = get_mock_use_case(
+            recurring_movement_service,
+            MockAccountService::new(),
+            MockCategoryService::new(),
+        );
+
+        let result = use_case
+            .materialise_recurring_movements(today)
+            .await
+            .unwrap();
+
+        assert_eq!(result, 0);
+    }
+
+    #[tokio::test]
+    async fn materialise_recurring_movements_skips_failing() {
+        let user_id = uuid::Uuid::new_v4();
+        let date = NaiveDate::from_ymd_opt(2023, 3, 10).unwrap();
+        let recurring_movements: Vec<RecurringMovement> = (0..3)
+            .map(|_| get_recurring_movement(user_id, date))
+            .collect();
+        let broken_id = recurring_movements[1].id;
+        let today = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+
+        let mut recurring_movement_service = MockRecurringMovementService::new();
+        recurring_movement_service
+            .expect_find_due()
+            .with(predicate::eq(today))
+            .return_once(move |_| Ok(recurring_movements));
+        recurring_movement_service
+            .expect_materialise()
+            .withf(move |x: &RecurringMovement, _: &Movement, _: &NaiveDate| x.id == broken_id)
+            .times(1)
+            .returning(|_, _, _| Err(Error::Validation(anyhow!("broken"))));
+        recurring_movement_service
+            .expect_materialise()
+            .withf(move |x: &RecurringMovement, _: &Movement, _: &NaiveDate| x.id != broken_id)
+            .times(2)
+            .returning(|_, movement, _| Ok(Some(movement)));
+
+        let use_case = get_mock_use_case(
+            recurring_movement_service,
+            MockAccountService::new(),
+            MockCategoryService::new(),
+        );
+
+        let result = use_case
+            .materialise_recurring_movements(today)
+            .await
+            .unwrap();
+
+        assert_eq!(result, 2);
+    }
+}
@@ -0,0 +1,560 @@
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use super::profile::{get_open_account, get_rule_set};
+use crate::application::services::{
+    accounts::AccountService, categories::CategoryService, investments::TradeService,
+    rules::RuleService,
+};
+use crate::domain::entities::accounts::{normalize_tags, MovementStatus};
+use crate::domain::entities::rules::{Rule, RuleActions, RuleChange, RuleConditions};
+use crate::domain::error::Result;
+
+#[async_trait]
+pub trait RuleUseCaseTrait: Send + Sync {
+    async fn get_rules(&self, user_id: Uuid) -> Result<Vec<Rule>>;
+    async fn create_rule(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        priority: i32,
+        conditions: RuleConditions,
+        actions: RuleActions,
+    ) -> Result<Rule>;
+    async fn update_rule(
+        &self,
+        user_id: Uuid,
+        rule_id: Uuid,
+        name: &str,
+        priority: i32,
+        conditions: RuleConditions,
+        actions: RuleActions,
+    ) -> Result<Rule>;
+    async fn delete_rule(&self, user_id: Uuid, rule_id: Uuid) -> Result<Rule>;
+    async fn apply_rules(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        dry_run: bool,
+    ) -> Result<Vec<RuleChange>>;
+}
+
+pub struct RuleUseCase {
+    rule_service: Box<dyn RuleService>,
+    account_service: Box<dyn AccountService>,
+    category_service: Box<dyn CategoryService>,
+    trade_service: Box<dyn TradeService>,
+}
+
+impl RuleUseCase {
+    pub fn new(
+        rule_service: Box<dyn RuleService>,
+        account_service: Box<dyn AccountService>,
+        category_service: Box<dyn CategoryService>,
+        trade_service: Box<dyn TradeService>,
+    ) -> Self {
+        Self {
+            rule_service,
+            account_service,
+            category_service,
+            trade_service,
+        }
+    }
+
+    async fn new_rule(&self, rule: Rule) -> Result<Rule> {
+        let rule = Rule {
+            actions: RuleActions {
+                add_tags: normalize_tags(rule.actions.add_tags)?,
+                ..rule.actions
+            },
+            ..rule
+        };
+        rule.validate()?;
+        if let Some(account_id) = rule.conditions.account_id {
+            self.account_service
+                .find_by_id_and_user_id(account_id, rule.user_id)
+                .await?;
+        }
+        if let Some(category_id) = rule.actions.category_id {
+            self.category_service
+                .find_by_id_and_user_id(category_id, rule.user_id)
+                .await?;
+        }
+        Ok(rule)
+    }
+}
+
+#[async_trait]
+impl RuleUseCaseTrait for RuleUseCase {
+    async fn get_rules(&self, user_id: Uuid) -> Result<Vec<Rule>> {
+        let rules = self.rule_service.find_many_by_user_id(user_id).await?;
+        Ok(rules)
+    }
+
+    async fn create_rule(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        priority: i32,
+        conditions: RuleConditions,
+        actions: RuleActions,
+    ) -> Result<Rule> {
+        let rule = self
+            .new_rule(Rule {
+                id: Uuid::new_v4(),
+                user_id,
+                name: name.to_string(),
+                priority,
+                conditions,
+                actions,
+            })
+            .await?;
+        let rule = self.rule_service.insert(rule).await?;
+        Ok(rule)
+    }
+
+    async fn update_rule(
+        &self,
+        user_id: Uuid,
+        rule_id: Uuid,
+        name: &str,
+        priority: i32,
+        conditions: RuleConditions,
+        actions: RuleActions,
+    ) -> Result<Rule> {
+        let rule = self
+            .rule_service
+            .find_by_id_and_user_id(rule_id, user_id)
+            .await?;
+        let rule = self
+            .new_rule(Rule {
+                name: name.to_string(),
+                priority,
+                conditions,
+                actions,
+                ..rule
+            })
+            .await?;
+        let rule = self.rule_service.update(rule).await?;
+        Ok(rule)
+    }
+
+    async fn delete_rule(&self, user_id: Uuid, rule_id: Uuid) -> Result<Rule> {
+        let rule = self
+            .rule_service
+            .find_by_id_and_user_id(rule_id, user_id)
+            .await?;
+        let rule = self.rule_service.delete(rule).await?;
+        Ok(rule)
+    }
+
+    async fn apply_rules(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        dry_run: bool,
+    ) -> Result<Vec<RuleChange>> {
+        let accounts = match account_id {
+            Some(account_id) => {
+                vec![get_open_account(self.account_service.as_ref(), user_id, account_id).await?]
+            }
+            None => self
+                .account_service
+                .find_many_by_user_id(user_id)
+                .await?
+                .into_iter()
+                .filter(|account| !account.archived)
+                .collect(),
+        };
+        let rules = get_rule_set(self.rule_service.as_ref(), user_id).await?;
+
+        let mut changes = vec![];
+        for account in accounts {
+            let movements = self
+                .account_service
+                .stream_movements(account.id, None, None)
+                .try_collect::<Vec<_>>()
+                .await?;
+            let split_ids = self
+                .account_service
+                .find_splits(movements.iter().map(|movement| movement.id).collect())
+                .await?
+                .into_iter()
+                .map(|split| split.movement_id)
+                .collect::<HashSet<_>>();
+            let trade_ids = self
+                .trade_service
+                .find_trades(account.id)
+                .await?
+                .into_iter()
+                .map(|trade| trade.movement_id)
+                .collect::<HashSet<_>>();
+            for movement in movements {
+                if movement.transfer_id.is_some()
+                    || movement.status == MovementStatus::Reconciled
+                    || split_ids.contains(&movement.id)
+                    || trade_ids.contains(&movement.id)
+                {
+                    continue;
+                }
+                let after = rules.apply(movement.clone());
+                if after != movement {
+                    changes.push(RuleChange {
+                        before: movement,
+                        after,
+                    });
+                }
+            }
+        }
+
+        if !dry_run {
+            for change in changes.iter_mut() {
+                change.after = self
+                    .account_service
+                    .update_movement(change.after.clone())
+                    .await?;
+            }
+        }
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub RuleUseCase {}
+    #[async_trait]
+    impl RuleUseCaseTrait for RuleUseCase {
+        async fn get_rules(&self, user_id: Uuid) -> Result<Vec<Rule>>;
+        async fn create_rule(
+            &self,
+            user_id: Uuid,
+            name: &str,
+            priority: i32,
+            conditions: RuleConditions,
+            actions: RuleActions,
+        ) -> Result<Rule>;
+        async fn update_rule(
+            &self,
+            user_id: Uuid,
+            rule_id: Uuid,
+            name: &str,
+            priority: i32,
+            conditions: RuleConditions,
+            actions: RuleActions,
+        ) -> Result<Rule>;
+        async fn delete_rule(&self, user_id: Uuid, rule_id: Uuid) -> Result<Rule>;
+        async fn apply_rules(
+            &self,
+            user_id: Uuid,
+            account_id: Option<Uuid>,
+            dry_run: bool,
+        ) -> Result<Vec<RuleChange>>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures::stream::{self, StreamExt};
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService, categories::MockCategoryService,
+        investments::MockTradeService, rules::MockRuleService,
+    };
+    use crate::domain::entities::accounts::{
+        Account, AccountKind, AccountTerms, Movement, MovementSplit,
+    };
+    use crate::domain::entities::categories::Category;
+    use crate::domain::entities::currencies::Currency;
+    use crate::domain::entities::investments::{Trade, TradeKind};
+
+    fn get_mock_use_case(
+        rule_service: MockRuleService,
+        account_service: MockAccountService,
+        category_service: MockCategoryService,
+        trade_service: MockTradeService,
+    ) -> RuleUseCase {
+        RuleUseCase {
+            rule_service: Box::new(rule_service),
+            account_service: Box::new(account_service),
+            category_service: Box::new(category_service),
+            trade_service: Box::new(trade_service),
+        }
+    }
+
+    fn get_rule_service(user_id: Uuid, rules: Vec<Rule>) -> MockRuleService {
+        let mut rule_service = MockRuleService::new();
+        rule_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(rules));
+        rule_service
+    }
+
+    fn get_category(id: Uuid, user_id: Uuid, parent_id: Option<Uuid>) -> Category {
+        Category {
+            id,
+            user_id,
+            parent_id,
+            name: "name".to_string(),
+            color: None,
+            icon: None,
+        }
+    }
+
+    fn get_rule(user_id: Uuid, category_id: Uuid) -> Rule {
+        Rule {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "groceries".to_string(),
+            priority: 0,
+            conditions: RuleConditions {
+                title_contains: Some("grocer".to_string()),
+                ..Default::default()
+            },
+            actions: RuleActions {
+                category_id: Some(category_id),
+                add_tags: vec!["food".to_string()],
+                ..Default::default()
+            },
+        }
+    }
+
+    fn get_rules_account_service(
+        user_id: Uuid,
+        category_id: Uuid,
+    ) -> (MockAccountService, MockTradeService, Vec<Movement>) {
+        let account = Account {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "name".to_string(),
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let archived = Account {
+            id: uuid::Uuid::new_v4(),
+            archived: true,
+            ..account.clone()
+        };
+        let account_id = account.id;
+        let movement = |title: &str| Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: Utc::now(),
+            title: title.to_string(),
+            amount: Decimal::from(-20),
+            category_id: None,
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let movements = vec![
+            movement("Groceries"),
+            Movement {
+                transfer_id: Some(uuid::Uuid::new_v4()),
+                ..movement("Groceries")
+            },
+            Movement {
+                status: MovementStatus::Reconciled,
+                ..movement("Groceries")
+            },
+            movement("Groceries"),
+            Movement {
+                category_id: Some(category_id),
+                tags: vec!["food".to_string()],
+                ..movement("Groceries")
+            },
+            movement("Salary"),
+            movement("Groceries"),
+        ];
+        let split = MovementSplit {
+            id: uuid::Uuid::new_v4(),
+            movement_id: movements[3].id,
+            category_id,
+            amount: Decimal::from(-20),
+            memo: None,
+        };
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(vec![account, archived]));
+        let movements2 = movements.clone();
+        account_service
+            .expect_stream_movements()
+            .with(
+                predicate::eq(account_id),
+                predicate::eq(None),
+                predicate::eq(None),
+            )
+            .return_once(move |_, _, _| stream::iter(movements2.into_iter().map(Ok)).boxed());
+        account_service
+            .expect_find_splits()
+            .return_once(move |_| Ok(vec![split]));
+
+        let trade = Trade {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            movement_id: movements[6].id,
+            date: Utc::now().date_naive(),
+            kind: TradeKind::Dividend,
+            symbol: "GROCER".to_string(),
+            quantity: Decimal::from(0),
+            price: Decimal::from(20),
+            fees: Decimal::from(0),
+        };
+        let mut trade_service = MockTradeService::new();
+        trade_service
+            .expect_find_trades()
+            .with(predicate::eq(account_id))
+            .return_once(move |_| Ok(vec![trade]));
+        (account_service, trade_service, movements)
+    }
+
+    #[tokio::test]
+    async fn create_rule_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let rule = get_rule(user_id, category_id);
+
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::eq(category_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(get_category(category_id, user_id, None)));
+        let mut rule_service = MockRuleService::new();
+        rule_service
+            .expect_insert()
+            .withf(move |rule: &Rule| {
+                rule.user_id == user_id
+                    && rule.name == "groceries"
+                    && rule.actions.add_tags == vec!["food".to_string()]
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            rule_service,
+            MockAccountService::new(),
+            category_service,
+            MockTradeService::new(),
+        );
+
+        let result = use_case
+            .create_rule(
+                user_id,
+                "groceries",
+                0,
+                rule.conditions,
+                RuleActions {
+                    add_tags: vec![" Food".to_string(), "food".to_string()],
+                    ..rule.actions
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.actions.add_tags, vec!["food".to_string()]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_rule_invalid_pattern() {
+        let user_id = uuid::Uuid::new_v4();
+        let rule = get_rule(user_id, uuid::Uuid::new_v4());
+
+        let use_case = get_mock_use_case(
+            MockRuleService::new(),
+            MockAccountService::new(),
+            MockCategoryService::new(),
+            MockTradeService::new(),
+        );
+
+        use_case
+            .create_rule(
+                user_id,
+                "groceries",
+                0,
+                RuleConditions {
+                    title_regex: Some("[grocer".to_string()),
+                    ..Default::default()
+                },
+                rule.actions,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn apply_rules_dry_run() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let (account_service, trade_service, movements) =
+            get_rules_account_service(user_id, category_id);
+
+        let use_case = get_mock_use_case(
+            get_rule_service(user_id, vec![get_rule(user_id, category_id)]),
+            account_service,
+            MockCategoryService::new(),
+            trade_service,
+        );
+
+        let result = use_case.apply_rules(user_id, None, true).await.unwrap();
+
+        assert_eq!(
+            result,
+            vec![RuleChange {
+                before: movements[0].clone(),
+                after: Movement {
+                    category_id: Some(category_id),
+                    tags: vec!["food".to_string()],
+                    ..movements[0].clone()
+                },
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_rules_saves_changes() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let (mut account_service, trade_service, movements) =
+            get_rules_account_service(user_id, category_id);
+        let movement_id = movements[0].id;
+        account_service
+            .expect_update_movement()
+            .times(1)
+            .withf(move |movement: &Movement| {
+                movement.id == movement_id && movement.category_id == Some(category_id)
+            })
+            .return_once(Ok);
+
+        let use_case = get_mock_use_case(
+            get_rule_service(user_id, vec![get_rule(user_id, category_id)]),
+            account_service,
+            MockCategoryService::new(),
+            trade_service,
+        );
+
+        let result = use_case.apply_rules(user_id, None, false).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].after.tags, vec!["food".to_string()]);
+    }
+}
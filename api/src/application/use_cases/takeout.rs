@@ -0,0 +1,479 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+use uuid::Uuid;
+
+use crate::application::services::{
+    accounts::AccountService,
+    budgets::BudgetService,
+    categories::CategoryService,
+    exchange_rates::ExchangeRateService,
+    goals::GoalService,
+    imports::ImportMappingService,
+    investments::{PriceService, TradeService},
+    reconciliations::ReconciliationService,
+    recurring_movements::RecurringMovementService,
+    rules::RuleService,
+    users::UserService,
+};
+use crate::domain::entities::takeout::Takeout;
+use crate::domain::error::{Error, Result};
+
+#[async_trait]
+pub trait TakeoutUseCaseTrait: Send + Sync {
+    async fn get_takeout(&self, user_id: Uuid) -> Result<Vec<u8>>;
+}
+
+pub struct TakeoutUseCase {
+    user_service: Box<dyn UserService>,
+    account_service: Box<dyn AccountService>,
+    category_service: Box<dyn CategoryService>,
+    budget_service: Box<dyn BudgetService>,
+    recurring_movement_service: Box<dyn RecurringMovementService>,
+    exchange_rate_service: Box<dyn ExchangeRateService>,
+    import_mapping_service: Box<dyn ImportMappingService>,
+    reconciliation_service: Box<dyn ReconciliationService>,
+    rule_service: Box<dyn RuleService>,
+    goal_service: Box<dyn GoalService>,
+    trade_service: Box<dyn TradeService>,
+    price_service: Box<dyn PriceService>,
+}
+
+impl TakeoutUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_service: Box<dyn UserService>,
+        account_service: Box<dyn AccountService>,
+        category_service: Box<dyn CategoryService>,
+        budget_service: Box<dyn BudgetService>,
+        recurring_movement_service: Box<dyn RecurringMovementService>,
+        exchange_rate_service: Box<dyn ExchangeRateService>,
+        import_mapping_service: Box<dyn ImportMappingService>,
+        reconciliation_service: Box<dyn ReconciliationService>,
+        rule_service: Box<dyn RuleService>,
+        goal_service: Box<dyn GoalService>,
+        trade_service: Box<dyn TradeService>,
+        price_service: Box<dyn PriceService>,
+    ) -> Self {
+        Self {
+            user_service,
+            account_service,
+            category_service,
+            budget_service,
+            recurring_movement_service,
+            exchange_rate_service,
+            import_mapping_service,
+            reconciliation_service,
+            rule_service,
+            goal_service,
+            trade_service,
+            price_service,
+        }
+    }
+}
+
+#[async_trait]
+impl TakeoutUseCaseTrait for TakeoutUseCase {
+    async fn get_takeout(&self, user_id: Uuid) -> Result<Vec<u8>> {
+        let user = self.user_service.find_by_id(user_id).await?;
+        let accounts = self.account_service.find_many_by_user_id(user_id).await?;
+
+        let mut takeout = Takeout::default();
+        takeout.add_json("user.json", &user)?;
+        takeout.add_json("accounts.json", &accounts)?;
+        takeout.start_file("movements.json")?;
+        takeout.write(b"[")?;
+        let mut first = true;
+        let mut splits = vec![];
+        for account in accounts.iter() {
+            let mut movement_ids = vec![];
+            let mut movements = self
+                .account_service
+                .stream_movements(account.id, None, None);
+            while let Some(movement) = movements.next().await {
+                if !first {
+                    takeout.write(b",")?;
+                }
+                first = false;
+                let movement = movement?;
+                movement_ids.push(movement.id);
+                let movement =
+                    serde_json::to_vec(&movement).map_err(|err| Error::External(err.into()))?;
+                takeout.write(&movement)?;
+            }
+            splits.extend(self.account_service.find_splits(movement_ids).await?);
+        }
+        takeout.write(b"]")?;
+        takeout.add_json("splits.json", &splits)?;
+        takeout.add_json(
+            "transfers.json",
+            &self.account_service.find_transfers(user_id).await?,
+        )?;
+        takeout.add_json(
+            "categories.json",
+            &self.category_service.find_many_by_user_id(user_id).await?,
+        )?;
+        takeout.add_json(
+            "budgets.json",
+            &self.budget_service.find_many_by_user_id(user_id).await?,
+        )?;
+        takeout.add_json(
+            "recurring_movements.json",
+            &self
+                .recurring_movement_service
+                .find_many_by_user_id(user_id)
+                .await?,
+        )?;
+        takeout.add_json(
+            "exchange_rates.json",
+            &self
+                .exchange_rate_service
+                .find_many_by_user_id(user_id)
+                .await?,
+        )?;
+        takeout.add_json(
+            "import_mappings.json",
+            &self
+                .import_mapping_service
+                .find_many_by_user_id(user_id)
+                .await?,
+        )?;
+        takeout.add_json(
+            "reconciliations.json",
+            &self
+                .reconciliation_service
+                .find_many_by_user_id(user_id)
+                .await?,
+        )?;
+        takeout.add_json(
+            "rules.json",
+            &self.rule_service.find_many_by_user_id(user_id).await?,
+        )?;
+        takeout.add_json(
+            "goals.json",
+            &self.goal_service.find_many_by_user_id(user_id).await?,
+        )?;
+        let mut trades = vec![];
+        for account in accounts.iter() {
+            trades.extend(self.trade_service.find_trades(account.id).await?);
+        }
+        takeout.add_json("trades.json", &trades)?;
+        takeout.add_json(
+            "prices.json",
+            &self.price_service.find_many_by_user_id(user_id).await?,
+        )?;
+        takeout.finish()
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub TakeoutUseCase {}
+    #[async_trait]
+    impl TakeoutUseCaseTrait for TakeoutUseCase {
+        async fn get_takeout(&self, user_id: Uuid) -> Result<Vec<u8>>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use futures::stream;
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService,
+        budgets::MockBudgetService,
+        categories::MockCategoryService,
+        exchange_rates::MockExchangeRateService,
+        goals::MockGoalService,
+        imports::MockImportMappingService,
+        investments::{MockPriceService, MockTradeService},
+        reconciliations::MockReconciliationService,
+        recurring_movements::MockRecurringMovementService,
+        rules::MockRuleService,
+        users::MockUserService,
+    };
+    use crate::domain::entities::accounts::{
+        Account, AccountKind, AccountTerms, Movement, MovementSplit, MovementStatus, Transfer,
+    };
+    use crate::domain::entities::currencies::Currency;
+    use crate::domain::entities::goals::Goal;
+    use crate::domain::entities::investments::{Price, Trade, TradeKind};
+    use crate::domain::entities::reconciliations::Reconciliation;
+    use crate::domain::entities::rules::{Rule, RuleActions, RuleConditions};
+    use crate::domain::entities::users::User;
+
+    #[allow(clippy::too_many_arguments)]
+    fn get_mock_use_case(
+        user_service: MockUserService,
+        account_service: MockAccountService,
+        category_service: MockCategoryService,
+        budget_service: MockBudgetService,
+        recurring_movement_service: MockRecurringMovementService,
+        exchange_rate_service: MockExchangeRateService,
+        import_mapping_service: MockImportMappingService,
+        reconciliation_service: MockReconciliationService,
+        rule_service: MockRuleService,
+        goal_service: MockGoalService,
+        trade_service: MockTradeService,
+        price_service: MockPriceService,
+    ) -> TakeoutUseCase {
+        TakeoutUseCase {
+            user_service: Box::new(user_service),
+            account_service: Box::new(account_service),
+            category_service: Box::new(category_service),
+            budget_service: Box::new(budget_service),
+            recurring_movement_service: Box::new(recurring_movement_service),
+            exchange_rate_service: Box::new(exchange_rate_service),
+            import_mapping_service: Box::new(import_mapping_service),
+            reconciliation_service: Box::new(reconciliation_service),
+            rule_service: Box::new(rule_service),
+            goal_service: Box::new(goal_service),
+            trade_service: Box::new(trade_service),
+            price_service: Box::new(price_service),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_takeout_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let user = User {
+            id: user_id,
+            email: "somebody@somebody.com".to_string(),
+            preferred_currency: Currency::USD,
+        };
+        let accounts: Vec<Account> = (0..2)
+            .map(|_| Account {
+                id: uuid::Uuid::new_v4(),
+                user_id,
+                name: "name".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .collect();
+        let movements: Vec<Movement> = accounts
+            .iter()
+            .map(|account| Movement {
+                id: uuid::Uuid::new_v4(),
+                account_id: account.id,
+                timestamp: Utc.with_ymd_and_hms(2023, 1, 5, 10, 30, 0).unwrap(),
+                title: "title".to_string(),
+                category_id: None,
+                amount: Decimal::new(-5, 0),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .collect();
+        let splits = vec![MovementSplit {
+            id: uuid::Uuid::new_v4(),
+            movement_id: movements[1].id,
+            category_id: uuid::Uuid::new_v4(),
+            amount: Decimal::new(-5, 0),
+            memo: None,
+        }];
+        let splits2 = splits.clone();
+        let transfer = Transfer {
+            id: uuid::Uuid::new_v4(),
+            debit: movements[0].clone(),
+            credit: movements[1].clone(),
+            rate: Decimal::from(1),
+            rate_date: None,
+        };
+        let transfer2 = transfer.clone();
+        let movements2 = movements.clone();
+        let accounts2 = accounts.clone();
+
+        let mut user_service = MockUserService::new();
+        user_service
+            .expect_find_by_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(user));
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(accounts));
+        account_service
+            .expect_stream_movements()
+            .times(2)
+            .returning(move |account_id, _, _| {
+                let movements: Vec<Result<Movement>> = movements
+                    .iter()
+                    .filter(|movement| movement.account_id == account_id)
+                    .cloned()
+                    .map(Ok)
+                    .collect();
+                stream::iter(movements).boxed()
+            });
+        account_service
+            .expect_find_splits()
+            .times(2)
+            .returning(move |movement_ids| {
+                Ok(splits
+                    .iter()
+                    .filter(|split| movement_ids.contains(&split.movement_id))
+                    .cloned()
+                    .collect())
+            });
+        account_service
+            .expect_find_transfers()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(vec![transfer]));
+        let mut category_service = MockCategoryService::new();
+        category_service
+            .expect_find_many_by_user_id()
+            .return_once(|_| Ok(vec![]));
+        let mut budget_service = MockBudgetService::new();
+        budget_service
+            .expect_find_many_by_user_id()
+            .return_once(|_| Ok(vec![]));
+        let mut recurring_movement_service = MockRecurringMovementService::new();
+        recurring_movement_service
+            .expect_find_many_by_user_id()
+            .return_once(|_| Ok(vec![]));
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_find_many_by_user_id()
+            .return_once(|_| Ok(vec![]));
+        let mut import_mapping_service = MockImportMappingService::new();
+        import_mapping_service
+            .expect_find_many_by_user_id()
+            .return_once(|_| Ok(vec![]));
+        let reconciliations = vec![Reconciliation {
+            id: uuid::Uuid::new_v4(),
+            account_id: accounts2[0].id,
+            statement_date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            statement_balance: Decimal::new(-5, 0),
+            finished_at: Some(Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap()),
+        }];
+        let reconciliations2 = reconciliations.clone();
+        let mut reconciliation_service = MockReconciliationService::new();
+        reconciliation_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(reconciliations));
+        let rules = vec![Rule {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "Groceries".to_string(),
+            priority: 1,
+            conditions: RuleConditions {
+                title_contains: Some("market".to_string()),
+                ..Default::default()
+            },
+            actions: RuleActions {
+                add_tags: vec!["food".to_string()],
+                ..Default::default()
+            },
+        }];
+        let rules2 = rules.clone();
+        let mut rule_service = MockRuleService::new();
+        rule_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(rules));
+        let goals = vec![Goal {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "Holidays".to_string(),
+            target_amount: Decimal::new(1000, 0),
+            currency: Currency::EUR,
+            start_date: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            deadline: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            account_ids: vec![accounts2[0].id],
+        }];
+        let goals2 = goals.clone();
+        let mut goal_service = MockGoalService::new();
+        goal_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(goals));
+        let trades = vec![Trade {
+            id: uuid::Uuid::new_v4(),
+            account_id: accounts2[1].id,
+            movement_id: movements2[1].id,
+            date: NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+            kind: TradeKind::Buy,
+            symbol: "VWCE".to_string(),
+            quantity: Decimal::new(2, 0),
+            price: Decimal::new(100, 0),
+            fees: Decimal::new(1, 0),
+        }];
+        let trades2 = trades.clone();
+        let mut trade_service = MockTradeService::new();
+        trade_service
+            .expect_find_trades()
+            .times(2)
+            .returning(move |account_id| {
+                Ok(trades
+                    .iter()
+                    .filter(|trade| trade.account_id == account_id)
+                    .cloned()
+                    .collect())
+            });
+        let prices = vec![Price {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            symbol: "VWCE".to_string(),
+            currency: Currency::EUR,
+            date: NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+            price: Decimal::new(101, 0),
+        }];
+        let prices2 = prices.clone();
+        let mut price_service = MockPriceService::new();
+        price_service
+            .expect_find_many_by_user_id()
+            .with(predicate::eq(user_id))
+            .return_once(move |_| Ok(prices));
+
+        let use_case = get_mock_use_case(
+            user_service,
+            account_service,
+            category_service,
+            budget_service,
+            recurring_movement_service,
+            exchange_rate_service,
+            import_mapping_service,
+            reconciliation_service,
+            rule_service,
+            goal_service,
+            trade_service,
+            price_service,
+        );
+
+        let result = use_case.get_takeout(user_id).await.unwrap();
+
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(result)).unwrap();
+        let mut read = |name: &str| -> serde_json::Value {
+            serde_json::from_reader(archive.by_name(name).unwrap()).unwrap()
+        };
+        assert_eq!(read("accounts.json"), serde_json::json!(accounts2));
+        assert_eq!(read("movements.json"), serde_json::json!(movements2));
+        assert_eq!(read("splits.json"), serde_json::json!(splits2));
+        assert_eq!(read("transfers.json"), serde_json::json!([transfer2]));
+        assert_eq!(read("user.json")["email"], "somebody@somebody.com");
+        assert_eq!(read("import_mappings.json"), serde_json::json!([]));
+        assert_eq!(read("prices.json"), serde_json::json!(prices2));
+        assert_eq!(read("trades.json"), serde_json::json!(trades2));
+        assert_eq!(read("goals.json"), serde_json::json!(goals2));
+        assert_eq!(read("rules.json"), serde_json::json!(rules2));
+        assert_eq!(
+            read("reconciliations.json"),
+            serde_json::json!(reconciliations2)
+        );
+    }
+}
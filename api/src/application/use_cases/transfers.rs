@@ -0,0 +1,492 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::profile::{ensure_not_reconciled, get_open_account};
+use crate::application::services::{accounts::AccountService, exchange_rates::ExchangeRateService};
+use crate::domain::entities::accounts::{Movement, MovementStatus, Transfer};
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+#[async_trait]
+pub trait TransferUseCaseTrait: Send + Sync {
+    async fn create_transfer(
+        &self,
+        user_id: Uuid,
+        from_account_id: Uuid,
+        to_account_id: Uuid,
+        title: &str,
+        amount: Decimal,
+    ) -> Result<Transfer>;
+    async fn delete_transfer(&self, user_id: Uuid, transfer_id: Uuid) -> Result<Transfer>;
+}
+
+pub struct TransferUseCase {
+    account_service: Box<dyn AccountService>,
+    exchange_rate_service: Box<dyn ExchangeRateService>,
+}
+
+impl TransferUseCase {
+    pub fn new(
+        account_service: Box<dyn AccountService>,
+        exchange_rate_service: Box<dyn ExchangeRateService>,
+    ) -> Self {
+        Self {
+            account_service,
+            exchange_rate_service,
+        }
+    }
+}
+
+#[async_trait]
+impl TransferUseCaseTrait for TransferUseCase {
+    async fn create_transfer(
+        &self,
+        user_id: Uuid,
+        from_account_id: Uuid,
+        to_account_id: Uuid,
+        title: &str,
+        amount: Decimal,
+    ) -> Result<Transfer> {
+        if from_account_id == to_account_id {
+            return Err(Error::Validation(anyhow!(
+                "cannot transfer to the same account"
+            )));
+        }
+        if amount <= Decimal::from(0) {
+            return Err(Error::Validation(anyhow!(
+                "transfer amount must be positive"
+            )));
+        }
+
+        let from =
+            get_open_account(self.account_service.as_ref(), user_id, from_account_id).await?;
+        let to = get_open_account(self.account_service.as_ref(), user_id, to_account_id).await?;
+        let amount = from.currency.validate_amount(amount)?;
+
+        let id = Uuid::new_v4();
+        let timestamp = Utc::now();
+        let (rate, rate_date) = match from.currency == to.currency {
+            true => (Decimal::from(1), None),
+            false => {
+                let rate = self
+                    .exchange_rate_service
+                    .find_latest(user_id, from.currency, to.currency, timestamp.date_naive())
+                    .await
+                    .map_err(|err| match err {
+                        Error::Repository(RepositoryErrorType::NotFound) => Error::Validation(
+                            anyhow!("no exchange rate between the account currencies"),
+                        ),
+                        err => err,
+                    })?;
+                (rate.rate, Some(rate.date))
+            }
+        };
+        let transfer = self
+            .account_service
+            .insert_transfer(Transfer {
+                id,
+                debit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: from.id,
+                    timestamp,
+                    title: title.to_string(),
+                    category_id: None,
+                    amount: -amount,
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                credit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: to.id,
+                    timestamp,
+                    title: title.to_string(),
+                    category_id: None,
+                    amount: to.currency.round(amount * rate),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                rate,
+                rate_date,
+            })
+            .await?;
+        Ok(transfer)
+    }
+
+    async fn delete_transfer(&self, user_id: Uuid, transfer_id: Uuid) -> Result<Transfer> {
+        let transfer = self
+            .account_service
+            .find_transfer(transfer_id, user_id)
+            .await?;
+        get_open_account(
+            self.account_service.as_ref(),
+            user_id,
+            transfer.debit.account_id,
+        )
+        .await?;
+        get_open_account(
+            self.account_service.as_ref(),
+            user_id,
+            transfer.credit.account_id,
+        )
+        .await?;
+        ensure_not_reconciled(&transfer.debit)?;
+        ensure_not_reconciled(&transfer.credit)?;
+        let transfer = self.account_service.delete_transfer(transfer).await?;
+        Ok(transfer)
+    }
+}
+
+#[cfg(test)]
+use mockall::*;
+#[cfg(test)]
+mock! {
+    pub TransferUseCase {}
+    #[async_trait]
+    impl TransferUseCaseTrait for TransferUseCase {
+        async fn create_transfer(
+            &self,
+            user_id: Uuid,
+            from_account_id: Uuid,
+            to_account_id: Uuid,
+            title: &str,
+            amount: Decimal,
+        ) -> Result<Transfer>;
+        async fn delete_transfer(&self, user_id: Uuid, transfer_id: Uuid) -> Result<Transfer>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use mockall::predicate;
+    use tokio;
+
+    use super::*;
+    use crate::application::services::{
+        accounts::MockAccountService, exchange_rates::MockExchangeRateService,
+    };
+    use crate::domain::entities::accounts::{Account, AccountKind, AccountTerms};
+    use crate::domain::entities::currencies::Currency;
+    use crate::domain::entities::exchange_rates::ExchangeRate;
+
+    fn get_mock_use_case(
+        account_service: MockAccountService,
+        exchange_rate_service: MockExchangeRateService,
+    ) -> TransferUseCase {
+        TransferUseCase {
+            account_service: Box::new(account_service),
+            exchange_rate_service: Box::new(exchange_rate_service),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_transfer_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let from_account_id = uuid::Uuid::new_v4();
+        let to_account_id = uuid::Uuid::new_v4();
+        let transfer_id = uuid::Uuid::new_v4();
+        let transfer = Transfer {
+            id: transfer_id,
+            debit: Movement {
+                account_id: from_account_id,
+                id: uuid::Uuid::new_v4(),
+                amount: Decimal::from(-10),
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: "title".to_string(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            credit: Movement {
+                account_id: to_account_id,
+                id: uuid::Uuid::new_v4(),
+                amount: Decimal::from(10),
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: "title".to_string(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            rate: Decimal::from(1),
+            rate_date: None,
+        };
+        let transfer2 = transfer.clone();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::always(), predicate::eq(user_id))
+            .times(2)
+            .returning(move |id, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_insert_transfer()
+            .withf(move |x: &Transfer| {
+                x.debit.account_id == from_account_id
+                    && x.debit.amount == Decimal::from(-10)
+                    && x.credit.account_id == to_account_id
+                    && x.credit.amount == Decimal::from(10)
+                    && x.debit.transfer_id == Some(x.id)
+                    && x.credit.transfer_id == Some(x.id)
+            })
+            .return_once(move |_| Ok(transfer));
+
+        let use_case = get_mock_use_case(account_service, MockExchangeRateService::new());
+
+        let result = use_case
+            .create_transfer(
+                user_id,
+                from_account_id,
+                to_account_id,
+                "title",
+                Decimal::from(10),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, transfer2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_transfer_missing_exchange_rate() {
+        let user_id = uuid::Uuid::new_v4();
+        let from_account_id = uuid::Uuid::new_v4();
+        let to_account_id = uuid::Uuid::new_v4();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::always(), predicate::eq(user_id))
+            .times(2)
+            .returning(move |id, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: match id == from_account_id {
+                        true => Currency::USD,
+                        false => Currency::EUR,
+                    },
+                    archived: false,
+                    id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_find_latest()
+            .return_once(|_, _, _, _| Err(Error::Repository(RepositoryErrorType::NotFound)));
+
+        let use_case = get_mock_use_case(account_service, exchange_rate_service);
+
+        use_case
+            .create_transfer(
+                user_id,
+                from_account_id,
+                to_account_id,
+                "title",
+                Decimal::from(10),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn create_transfer_same_account() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+
+        let use_case = get_mock_use_case(MockAccountService::new(), MockExchangeRateService::new());
+
+        use_case
+            .create_transfer(user_id, account_id, account_id, "title", Decimal::from(10))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn delete_transfer_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let transfer_id = uuid::Uuid::new_v4();
+        let transfer = Transfer {
+            id: transfer_id,
+            debit: Movement {
+                account_id: uuid::Uuid::new_v4(),
+                id: uuid::Uuid::new_v4(),
+                amount: Decimal::from(-10),
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: "title".to_string(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            credit: Movement {
+                account_id: uuid::Uuid::new_v4(),
+                id: uuid::Uuid::new_v4(),
+                amount: Decimal::from(10),
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: "title".to_string(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            rate: Decimal::from(1),
+            rate_date: None,
+        };
+        let transfer2 = transfer.clone();
+        let transfer3 = transfer.clone();
+        let transfer4 = transfer.clone();
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_transfer()
+            .with(predicate::eq(transfer_id), predicate::eq(user_id))
+            .return_once(move |_, _| Ok(transfer));
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::always(), predicate::eq(user_id))
+            .times(2)
+            .returning(move |id, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: Currency::USD,
+                    archived: false,
+                    id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_delete_transfer()
+            .with(predicate::eq(transfer2))
+            .return_once(move |_| Ok(transfer3));
+
+        let use_case = get_mock_use_case(account_service, MockExchangeRateService::new());
+
+        let result = use_case
+            .delete_transfer(user_id, transfer_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result, transfer4);
+    }
+
+    #[tokio::test]
+    async fn create_transfer_cross_currency() {
+        let user_id = uuid::Uuid::new_v4();
+        let from_account_id = uuid::Uuid::new_v4();
+        let to_account_id = uuid::Uuid::new_v4();
+        let rate = ExchangeRate {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            from: Currency::USD,
+            to: Currency::EUR,
+            rate: Decimal::new(925, 3),
+        };
+
+        let mut account_service = MockAccountService::new();
+        account_service
+            .expect_find_by_id_and_user_id()
+            .with(predicate::always(), predicate::eq(user_id))
+            .times(2)
+            .returning(move |id, _| {
+                Ok(Account {
+                    balance: Decimal::from(0),
+                    currency: match id == from_account_id {
+                        true => Currency::USD,
+                        false => Currency::EUR,
+                    },
+                    archived: false,
+                    id,
+                    user_id,
+                    name: "name".to_string(),
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+            });
+        account_service
+            .expect_insert_transfer()
+            .withf(|x: &Transfer| {
+                x.debit.amount == Decimal::from(-10)
+                    && x.credit.amount == Decimal::new(925, 2)
+                    && x.rate == Decimal::new(925, 3)
+                    && x.rate_date == NaiveDate::from_ymd_opt(2023, 1, 31)
+            })
+            .return_once(Ok);
+        let mut exchange_rate_service = MockExchangeRateService::new();
+        exchange_rate_service
+            .expect_find_latest()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(Currency::USD),
+                predicate::eq(Currency::EUR),
+                predicate::always(),
+            )
+            .return_once(move |_, _, _, _| Ok(rate));
+
+        let use_case = get_mock_use_case(account_service, exchange_rate_service);
+
+        let result = use_case
+            .create_transfer(
+                user_id,
+                from_account_id,
+                to_account_id,
+                "title",
+                Decimal::from(10),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.credit.amount, Decimal::new(925, 2));
+    }
+}
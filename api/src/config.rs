@@ -29,6 +29,13 @@ pub struct Config {
     pub smtp_password: Option<String>,
     #[serde(default = "default_smtp_secure")]
     pub smtp_secure: bool,
+    pub exchange_rates_path: Option<String>,
+    #[serde(default = "default_recurring_movements_interval_minutes")]
+    pub recurring_movements_interval_minutes: u64,
+    #[serde(default = "default_balance_check_interval_minutes")]
+    pub balance_check_interval_minutes: u64,
+    #[serde(default)]
+    pub balance_check_repair: bool,
 }
 
 impl Config {
@@ -71,6 +78,22 @@ impl Config {
         (public_key.into(), private_key.into(), expiration)
     }
 
+    pub fn get_exchange_rates_csv(&self) -> String {
+        match self.exchange_rates_path.as_deref() {
+            Some(path) => std::fs::read_to_string(path).expect("Error reading exchange rates file"),
+            None => "date,from,to,rate".to_string(),
+        }
+    }
+
+    pub fn get_recurring_movements_interval(&self) -> std::time::Duration {
+        interval(self.recurring_movements_interval_minutes)
+            .expect("Invalid recurring movements interval")
+    }
+
+    pub fn get_balance_check_interval(&self) -> std::time::Duration {
+        interval(self.balance_check_interval_minutes).expect("Invalid balance check interval")
+    }
+
     pub fn get_cors_layer(&self) -> CorsLayer {
         let cors = CorsLayer::new()
             .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
@@ -109,3 +132,17 @@ fn default_smtp_port() -> u16 {
 fn default_smtp_secure() -> bool {
     true
 }
+fn default_recurring_movements_interval_minutes() -> u64 {
+    60
+}
+fn default_balance_check_interval_minutes() -> u64 {
+    1440
+}
+
+// At least one minute, tokio intervals can't be zero
+fn interval(minutes: u64) -> Option<std::time::Duration> {
+    minutes
+        .checked_mul(60)
+        .filter(|seconds| *seconds > 0)
+        .map(std::time::Duration::from_secs)
+}
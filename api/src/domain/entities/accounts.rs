@@ -1,6 +1,11 @@
-use chrono::{DateTime, Utc};
+use anyhow::anyhow;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+use super::currencies::Currency;
+use crate::domain::error::Error;
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct Account {
@@ -8,7 +13,140 @@ pub struct Account {
     pub user_id: uuid::Uuid,
     pub name: String,
     pub balance: Decimal,
-    pub currency: CurrencyType,
+    pub currency: Currency,
+    pub archived: bool,
+    pub opening_balance: Decimal,
+    pub opening_date: Option<NaiveDate>,
+    pub kind: AccountKind,
+    pub terms: AccountTerms,
+}
+
+#[derive(Deserialize, Serialize, sqlx::Type, PartialEq, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[sqlx(type_name = "varchar", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountKind {
+    #[default]
+    Checking,
+    Savings,
+    Cash,
+    CreditCard,
+    Loan,
+    Investment,
+}
+
+impl AccountKind {
+    pub fn validate_opening_balance(self, opening_balance: Decimal) -> Result<Decimal, Error> {
+        match self {
+            AccountKind::Loan if opening_balance >= Decimal::from(0) => Err(Error::Validation(
+                anyhow!("the opening balance of a loan must be negative"),
+            )),
+            AccountKind::CreditCard if opening_balance > Decimal::from(0) => {
+                Err(Error::Validation(anyhow!(
+                    "the opening balance of a credit card cannot be positive"
+                )))
+            }
+            _ => Ok(opening_balance),
+        }
+    }
+}
+
+const MAX_APR: i64 = 100;
+const MAX_LOAN_TERM_MONTHS: i32 = 600;
+const MAX_LOAN_PRINCIPAL: i64 = 1_000_000_000_000;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct AccountTerms {
+    pub credit_limit: Option<Decimal>,
+    pub apr: Option<Decimal>,
+    pub statement_day: Option<i16>,
+    pub loan_principal: Option<Decimal>,
+    pub loan_term_months: Option<i32>,
+}
+
+impl AccountTerms {
+    pub fn validate(self, kind: AccountKind, currency: Currency) -> Result<Self, Error> {
+        let (credit_card, loan) = (kind == AccountKind::CreditCard, kind == AccountKind::Loan);
+        let fields = [
+            ("credit_limit", self.credit_limit.is_some(), credit_card),
+            ("apr", self.apr.is_some(), credit_card || loan),
+            ("statement_day", self.statement_day.is_some(), credit_card),
+            ("loan_principal", self.loan_principal.is_some(), loan),
+            ("loan_term_months", self.loan_term_months.is_some(), loan),
+        ];
+        for (name, set, allowed) in fields {
+            if set && !allowed {
+                return Err(Error::Validation(anyhow!(
+                    "{name} is not allowed for this kind of account"
+                )));
+            }
+        }
+
+        let required = match kind {
+            AccountKind::CreditCard => vec![
+                ("credit_limit", self.credit_limit.is_some()),
+                ("statement_day", self.statement_day.is_some()),
+            ],
+            AccountKind::Loan => vec![
+                ("apr", self.apr.is_some()),
+                ("loan_principal", self.loan_principal.is_some()),
+                ("loan_term_months", self.loan_term_months.is_some()),
+            ],
+            _ => vec![],
+        };
+        for (name, set) in required {
+            if !set {
+                return Err(Error::Validation(anyhow!(
+                    "{name} is required for this kind of account"
+                )));
+            }
+        }
+
+        let positive = |amount: Option<Decimal>, name: &str| match amount {
+            Some(amount) if amount <= Decimal::from(0) => {
+                Err(Error::Validation(anyhow!("{name} must be greater than 0")))
+            }
+            amount => amount
+                .map(|amount| currency.validate_amount(amount))
+                .transpose(),
+        };
+        let credit_limit = positive(self.credit_limit, "credit_limit")?;
+        let loan_principal = positive(self.loan_principal, "loan_principal")?;
+        if loan_principal.is_some_and(|principal| principal > Decimal::from(MAX_LOAN_PRINCIPAL)) {
+            return Err(Error::Validation(anyhow!(
+                "loan_principal must be at most {MAX_LOAN_PRINCIPAL}"
+            )));
+        }
+        if self
+            .apr
+            .is_some_and(|apr| apr < Decimal::from(0) || apr > Decimal::from(MAX_APR))
+        {
+            return Err(Error::Validation(anyhow!(
+                "apr must be between 0 and {MAX_APR}"
+            )));
+        }
+        if self
+            .statement_day
+            .is_some_and(|day| !(1..=28).contains(&day))
+        {
+            return Err(Error::Validation(anyhow!(
+                "statement_day must be between 1 and 28"
+            )));
+        }
+        if self
+            .loan_term_months
+            .is_some_and(|months| !(1..=MAX_LOAN_TERM_MONTHS).contains(&months))
+        {
+            return Err(Error::Validation(anyhow!(
+                "loan_term_months must be between 1 and {MAX_LOAN_TERM_MONTHS}"
+            )));
+        }
+
+        Ok(Self {
+            credit_limit,
+            loan_principal,
+            ..self
+        })
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -17,28 +155,279 @@ pub struct Movement {
     pub account_id: uuid::Uuid,
     pub timestamp: DateTime<Utc>,
     pub title: String,
-    pub category: CategoryType,
+    pub category_id: Option<uuid::Uuid>,
     pub amount: Decimal,
+    pub transfer_id: Option<uuid::Uuid>,
+    pub external_id: Option<String>,
+    pub status: MovementStatus,
+    pub note: Option<String>,
+    pub tags: Vec<String>,
+    pub fingerprint: Option<String>,
 }
 
-// What is worse, code duplication or non respecting layer segregation?
-// Choose your poison, I chose mine! (given the fact that these enums would be quite big in a real case scenario)
+const MAX_TAGS: usize = 20;
+const MAX_TAG_LENGTH: usize = 32;
 
-#[derive(Deserialize, Serialize, sqlx::Type, PartialEq, Debug, Clone)]
-#[serde(rename_all = "UPPERCASE")]
-#[sqlx(type_name = "varchar", rename_all = "UPPERCASE")]
-pub enum CurrencyType {
-    Usd,
-    Eur,
+pub fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>, Error> {
+    let mut tags = tags
+        .into_iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .collect::<Vec<_>>();
+    tags.sort();
+    tags.dedup();
+
+    if tags.len() > MAX_TAGS {
+        return Err(Error::Validation(anyhow!(
+            "a movement can have at most {MAX_TAGS} tags"
+        )));
+    }
+    for tag in &tags {
+        if tag.is_empty()
+            || tag.chars().count() > MAX_TAG_LENGTH
+            || !tag
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(Error::Validation(anyhow!("invalid tag \"{tag}\"")));
+        }
+    }
+    Ok(tags)
 }
 
-#[derive(Deserialize, Serialize, sqlx::Type, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Tag {
+    pub name: String,
+    pub count: i64,
+}
+
+#[derive(Deserialize, Serialize, sqlx::Type, PartialEq, Debug, Clone, Copy)]
 #[serde(rename_all = "UPPERCASE")]
 #[sqlx(type_name = "varchar", rename_all = "UPPERCASE")]
-pub enum CategoryType {
-    Generic,
-    Bills,
-    Shopping,
-    Income,
-    Insurance,
+pub enum MovementStatus {
+    Pending,
+    Cleared,
+    Reconciled,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct MovementSplit {
+    pub id: uuid::Uuid,
+    pub movement_id: uuid::Uuid,
+    pub category_id: uuid::Uuid,
+    pub amount: Decimal,
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SplitLine {
+    pub category_id: uuid::Uuid,
+    pub amount: Decimal,
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SplitMovement {
+    pub movement: Movement,
+    pub splits: Vec<MovementSplit>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Transfer {
+    pub id: uuid::Uuid,
+    pub debit: Movement,
+    pub credit: Movement,
+    pub rate: Decimal,
+    pub rate_date: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct BalanceDrift {
+    pub account_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub balance: Decimal,
+    pub expected: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct NetWorth {
+    pub currency: Currency,
+    pub total: Decimal,
+    pub rate_date: Option<NaiveDate>,
+    pub breakdown: Vec<NetWorthEntry>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct NetWorthEntry {
+    pub currency: Currency,
+    pub balance: Decimal,
+    pub rate: Decimal,
+    pub converted: Decimal,
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct MovementFilter {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub category_ids: Option<Vec<uuid::Uuid>>,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub title: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub exclude_transfers: bool,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct MovementCursor {
+    pub timestamp: DateTime<Utc>,
+    pub id: uuid::Uuid,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct MovementPage {
+    pub movements: Vec<Movement>,
+    pub next_cursor: Option<String>,
+    pub splits: Vec<MovementSplit>,
+}
+
+impl From<&Movement> for MovementCursor {
+    fn from(movement: &Movement) -> Self {
+        Self {
+            timestamp: movement.timestamp,
+            id: movement.id,
+        }
+    }
+}
+
+impl fmt::Display for MovementCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.timestamp.timestamp_micros(), self.id)
+    }
+}
+
+impl FromStr for MovementCursor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::Validation(anyhow!("invalid cursor"));
+
+        let (micros, id) = s.split_once('_').ok_or_else(invalid)?;
+        let micros = micros.parse::<i64>().map_err(|_| invalid())?;
+        let timestamp = Utc
+            .timestamp_opt(
+                micros.div_euclid(1_000_000),
+                (micros.rem_euclid(1_000_000) * 1_000) as u32,
+            )
+            .single()
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            timestamp,
+            id: id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_normalized() {
+        assert_eq!(
+            normalize_tags(vec![
+                " Vacation-2026".to_string(),
+                "reimbursable".to_string(),
+                "vacation-2026".to_string(),
+            ])
+            .unwrap(),
+            vec!["reimbursable".to_string(), "vacation-2026".to_string()]
+        );
+        assert!(normalize_tags(vec!["two words".to_string()]).is_err());
+        assert!(normalize_tags(vec![" ".to_string()]).is_err());
+        assert!(normalize_tags((0..21).map(|i| i.to_string()).collect()).is_err());
+    }
+
+    #[test]
+    fn terms_validated_per_kind() {
+        let card = AccountTerms {
+            credit_limit: Some(Decimal::from(2000)),
+            apr: Some(Decimal::new(1999, 2)),
+            statement_day: Some(15),
+            ..Default::default()
+        };
+        assert_eq!(
+            card.clone()
+                .validate(AccountKind::CreditCard, Currency::EUR)
+                .unwrap(),
+            card
+        );
+        assert!(card
+            .clone()
+            .validate(AccountKind::Checking, Currency::EUR)
+            .is_err());
+        assert!(AccountTerms {
+            statement_day: Some(31),
+            ..card
+        }
+        .validate(AccountKind::CreditCard, Currency::EUR)
+        .is_err());
+        assert!(AccountTerms {
+            apr: Some(Decimal::from(5)),
+            loan_principal: Some(Decimal::from(10000)),
+            ..Default::default()
+        }
+        .validate(AccountKind::Loan, Currency::EUR)
+        .is_err());
+        assert!(AccountTerms {
+            apr: Some(Decimal::from(5)),
+            loan_principal: Some(Decimal::from(MAX_LOAN_PRINCIPAL + 1)),
+            loan_term_months: Some(12),
+            ..Default::default()
+        }
+        .validate(AccountKind::Loan, Currency::EUR)
+        .is_err());
+        assert_eq!(
+            AccountTerms::default()
+                .validate(AccountKind::Savings, Currency::EUR)
+                .unwrap(),
+            AccountTerms::default()
+        );
+    }
+
+    #[test]
+    fn liability_opening_balance_sign() {
+        assert!(AccountKind::Loan
+            .validate_opening_balance(Decimal::from(-1000))
+            .is_ok());
+        assert!(AccountKind::Loan
+            .validate_opening_balance(Decimal::from(0))
+            .is_err());
+        assert!(AccountKind::CreditCard
+            .validate_opening_balance(Decimal::from(0))
+            .is_ok());
+        assert!(AccountKind::CreditCard
+            .validate_opening_balance(Decimal::from(10))
+            .is_err());
+        assert!(AccountKind::Checking
+            .validate_opening_balance(Decimal::from(10))
+            .is_ok());
+    }
+
+    #[test]
+    fn movement_cursor_roundtrip() {
+        let cursor = MovementCursor {
+            timestamp: "2023-01-31T10:00:00.123456Z".parse().unwrap(),
+            id: uuid::Uuid::new_v4(),
+        };
+        assert_eq!(
+            cursor.to_string().parse::<MovementCursor>().unwrap(),
+            cursor
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Validation")]
+    fn movement_cursor_invalid() {
+        "not-a-cursor".parse::<MovementCursor>().unwrap();
+    }
 }
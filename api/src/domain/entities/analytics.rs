@@ -0,0 +1,152 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::currencies::Currency;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Interval {
+    Day,
+    Week,
+    Month,
+}
+
+impl Interval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::Day => "day",
+            Interval::Week => "week",
+            Interval::Month => "month",
+        }
+    }
+
+    pub fn start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Interval::Day => date,
+            Interval::Week => date - Days::new(date.weekday().num_days_from_monday().into()),
+            Interval::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    pub fn periods(&self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        let mut periods = vec![];
+        let mut period = self.start(from);
+        while period < to {
+            periods.push(period);
+            let next = match self {
+                Interval::Day => period.checked_add_days(Days::new(1)),
+                Interval::Week => period.checked_add_days(Days::new(7)),
+                Interval::Month => period.checked_add_months(Months::new(1)),
+            };
+            match next {
+                Some(next) => period = next,
+                None => break,
+            }
+        }
+        periods
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct PeriodTotal {
+    pub period: NaiveDate,
+    pub currency: Currency,
+    pub income: Decimal,
+    pub expense: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CategoryTotal {
+    pub category_id: Option<uuid::Uuid>,
+    pub currency: Currency,
+    pub income: Decimal,
+    pub expense: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct TitleTotal {
+    pub title: String,
+    pub currency: Currency,
+    pub count: i64,
+    pub expense: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Analytics {
+    pub currency: Currency,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub interval: Interval,
+    pub income: Decimal,
+    pub expense: Decimal,
+    pub net: Decimal,
+    pub periods: Vec<AnalyticsPeriod>,
+    pub categories: Vec<AnalyticsCategory>,
+    pub top_titles: Vec<AnalyticsTitle>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AnalyticsPeriod {
+    pub period: NaiveDate,
+    pub income: Decimal,
+    pub expense: Decimal,
+    pub net: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AnalyticsCategory {
+    pub category_id: Option<uuid::Uuid>,
+    pub income: Decimal,
+    pub expense: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AnalyticsTitle {
+    pub title: String,
+    pub count: i64,
+    pub expense: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct BalancePoint {
+    pub period: NaiveDate,
+    pub balance: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct BalanceSeries {
+    pub account_id: uuid::Uuid,
+    pub currency: Currency,
+    pub interval: Interval,
+    pub balances: Vec<BalancePoint>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(
+            Interval::Day.periods(date(2023, 1, 30), date(2023, 2, 2)),
+            vec![date(2023, 1, 30), date(2023, 1, 31), date(2023, 2, 1)]
+        );
+        assert_eq!(
+            Interval::Week.periods(date(2023, 1, 4), date(2023, 1, 17)),
+            vec![date(2023, 1, 2), date(2023, 1, 9), date(2023, 1, 16)]
+        );
+        assert_eq!(
+            Interval::Month.periods(date(2023, 1, 15), date(2023, 3, 1)),
+            vec![date(2023, 1, 1), date(2023, 2, 1)]
+        );
+        assert_eq!(
+            Interval::Month.periods(date(2023, 3, 1), date(2023, 3, 1)),
+            vec![]
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::currencies::Currency;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Budget {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub category_id: uuid::Uuid,
+    pub month: NaiveDate,
+    pub amount: Decimal,
+    pub currency: Currency,
+    pub rollover: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Spending {
+    pub category_id: uuid::Uuid,
+    pub month: NaiveDate,
+    pub currency: Currency,
+    pub amount: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct BudgetReport {
+    pub month: NaiveDate,
+    pub threshold: u32,
+    pub entries: Vec<BudgetReportEntry>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct BudgetReportEntry {
+    pub budget_id: uuid::Uuid,
+    pub category_id: uuid::Uuid,
+    pub currency: Currency,
+    pub budgeted: Decimal,
+    pub carried_over: Decimal,
+    pub available: Decimal,
+    pub spent: Decimal,
+    pub remaining: Decimal,
+    pub over_threshold: bool,
+}
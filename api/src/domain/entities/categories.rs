@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_CATEGORIES: [&str; 5] = ["Generic", "Bills", "Shopping", "Income", "Insurance"];
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Category {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub parent_id: Option<uuid::Uuid>,
+    pub name: String,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CategoryStats {
+    pub category_id: uuid::Uuid,
+    pub movements: i64,
+    pub tokens: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct TokenCount {
+    pub category_id: uuid::Uuid,
+    pub token: String,
+    pub count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ClassifierData {
+    pub tokens: Vec<String>,
+    pub vocabulary: i64,
+    pub categories: Vec<CategoryStats>,
+    pub token_counts: Vec<TokenCount>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CategorySuggestion {
+    pub category_id: uuid::Uuid,
+    pub confidence: f64,
+}
+
+impl ClassifierData {
+    // Multinomial naive Bayes with Laplace smoothing
+    pub fn suggest(&self) -> Option<CategorySuggestion> {
+        let movements: i64 = self.categories.iter().map(|stats| stats.movements).sum();
+        if movements == 0
+            || !self
+                .token_counts
+                .iter()
+                .any(|count| self.tokens.contains(&count.token))
+        {
+            return None;
+        }
+
+        let scores = self
+            .categories
+            .iter()
+            .map(|stats| {
+                let denominator = (stats.tokens + self.vocabulary) as f64;
+                let likelihood: f64 = self
+                    .tokens
+                    .iter()
+                    .map(|token| {
+                        let count = self
+                            .token_counts
+                            .iter()
+                            .find(|count| {
+                                count.category_id == stats.category_id && &count.token == token
+                            })
+                            .map_or(0, |count| count.count);
+                        ((count + 1) as f64 / denominator).ln()
+                    })
+                    .sum();
+                let prior = (stats.movements as f64 / movements as f64).ln();
+                (stats.category_id, prior + likelihood)
+            })
+            .collect::<Vec<_>>();
+
+        let (category_id, best) = scores.iter().copied().max_by(|a, b| a.1.total_cmp(&b.1))?;
+        let total: f64 = scores.iter().map(|(_, score)| (score - best).exp()).sum();
+        Some(CategorySuggestion {
+            category_id,
+            confidence: 1.0 / total,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_data(tokens: &[&str]) -> (uuid::Uuid, uuid::Uuid, ClassifierData) {
+        let groceries = uuid::Uuid::new_v4();
+        let transport = uuid::Uuid::new_v4();
+        let counts = [
+            (groceries, "supermarket", 3),
+            (groceries, "rome", 1),
+            (transport, "train", 2),
+            (transport, "rome", 2),
+        ];
+        let data = ClassifierData {
+            tokens: tokens.iter().map(|token| token.to_string()).collect(),
+            vocabulary: 3,
+            categories: vec![
+                CategoryStats {
+                    category_id: groceries,
+                    movements: 3,
+                    tokens: 4,
+                },
+                CategoryStats {
+                    category_id: transport,
+                    movements: 2,
+                    tokens: 4,
+                },
+            ],
+            token_counts: counts
+                .into_iter()
+                .filter(|(_, token, _)| tokens.contains(token))
+                .map(|(category_id, token, count)| TokenCount {
+                    category_id,
+                    token: token.to_string(),
+                    count,
+                })
+                .collect(),
+        };
+        (groceries, transport, data)
+    }
+
+    #[test]
+    fn suggest_most_likely_category() {
+        let (groceries, _, data) = get_data(&["supermarket", "rome"]);
+        let suggestion = data.suggest().unwrap();
+        assert_eq!(suggestion.category_id, groceries);
+        assert!(suggestion.confidence > 0.5 && suggestion.confidence < 1.0);
+
+        let (_, transport, data) = get_data(&["train", "rome", "ticket"]);
+        let suggestion = data.suggest().unwrap();
+        assert_eq!(suggestion.category_id, transport);
+        assert!(suggestion.confidence > 0.5 && suggestion.confidence < 1.0);
+    }
+
+    #[test]
+    fn suggest_nothing_for_unknown_tokens() {
+        let (_, _, data) = get_data(&["salary"]);
+        assert_eq!(data.suggest(), None);
+
+        let data = ClassifierData {
+            categories: vec![],
+            ..get_data(&["supermarket"]).2
+        };
+        assert_eq!(data.suggest(), None);
+    }
+}
@@ -0,0 +1,275 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::currencies::Currency;
+
+const PAYMENT_DUE_DAYS: u64 = 21;
+const MINIMUM_PAYMENT_PERCENT: i64 = 1;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Instalment {
+    pub number: u32,
+    pub date: NaiveDate,
+    pub payment: Decimal,
+    pub interest: Decimal,
+    pub principal: Decimal,
+    pub remaining: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AmortisationSchedule {
+    pub account_id: uuid::Uuid,
+    pub currency: Currency,
+    pub principal: Decimal,
+    pub apr: Decimal,
+    pub payment: Decimal,
+    pub total_interest: Decimal,
+    pub instalments: Vec<Instalment>,
+}
+
+impl AmortisationSchedule {
+    pub fn new(
+        account_id: uuid::Uuid,
+        currency: Currency,
+        principal: Decimal,
+        apr: Decimal,
+        months: u32,
+        start: NaiveDate,
+    ) -> Self {
+        let zero = Decimal::from(0);
+        let rate = apr / Decimal::from(1200);
+        let payment = match rate == zero {
+            true => principal / Decimal::from(months),
+            false => {
+                let mut growth = Decimal::from(1);
+                for _ in 0..months {
+                    growth *= Decimal::from(1) + rate;
+                }
+                // Dividing by the growth instead of multiplying keeps long, high rate loans in range
+                principal * rate / (Decimal::from(1) - Decimal::from(1) / growth)
+            }
+        };
+        let payment = currency.round(payment);
+
+        let mut remaining = principal;
+        let mut instalments = vec![];
+        for number in 1..=months {
+            let interest = currency.round(remaining * rate);
+            let principal = match number == months {
+                true => remaining,
+                false => (payment - interest).min(remaining),
+            };
+            remaining -= principal;
+            instalments.push(Instalment {
+                number,
+                date: start
+                    .checked_add_months(Months::new(number))
+                    .unwrap_or(start),
+                payment: principal + interest,
+                interest,
+                principal,
+                remaining,
+            });
+        }
+
+        AmortisationSchedule {
+            account_id,
+            currency,
+            principal,
+            apr,
+            payment,
+            total_interest: instalments
+                .iter()
+                .map(|instalment| instalment.interest)
+                .sum(),
+            instalments,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CardStatement {
+    pub account_id: uuid::Uuid,
+    pub currency: Currency,
+    pub statement_date: NaiveDate,
+    pub due_date: NaiveDate,
+    pub statement_balance: Decimal,
+    pub minimum_payment: Decimal,
+    pub available_credit: Decimal,
+}
+
+impl CardStatement {
+    pub fn last_statement_date(statement_day: u32, today: NaiveDate) -> NaiveDate {
+        let date = today.with_day(statement_day).unwrap_or(today);
+        match date > today {
+            true => date.checked_sub_months(Months::new(1)).unwrap_or(date),
+            false => date,
+        }
+    }
+
+    pub fn new(
+        account_id: uuid::Uuid,
+        currency: Currency,
+        statement_date: NaiveDate,
+        statement_balance: Decimal,
+        balance: Decimal,
+        credit_limit: Decimal,
+        apr: Decimal,
+    ) -> Self {
+        let zero = Decimal::from(0);
+        let owed = (-statement_balance).max(zero);
+        let minimum_payment = currency
+            .round(
+                owed * Decimal::from(MINIMUM_PAYMENT_PERCENT) / Decimal::from(100)
+                    + owed * apr / Decimal::from(1200),
+            )
+            .min(owed);
+
+        CardStatement {
+            account_id,
+            currency,
+            statement_date,
+            due_date: statement_date + Days::new(PAYMENT_DUE_DAYS),
+            statement_balance: owed,
+            minimum_payment,
+            available_credit: credit_limit + balance.min(zero),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amortisation_schedule() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        let schedule = AmortisationSchedule::new(
+            uuid::Uuid::new_v4(),
+            Currency::EUR,
+            Decimal::from(10000),
+            Decimal::from(6),
+            12,
+            start,
+        );
+        assert_eq!(schedule.payment, Decimal::new(86066, 2));
+        assert_eq!(schedule.instalments.len(), 12);
+
+        let first = &schedule.instalments[0];
+        assert_eq!(first.date, NaiveDate::from_ymd_opt(2026, 2, 28).unwrap());
+        assert_eq!(first.interest, Decimal::from(50));
+        assert_eq!(first.principal, Decimal::new(81066, 2));
+        assert_eq!(first.remaining, Decimal::new(918934, 2));
+
+        let last = schedule.instalments.last().unwrap();
+        assert_eq!(last.remaining, Decimal::from(0));
+        assert_eq!(
+            schedule
+                .instalments
+                .iter()
+                .map(|instalment| instalment.principal)
+                .sum::<Decimal>(),
+            Decimal::from(10000)
+        );
+        assert_eq!(
+            schedule.total_interest,
+            schedule
+                .instalments
+                .iter()
+                .map(|instalment| instalment.payment)
+                .sum::<Decimal>()
+                - Decimal::from(10000)
+        );
+    }
+
+    #[test]
+    fn amortisation_schedule_at_maximum_terms() {
+        let principal = Decimal::from(1_000_000_000_000i64);
+        let schedule = AmortisationSchedule::new(
+            uuid::Uuid::new_v4(),
+            Currency::USD,
+            principal,
+            Decimal::from(100),
+            600,
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        );
+        assert_eq!(schedule.instalments.len(), 600);
+        assert_eq!(schedule.payment, Decimal::new(8333333333333, 2));
+        assert_eq!(
+            schedule.instalments.last().unwrap().remaining,
+            Decimal::from(0)
+        );
+        assert_eq!(
+            schedule
+                .instalments
+                .iter()
+                .map(|instalment| instalment.principal)
+                .sum::<Decimal>(),
+            principal
+        );
+    }
+
+    #[test]
+    fn amortisation_schedule_without_interest() {
+        let schedule = AmortisationSchedule::new(
+            uuid::Uuid::new_v4(),
+            Currency::EUR,
+            Decimal::from(1000),
+            Decimal::from(0),
+            3,
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        );
+        assert_eq!(schedule.payment, Decimal::new(33333, 2));
+        assert_eq!(schedule.total_interest, Decimal::from(0));
+        assert_eq!(schedule.instalments[2].payment, Decimal::new(33334, 2));
+    }
+
+    #[test]
+    fn last_statement_date() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        assert_eq!(
+            CardStatement::last_statement_date(15, today),
+            NaiveDate::from_ymd_opt(2026, 10, 15).unwrap()
+        );
+        assert_eq!(CardStatement::last_statement_date(18, today), today);
+        assert_eq!(
+            CardStatement::last_statement_date(25, today),
+            NaiveDate::from_ymd_opt(2026, 9, 25).unwrap()
+        );
+    }
+
+    #[test]
+    fn card_statement() {
+        let statement_date = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let statement = CardStatement::new(
+            uuid::Uuid::new_v4(),
+            Currency::EUR,
+            statement_date,
+            Decimal::from(-1200),
+            Decimal::from(-1500),
+            Decimal::from(2000),
+            Decimal::from(18),
+        );
+        assert_eq!(statement.statement_balance, Decimal::from(1200));
+        assert_eq!(statement.minimum_payment, Decimal::from(30));
+        assert_eq!(statement.available_credit, Decimal::from(500));
+        assert_eq!(
+            statement.due_date,
+            NaiveDate::from_ymd_opt(2026, 11, 5).unwrap()
+        );
+
+        let statement = CardStatement::new(
+            uuid::Uuid::new_v4(),
+            Currency::EUR,
+            statement_date,
+            Decimal::from(100),
+            Decimal::from(100),
+            Decimal::from(2000),
+            Decimal::from(18),
+        );
+        assert_eq!(statement.statement_balance, Decimal::from(0));
+        assert_eq!(statement.minimum_payment, Decimal::from(0));
+        assert_eq!(statement.available_credit, Decimal::from(2000));
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::anyhow;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+use crate::domain::error::Error;
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct Currency {
+    code: &'static str,
+    minor_units: u32,
+}
+
+const fn currency(code: &'static str, minor_units: u32) -> Currency {
+    Currency { code, minor_units }
+}
+
+#[rustfmt::skip]
+const CURRENCIES: &[Currency] = &[
+    currency("AED", 2), currency("AFN", 2), currency("ALL", 2), currency("AMD", 2),
+    currency("ANG", 2), currency("AOA", 2), currency("ARS", 2), currency("AUD", 2),
+    currency("AWG", 2), currency("AZN", 2), currency("BAM", 2), currency("BBD", 2),
+    currency("BDT", 2), currency("BGN", 2), currency("BHD", 3), currency("BIF", 0),
+    currency("BMD", 2), currency("BND", 2), currency("BOB", 2), currency("BRL", 2),
+    currency("BSD", 2), currency("BTN", 2), currency("BWP", 2), currency("BYN", 2),
+    currency("BZD", 2), currency("CAD", 2), currency("CDF", 2), Currency::CHF,
+    currency("CLF", 4), currency("CLP", 0), currency("CNY", 2), currency("COP", 2),
+    currency("CRC", 2), currency("CUP", 2), currency("CVE", 2), currency("CZK", 2),
+    currency("DJF", 0), currency("DKK", 2), currency("DOP", 2), currency("DZD", 2),
+    currency("EGP", 2), currency("ERN", 2), currency("ETB", 2), Currency::EUR,
+    currency("FJD", 2), currency("FKP", 2), Currency::GBP, currency("GEL", 2),
+    currency("GHS", 2), currency("GIP", 2), currency("GMD", 2), currency("GNF", 0),
+    currency("GTQ", 2), currency("GYD", 2), currency("HKD", 2), currency("HNL", 2),
+    currency("HTG", 2), currency("HUF", 2), currency("IDR", 2), currency("ILS", 2),
+    currency("INR", 2), currency("IQD", 3), currency("IRR", 2), currency("ISK", 0),
+    currency("JMD", 2), currency("JOD", 3), Currency::JPY, currency("KES", 2),
+    currency("KGS", 2), currency("KHR", 2), currency("KMF", 0), currency("KPW", 2),
+    currency("KRW", 0), currency("KWD", 3), currency("KYD", 2), currency("KZT", 2),
+    currency("LAK", 2), currency("LBP", 2), currency("LKR", 2), currency("LRD", 2),
+    currency("LSL", 2), currency("LYD", 3), currency("MAD", 2), currency("MDL", 2),
+    currency("MGA", 2), currency("MKD", 2), currency("MMK", 2), currency("MNT", 2),
+    currency("MOP", 2), currency("MRU", 2), currency("MUR", 2), currency("MVR", 2),
+    currency("MWK", 2), currency("MXN", 2), currency("MYR", 2), currency("MZN", 2),
+    currency("NAD", 2), currency("NGN", 2), currency("NIO", 2), currency("NOK", 2),
+    currency("NPR", 2), currency("NZD", 2), currency("OMR", 3), currency("PAB", 2),
+    currency("PEN", 2), currency("PGK", 2), currency("PHP", 2), currency("PKR", 2),
+    currency("PLN", 2), currency("PYG", 0), currency("QAR", 2), currency("RON", 2),
+    currency("RSD", 2), currency("RUB", 2), currency("RWF", 0), currency("SAR", 2),
+    currency("SBD", 2), currency("SCR", 2), currency("SDG", 2), currency("SEK", 2),
+    currency("SGD", 2), currency("SHP", 2), currency("SLE", 2), currency("SOS", 2),
+    currency("SRD", 2), currency("SSP", 2), currency("STN", 2), currency("SVC", 2),
+    currency("SYP", 2), currency("SZL", 2), currency("THB", 2), currency("TJS", 2),
+    currency("TMT", 2), currency("TND", 3), currency("TOP", 2), currency("TRY", 2),
+    currency("TTD", 2), currency("TWD", 2), currency("TZS", 2), currency("UAH", 2),
+    currency("UGX", 0), Currency::USD, currency("UYI", 0), currency("UYU", 2),
+    currency("UYW", 4), currency("UZS", 2), currency("VED", 2), currency("VES", 2),
+    currency("VND", 0), currency("VUV", 0), currency("WST", 2), currency("XAF", 0),
+    currency("XCD", 2), currency("XOF", 0), currency("XPF", 0), currency("YER", 2),
+    currency("ZAR", 2), currency("ZMW", 2), currency("ZWL", 2),
+];
+
+impl Currency {
+    pub const USD: Currency = currency("USD", 2);
+    pub const EUR: Currency = currency("EUR", 2);
+    pub const GBP: Currency = currency("GBP", 2);
+    pub const CHF: Currency = currency("CHF", 2);
+    pub const JPY: Currency = currency("JPY", 0);
+
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    pub fn round(&self, amount: Decimal) -> Decimal {
+        amount.round_dp_with_strategy(self.minor_units, RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    pub fn format(&self, amount: Decimal) -> String {
+        let mut amount = self.round(amount);
+        amount.rescale(self.minor_units);
+        amount.to_string()
+    }
+
+    pub fn validate_amount(&self, amount: Decimal) -> Result<Decimal, Error> {
+        match amount.normalize().scale() > self.minor_units {
+            true => Err(Error::Validation(anyhow!(
+                "{} amounts allow at most {} decimals",
+                self.code,
+                self.minor_units
+            ))),
+            false => Ok(amount),
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+impl FromStr for Currency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.code == s)
+            .copied()
+            .ok_or_else(|| Error::Validation(anyhow!("invalid currency {s}")))
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_currency() {
+        assert_eq!("JPY".parse::<Currency>().unwrap(), Currency::JPY);
+        assert_eq!("KWD".parse::<Currency>().unwrap().to_string(), "KWD");
+    }
+
+    #[test]
+    #[should_panic(expected = "Validation")]
+    fn parse_currency_invalid() {
+        "usd".parse::<Currency>().unwrap();
+    }
+
+    #[test]
+    fn round_per_currency() {
+        assert_eq!(
+            Currency::JPY.round(Decimal::new(1005, 1)),
+            Decimal::from(101)
+        );
+        assert_eq!(
+            Currency::USD.round(Decimal::new(10005, 3)),
+            Decimal::new(1001, 2)
+        );
+    }
+
+    #[test]
+    fn validate_amount_per_currency() {
+        assert!(Currency::JPY.validate_amount(Decimal::new(1000, 1)).is_ok());
+        assert!(Currency::JPY
+            .validate_amount(Decimal::new(1005, 1))
+            .is_err());
+        assert!(Currency::USD.validate_amount(Decimal::new(1005, 2)).is_ok());
+        assert!(Currency::USD
+            .validate_amount(Decimal::new(1005, 3))
+            .is_err());
+    }
+
+    #[test]
+    fn format_per_currency() {
+        assert_eq!(Currency::USD.format(Decimal::new(105, 1)), "10.50");
+        assert_eq!(Currency::USD.format(Decimal::new(-4, 0)), "-4.00");
+        assert_eq!(Currency::JPY.format(Decimal::new(10500, 2)), "105");
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::currencies::Currency;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ExchangeRate {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub date: NaiveDate,
+    pub from: Currency,
+    pub to: Currency,
+    pub rate: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Quote {
+    pub date: NaiveDate,
+    pub from: Currency,
+    pub to: Currency,
+    pub rate: Decimal,
+}
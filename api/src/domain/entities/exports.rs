@@ -0,0 +1,393 @@
+use anyhow::anyhow;
+use chrono::{DateTime, SecondsFormat, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::accounts::{Account, Movement};
+use crate::domain::error::Error;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Ofx,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Json => "application/json",
+            ExportFormat::Ofx => "application/x-ofx",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Ofx => "ofx",
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+struct ExportRow<'a> {
+    id: Uuid,
+    timestamp: String,
+    title: &'a str,
+    amount: String,
+    currency: &'static str,
+    category: Option<&'a str>,
+    transfer_id: Option<Uuid>,
+}
+
+pub struct Exporter {
+    pub format: ExportFormat,
+    pub account: Account,
+    pub categories: HashMap<Uuid, String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub balance: Decimal,
+    pub generated_at: DateTime<Utc>,
+}
+
+fn iso_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn ofx_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.format("%Y%m%d%H%M%S[0:GMT]").to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+const CSV_HEADER: [&str; 7] = [
+    "id",
+    "timestamp",
+    "title",
+    "amount",
+    "currency",
+    "category",
+    "transfer_id",
+];
+
+impl Exporter {
+    pub fn header(&self) -> Result<String, Error> {
+        match self.format {
+            ExportFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+                writer
+                    .write_record(CSV_HEADER)
+                    .map_err(|err| Error::External(err.into()))?;
+                Self::into_string(writer)
+            }
+            ExportFormat::Json => Ok("[".to_string()),
+            ExportFormat::Ofx => Ok(format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<?OFX OFXHEADER="200" VERSION="220" SECURITY="NONE" OLDFILEUID="NONE" NEWFILEUID="NONE"?>
+<OFX>
+<SIGNONMSGSRSV1>
+<SONRS>
+<STATUS><CODE>0</CODE><SEVERITY>INFO</SEVERITY></STATUS>
+<DTSERVER>{generated_at}</DTSERVER>
+<LANGUAGE>ENG</LANGUAGE>
+</SONRS>
+</SIGNONMSGSRSV1>
+<BANKMSGSRSV1>
+<STMTTRNRS>
+<TRNUID>0</TRNUID>
+<STATUS><CODE>0</CODE><SEVERITY>INFO</SEVERITY></STATUS>
+<STMTRS>
+<CURDEF>{currency}</CURDEF>
+<BANKACCTFROM>
+<BANKID>finance-app</BANKID>
+<ACCTID>{account_id}</ACCTID>
+<ACCTTYPE>CHECKING</ACCTTYPE>
+</BANKACCTFROM>
+<BANKTRANLIST>
+<DTSTART>{from}</DTSTART>
+<DTEND>{to}</DTEND>
+"#,
+                generated_at = ofx_timestamp(self.generated_at),
+                currency = self.account.currency,
+                account_id = self.account.id,
+                from = ofx_timestamp(self.from.unwrap_or_default()),
+                to = ofx_timestamp(self.to.unwrap_or(self.generated_at)),
+            )),
+        }
+    }
+
+    pub fn row(&self, movement: &Movement, index: usize) -> Result<String, Error> {
+        let currency = self.account.currency;
+        match self.format {
+            ExportFormat::Csv | ExportFormat::Json => {
+                let row = ExportRow {
+                    id: movement.id,
+                    timestamp: iso_timestamp(movement.timestamp),
+                    title: &movement.title,
+                    amount: currency.format(movement.amount),
+                    currency: currency.code(),
+                    category: movement
+                        .category_id
+                        .and_then(|id| self.categories.get(&id))
+                        .map(String::as_str),
+                    transfer_id: movement.transfer_id,
+                };
+                match self.format {
+                    ExportFormat::Csv => {
+                        let mut writer = csv::WriterBuilder::new()
+                            .has_headers(false)
+                            .from_writer(vec![]);
+                        writer
+                            .serialize(row)
+                            .map_err(|err| Error::External(err.into()))?;
+                        Self::into_string(writer)
+                    }
+                    _ => {
+                        let row = serde_json::to_string(&row)
+                            .map_err(|err| Error::External(err.into()))?;
+                        Ok(match index {
+                            0 => row,
+                            _ => format!(",{row}"),
+                        })
+                    }
+                }
+            }
+            ExportFormat::Ofx => {
+                let transaction_type = match movement.amount.is_sign_negative() {
+                    true => "DEBIT",
+                    false => "CREDIT",
+                };
+                // NAME is limited to 32 characters, the full title is kept in MEMO
+                let name: String = movement.title.chars().take(32).collect();
+                Ok(format!(
+                    "<STMTTRN><TRNTYPE>{transaction_type}</TRNTYPE><DTPOSTED>{}</DTPOSTED><TRNAMT>{}</TRNAMT><FITID>{}</FITID><NAME>{}</NAME><MEMO>{}</MEMO></STMTTRN>\n",
+                    ofx_timestamp(movement.timestamp),
+                    currency.format(movement.amount),
+                    escape(movement.external_id.as_deref().unwrap_or(&movement.id.to_string())),
+                    escape(&name),
+                    escape(&movement.title),
+                ))
+            }
+        }
+    }
+
+    pub fn footer(&self) -> String {
+        match self.format {
+            ExportFormat::Csv => "".to_string(),
+            ExportFormat::Json => "]".to_string(),
+            ExportFormat::Ofx => format!(
+                r#"</BANKTRANLIST>
+<LEDGERBAL>
+<BALAMT>{}</BALAMT>
+<DTASOF>{}</DTASOF>
+</LEDGERBAL>
+</STMTRS>
+</STMTTRNRS>
+</BANKMSGSRSV1>
+</OFX>
+"#,
+                self.account.currency.format(self.balance),
+                ofx_timestamp(self.to.unwrap_or(self.generated_at)),
+            ),
+        }
+    }
+
+    fn into_string(writer: csv::Writer<Vec<u8>>) -> Result<String, Error> {
+        let data = writer
+            .into_inner()
+            .map_err(|err| Error::External(anyhow!("{err}")))?;
+        String::from_utf8(data).map_err(|err| Error::External(err.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::domain::entities::accounts::{AccountKind, AccountTerms, MovementStatus};
+    use crate::domain::entities::currencies::Currency;
+    use crate::domain::entities::statements::StatementFormat;
+
+    fn get_exporter(format: ExportFormat, category_id: Uuid) -> Exporter {
+        Exporter {
+            format,
+            account: Account {
+                id: Uuid::new_v4(),
+                user_id: Uuid::new_v4(),
+                name: "name".to_string(),
+                balance: Decimal::new(1005, 1),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            },
+            categories: HashMap::from([(category_id, "Food, groceries".to_string())]),
+            from: None,
+            to: None,
+            balance: Decimal::new(1005, 1),
+            generated_at: Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    fn get_movements(category_id: Uuid) -> Vec<Movement> {
+        vec![
+            Movement {
+                id: Uuid::new_v4(),
+                account_id: Uuid::new_v4(),
+                timestamp: Utc.with_ymd_and_hms(2023, 1, 5, 10, 30, 0).unwrap(),
+                title: "Grocery \"store\" & co".to_string(),
+                category_id: Some(category_id),
+                amount: Decimal::new(-105, 1),
+                transfer_id: None,
+                external_id: Some("FITID-1".to_string()),
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            Movement {
+                id: Uuid::new_v4(),
+                account_id: Uuid::new_v4(),
+                timestamp: Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap(),
+                title: "Salary".to_string(),
+                category_id: None,
+                amount: Decimal::from(2500),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+        ]
+    }
+
+    fn export(exporter: &Exporter, movements: &[Movement]) -> String {
+        let mut data = exporter.header().unwrap();
+        for (index, movement) in movements.iter().enumerate() {
+            data += &exporter.row(movement, index).unwrap();
+        }
+        data + &exporter.footer()
+    }
+
+    #[test]
+    fn export_csv() {
+        let category_id = Uuid::new_v4();
+        let movements = get_movements(category_id);
+
+        let data = export(&get_exporter(ExportFormat::Csv, category_id), &movements);
+
+        assert_eq!(
+            data,
+            format!(
+                "id,timestamp,title,amount,currency,category,transfer_id\n\
+                {},2023-01-05T10:30:00Z,\"Grocery \"\"store\"\" & co\",-10.50,USD,\"Food, groceries\",\n\
+                {},2023-01-31T00:00:00Z,Salary,2500.00,USD,,\n",
+                movements[0].id, movements[1].id
+            )
+        );
+    }
+
+    #[test]
+    fn export_json() {
+        let category_id = Uuid::new_v4();
+        let movements = get_movements(category_id);
+
+        let data = export(&get_exporter(ExportFormat::Json, category_id), &movements);
+
+        let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+        assert_eq!(
+            data,
+            serde_json::json!([
+                {
+                    "id": movements[0].id,
+                    "timestamp": "2023-01-05T10:30:00Z",
+                    "title": "Grocery \"store\" & co",
+                    "amount": "-10.50",
+                    "currency": "USD",
+                    "category": "Food, groceries",
+                    "transfer_id": null
+                },
+                {
+                    "id": movements[1].id,
+                    "timestamp": "2023-01-31T00:00:00Z",
+                    "title": "Salary",
+                    "amount": "2500.00",
+                    "currency": "USD",
+                    "category": null,
+                    "transfer_id": null
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn export_json_empty() {
+        let exporter = get_exporter(ExportFormat::Json, Uuid::new_v4());
+        assert_eq!(export(&exporter, &[]), "[]");
+    }
+
+    #[test]
+    fn export_ofx_can_be_imported() {
+        let category_id = Uuid::new_v4();
+        let movements = get_movements(category_id);
+
+        let data = export(&get_exporter(ExportFormat::Ofx, category_id), &movements);
+
+        let entries = StatementFormat::Ofx.parse(&data).unwrap();
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| (
+                    entry.external_id.clone(),
+                    entry.title.as_str(),
+                    entry.amount,
+                    entry.currency
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "FITID-1".to_string(),
+                    "Grocery \"store\" & co",
+                    Decimal::new(-1050, 2),
+                    Some(Currency::USD)
+                ),
+                (
+                    movements[1].id.to_string(),
+                    "Salary",
+                    Decimal::new(250000, 2),
+                    Some(Currency::USD)
+                ),
+            ]
+        );
+        assert!(data.contains("<BALAMT>100.50</BALAMT>"));
+        assert!(data.contains("<DTASOF>20230201000000[0:GMT]</DTASOF>"));
+    }
+
+    #[test]
+    fn export_ofx_balance_as_of_to() {
+        let exporter = Exporter {
+            to: Some(Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap()),
+            balance: Decimal::from(40),
+            ..get_exporter(ExportFormat::Ofx, Uuid::new_v4())
+        };
+
+        let footer = exporter.footer();
+
+        assert!(footer.contains("<BALAMT>40.00</BALAMT>"));
+        assert!(footer.contains("<DTASOF>20230115000000[0:GMT]</DTASOF>"));
+    }
+}
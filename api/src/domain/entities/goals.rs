@@ -0,0 +1,140 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::currencies::Currency;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Goal {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub name: String,
+    pub target_amount: Decimal,
+    pub currency: Currency,
+    pub start_date: NaiveDate,
+    pub deadline: NaiveDate,
+    pub account_ids: Vec<uuid::Uuid>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GoalStatus {
+    Achieved,
+    OnTrack,
+    Behind,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct GoalProgress {
+    pub goal: Goal,
+    pub saved: Decimal,
+    pub remaining: Decimal,
+    pub percent_complete: Decimal,
+    pub months_left: u32,
+    pub monthly_contribution: Decimal,
+    pub status: GoalStatus,
+}
+
+impl GoalProgress {
+    pub fn new(goal: Goal, saved: Decimal, today: NaiveDate) -> Self {
+        let zero = Decimal::from(0);
+        let hundred = Decimal::from(100);
+        let remaining = (goal.target_amount - saved).max(zero);
+        let percent_complete = match goal.target_amount > zero {
+            true => (saved * hundred / goal.target_amount)
+                .clamp(zero, hundred)
+                .round_dp(2),
+            false => hundred,
+        };
+
+        let months_left = match today <= goal.deadline {
+            true => {
+                let months = |date: NaiveDate| date.year() * 12 + date.month0() as i32;
+                (months(goal.deadline) - months(today) + 1) as u32
+            }
+            false => 0,
+        };
+        let monthly_contribution = match months_left {
+            0 => remaining,
+            months => goal.currency.round(remaining / Decimal::from(months)),
+        };
+
+        let status = match remaining == zero {
+            true => GoalStatus::Achieved,
+            false if today > goal.deadline => GoalStatus::Behind,
+            false => {
+                let total = (goal.deadline - goal.start_date).num_days().max(1);
+                let elapsed = (today - goal.start_date).num_days().clamp(0, total);
+                let expected = goal.target_amount * Decimal::from(elapsed) / Decimal::from(total);
+                match saved >= goal.currency.round(expected) {
+                    true => GoalStatus::OnTrack,
+                    false => GoalStatus::Behind,
+                }
+            }
+        };
+
+        GoalProgress {
+            goal,
+            saved,
+            remaining,
+            percent_complete,
+            months_left,
+            monthly_contribution,
+            status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_goal() -> Goal {
+        Goal {
+            id: uuid::Uuid::new_v4(),
+            user_id: uuid::Uuid::new_v4(),
+            name: "Emergency fund".to_string(),
+            target_amount: Decimal::from(5000),
+            currency: Currency::EUR,
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            deadline: NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+            account_ids: vec![],
+        }
+    }
+
+    #[test]
+    fn progress_on_track() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let progress = GoalProgress::new(get_goal(), Decimal::from(4400), today);
+        assert_eq!(progress.remaining, Decimal::from(600));
+        assert_eq!(progress.percent_complete, Decimal::from(88));
+        assert_eq!(progress.months_left, 3);
+        assert_eq!(progress.monthly_contribution, Decimal::from(200));
+        assert_eq!(progress.status, GoalStatus::OnTrack);
+    }
+
+    #[test]
+    fn progress_behind() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let progress = GoalProgress::new(get_goal(), Decimal::from(1000), today);
+        assert_eq!(progress.percent_complete, Decimal::from(20));
+        assert_eq!(progress.monthly_contribution, Decimal::new(133333, 2));
+        assert_eq!(progress.status, GoalStatus::Behind);
+
+        let today = NaiveDate::from_ymd_opt(2027, 1, 1).unwrap();
+        let progress = GoalProgress::new(get_goal(), Decimal::from(4900), today);
+        assert_eq!(progress.months_left, 0);
+        assert_eq!(progress.monthly_contribution, Decimal::from(100));
+        assert_eq!(progress.status, GoalStatus::Behind);
+    }
+
+    #[test]
+    fn progress_achieved() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let progress = GoalProgress::new(get_goal(), Decimal::from(6000), today);
+        assert_eq!(progress.remaining, Decimal::from(0));
+        assert_eq!(progress.percent_complete, Decimal::from(100));
+        assert_eq!(progress.monthly_contribution, Decimal::from(0));
+        assert_eq!(progress.status, GoalStatus::Achieved);
+    }
+}
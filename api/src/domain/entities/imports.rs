@@ -0,0 +1,289 @@
+use anyhow::anyhow;
+use chrono::format::{Item, StrftimeItems};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::accounts::Movement;
+use crate::domain::error::Error;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CsvFormat {
+    pub delimiter: char,
+    pub date_column: String,
+    pub description_column: String,
+    pub amount_column: Option<String>,
+    pub debit_column: Option<String>,
+    pub credit_column: Option<String>,
+    pub date_format: String,
+    pub decimal_separator: char,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ImportMapping {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub name: String,
+    #[serde(flatten)]
+    pub format: CsvFormat,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ImportRow {
+    pub date: NaiveDate,
+    pub title: String,
+    pub amount: Decimal,
+    pub duplicate: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ImportResult {
+    pub movements: Vec<Movement>,
+    pub skipped: usize,
+}
+
+pub fn fingerprint(date: NaiveDate, amount: Decimal, title: &str) -> String {
+    format!(
+        "{date}|{}|{}",
+        amount.normalize(),
+        title
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    )
+}
+
+impl CsvFormat {
+    pub fn validate(&self) -> Result<(), Error> {
+        if !self.delimiter.is_ascii() || self.delimiter == self.decimal_separator {
+            return Err(Error::Validation(anyhow!(
+                "delimiter must be an ascii character other than the decimal separator"
+            )));
+        }
+        if !matches!(self.decimal_separator, '.' | ',') {
+            return Err(Error::Validation(anyhow!(
+                "decimal separator must be . or ,"
+            )));
+        }
+        if self.amount_column.is_some()
+            == (self.debit_column.is_some() || self.credit_column.is_some())
+        {
+            return Err(Error::Validation(anyhow!(
+                "either an amount column or debit and credit columns are required"
+            )));
+        }
+        if StrftimeItems::new(&self.date_format).any(|item| item == Item::Error) {
+            return Err(Error::Validation(anyhow!("invalid date format")));
+        }
+        Ok(())
+    }
+
+    fn parse_amount(&self, value: &str) -> Result<Option<Decimal>, String> {
+        let thousands_separator = match self.decimal_separator {
+            ',' => '.',
+            _ => ',',
+        };
+        let value: String = value
+            .chars()
+            .filter(|c| *c != thousands_separator && !c.is_whitespace())
+            .map(|c| match c == self.decimal_separator {
+                true => '.',
+                false => c,
+            })
+            .collect();
+        match value.is_empty() {
+            true => Ok(None),
+            false => Decimal::from_str(&value)
+                .map(Some)
+                .map_err(|_| format!("invalid amount {value}")),
+        }
+    }
+
+    pub fn parse(&self, csv: &str) -> Result<Vec<ImportRow>, Error> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter as u8)
+            .trim(csv::Trim::All)
+            .flexible(true)
+            .from_reader(csv.as_bytes());
+
+        let headers = reader
+            .headers()
+            .map_err(|err| Error::Validation(anyhow!("invalid csv header: {err}")))?
+            .clone();
+        let column = |name: &Option<String>| -> Result<Option<usize>, Error> {
+            name.as_deref()
+                .map(|name| {
+                    headers
+                        .iter()
+                        .position(|header| header == name)
+                        .ok_or_else(|| Error::Validation(anyhow!("column {name} not found")))
+                })
+                .transpose()
+        };
+        let date_column = column(&Some(self.date_column.clone()))?.unwrap_or_default();
+        let description_column =
+            column(&Some(self.description_column.clone()))?.unwrap_or_default();
+        let amount_column = column(&self.amount_column)?;
+        let debit_column = column(&self.debit_column)?;
+        let credit_column = column(&self.credit_column)?;
+
+        let mut rows = vec![];
+        for (line, record) in reader.records().enumerate() {
+            let invalid = |err: String| Error::Validation(anyhow!("{err} at line {}", line + 2));
+            let record = record.map_err(|err| invalid(err.to_string()))?;
+            let field = |index: Option<usize>| index.and_then(|index| record.get(index));
+
+            let date = field(Some(date_column)).unwrap_or_default();
+            let date = NaiveDate::parse_from_str(date, &self.date_format)
+                .map_err(|_| invalid(format!("invalid date {date}")))?;
+            let amount = match amount_column {
+                Some(_) => self
+                    .parse_amount(field(amount_column).unwrap_or_default())
+                    .map_err(invalid)?,
+                None => {
+                    let debit = self
+                        .parse_amount(field(debit_column).unwrap_or_default())
+                        .map_err(invalid)?;
+                    let credit = self
+                        .parse_amount(field(credit_column).unwrap_or_default())
+                        .map_err(invalid)?;
+                    match (debit, credit) {
+                        (None, None) => None,
+                        (debit, credit) => {
+                            Some(credit.unwrap_or_default() - debit.unwrap_or_default().abs())
+                        }
+                    }
+                }
+            }
+            .ok_or_else(|| invalid("missing amount".to_string()))?;
+
+            rows.push(ImportRow {
+                date,
+                title: field(Some(description_column))
+                    .unwrap_or_default()
+                    .to_string(),
+                amount,
+                duplicate: false,
+            });
+        }
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_format() -> CsvFormat {
+        CsvFormat {
+            delimiter: ';',
+            date_column: "Date".to_string(),
+            description_column: "Description".to_string(),
+            amount_column: None,
+            debit_column: Some("Debit".to_string()),
+            credit_column: Some("Credit".to_string()),
+            date_format: "%d/%m/%Y".to_string(),
+            decimal_separator: ',',
+        }
+    }
+
+    #[test]
+    fn parse_debit_credit() {
+        let csv = "Date;Description;Debit;Credit\n\
+            31/01/2023;Groceries;1.234,50;\n\
+            01/02/2023; Salary ;;2000\n";
+        assert_eq!(
+            get_format().parse(csv).unwrap(),
+            vec![
+                ImportRow {
+                    date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                    title: "Groceries".to_string(),
+                    amount: Decimal::new(-123450, 2),
+                    duplicate: false,
+                },
+                ImportRow {
+                    date: NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                    title: "Salary".to_string(),
+                    amount: Decimal::from(2000),
+                    duplicate: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_amount_column() {
+        let format = CsvFormat {
+            delimiter: ',',
+            amount_column: Some("Amount".to_string()),
+            debit_column: None,
+            credit_column: None,
+            date_format: "%Y-%m-%d".to_string(),
+            decimal_separator: '.',
+            ..get_format()
+        };
+        let csv = "Date,Description,Amount\n2023-01-31,Rent,\"-1,000.00\"\n";
+        assert_eq!(
+            format.parse(csv).unwrap(),
+            vec![ImportRow {
+                date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                title: "Rent".to_string(),
+                amount: Decimal::from(-1000),
+                duplicate: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_rows() {
+        let format = get_format();
+        assert!(format.parse("Date;Label;Debit;Credit\n").is_err());
+        assert!(format
+            .parse("Date;Description;Debit;Credit\n2023-01-31;Rent;10;\n")
+            .is_err());
+        assert!(format
+            .parse("Date;Description;Debit;Credit\n31/01/2023;Rent;;\n")
+            .is_err());
+        assert!(format
+            .parse("Date;Description;Debit;Credit\n31/01/2023;Rent;ten;\n")
+            .is_err());
+    }
+
+    #[test]
+    fn validate_format() {
+        assert!(get_format().validate().is_ok());
+        assert!(CsvFormat {
+            amount_column: Some("Amount".to_string()),
+            ..get_format()
+        }
+        .validate()
+        .is_err());
+        assert!(CsvFormat {
+            decimal_separator: ' ',
+            ..get_format()
+        }
+        .validate()
+        .is_err());
+        assert!(CsvFormat {
+            date_format: "%Q".to_string(),
+            ..get_format()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn fingerprint_ignores_case_and_spacing() {
+        let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        assert_eq!(
+            fingerprint(date, Decimal::new(-1000, 2), "  Coffee  Shop"),
+            fingerprint(date, Decimal::from(-10), "coffee shop")
+        );
+        assert_ne!(
+            fingerprint(date, Decimal::from(-10), "coffee shop"),
+            fingerprint(date, Decimal::from(-11), "coffee shop")
+        );
+    }
+}
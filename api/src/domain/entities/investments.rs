@@ -0,0 +1,422 @@
+use anyhow::anyhow;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+
+use super::accounts::Account;
+use super::currencies::Currency;
+use crate::domain::error::Error;
+
+const MAX_SYMBOL_LENGTH: usize = 16;
+const MAX_UNIT_DECIMALS: u32 = 8;
+
+#[derive(Deserialize, Serialize, sqlx::Type, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[sqlx(type_name = "varchar", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TradeKind {
+    Buy,
+    Sell,
+    Dividend,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Trade {
+    pub id: uuid::Uuid,
+    pub account_id: uuid::Uuid,
+    pub movement_id: uuid::Uuid,
+    pub date: NaiveDate,
+    pub kind: TradeKind,
+    pub symbol: String,
+    pub quantity: Decimal,
+    pub price: Decimal,
+    pub fees: Decimal,
+}
+
+impl Trade {
+    pub fn validate(self, currency: Currency) -> Result<Self, Error> {
+        let zero = Decimal::from(0);
+        let symbol = normalize_symbol(&self.symbol)?;
+        match self.kind {
+            TradeKind::Dividend if self.quantity != zero => {
+                return Err(Error::Validation(anyhow!("dividends have no quantity")));
+            }
+            TradeKind::Buy | TradeKind::Sell if self.quantity <= zero => {
+                return Err(Error::Validation(anyhow!(
+                    "quantity must be greater than 0"
+                )));
+            }
+            _ => {}
+        }
+        if self.quantity.normalize().scale() > MAX_UNIT_DECIMALS {
+            return Err(Error::Validation(anyhow!(
+                "quantity allows at most {MAX_UNIT_DECIMALS} decimals"
+            )));
+        }
+        let price = match self.kind {
+            TradeKind::Dividend => {
+                validate_price(self.price).and_then(|price| currency.validate_amount(price))?
+            }
+            _ => validate_price(self.price)?,
+        };
+        if self.fees < zero {
+            return Err(Error::Validation(anyhow!("fees must not be negative")));
+        }
+        let fees = currency.validate_amount(self.fees)?;
+        Ok(Trade {
+            symbol,
+            price,
+            fees,
+            ..self
+        })
+    }
+
+    pub fn amount(&self, currency: Currency) -> Decimal {
+        match self.kind {
+            TradeKind::Buy => -currency.round(self.quantity * self.price + self.fees),
+            TradeKind::Sell => currency.round(self.quantity * self.price - self.fees),
+            TradeKind::Dividend => self.price - self.fees,
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match self.kind {
+            TradeKind::Buy => format!("Buy {} {}", self.quantity.normalize(), self.symbol),
+            TradeKind::Sell => format!("Sell {} {}", self.quantity.normalize(), self.symbol),
+            TradeKind::Dividend => format!("Dividend {}", self.symbol),
+        }
+    }
+}
+
+pub fn normalize_symbol(symbol: &str) -> Result<String, Error> {
+    let symbol = symbol.trim().to_uppercase();
+    if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LENGTH {
+        return Err(Error::Validation(anyhow!(
+            "symbol must be between 1 and {MAX_SYMBOL_LENGTH} characters"
+        )));
+    }
+    if !symbol
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+    {
+        return Err(Error::Validation(anyhow!(
+            "symbols can only contain letters, digits, '.' and '-'"
+        )));
+    }
+    Ok(symbol)
+}
+
+pub fn validate_price(price: Decimal) -> Result<Decimal, Error> {
+    if price <= Decimal::from(0) {
+        return Err(Error::Validation(anyhow!("price must be greater than 0")));
+    }
+    if price.normalize().scale() > MAX_UNIT_DECIMALS {
+        return Err(Error::Validation(anyhow!(
+            "price allows at most {MAX_UNIT_DECIMALS} decimals"
+        )));
+    }
+    Ok(price)
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Price {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub symbol: String,
+    pub currency: Currency,
+    pub date: NaiveDate,
+    pub price: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct PriceQuote {
+    pub date: NaiveDate,
+    pub symbol: String,
+    pub currency: Currency,
+    pub price: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CostMethod {
+    #[default]
+    Fifo,
+    Average,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Holding {
+    pub symbol: String,
+    pub quantity: Decimal,
+    pub cost_basis: Decimal,
+    pub price: Option<Decimal>,
+    pub price_date: Option<NaiveDate>,
+    pub market_value: Decimal,
+    pub unrealised_gain: Decimal,
+    pub realised_gain: Decimal,
+    pub dividends: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Portfolio {
+    pub account_id: uuid::Uuid,
+    pub currency: Currency,
+    pub method: CostMethod,
+    pub cash: Decimal,
+    pub market_value: Decimal,
+    pub total: Decimal,
+    pub cost_basis: Decimal,
+    pub unrealised_gain: Decimal,
+    pub realised_gain: Decimal,
+    pub dividends: Decimal,
+    pub holdings: Vec<Holding>,
+}
+
+struct Lot {
+    quantity: Decimal,
+    cost: Decimal,
+}
+
+#[derive(Default)]
+struct Position {
+    lots: VecDeque<Lot>,
+    realised_gain: Decimal,
+    dividends: Decimal,
+}
+
+impl Portfolio {
+    // On the same day buys come before sells, prices must be in the account currency
+    pub fn new(
+        account: &Account,
+        mut trades: Vec<Trade>,
+        prices: &[Price],
+        method: CostMethod,
+    ) -> Result<Self, Error> {
+        let zero = Decimal::from(0);
+        let currency = account.currency;
+        trades.sort_by_key(|trade| (trade.date, trade.kind == TradeKind::Sell));
+
+        let mut positions: BTreeMap<String, Position> = BTreeMap::new();
+        for trade in trades {
+            let amount = trade.amount(currency);
+            let position = positions.entry(trade.symbol.clone()).or_default();
+            match trade.kind {
+                TradeKind::Buy => {
+                    let lot = Lot {
+                        quantity: trade.quantity,
+                        cost: -amount,
+                    };
+                    match (method, position.lots.back_mut()) {
+                        (CostMethod::Average, Some(pool)) => {
+                            pool.quantity += lot.quantity;
+                            pool.cost += lot.cost;
+                        }
+                        _ => position.lots.push_back(lot),
+                    }
+                }
+                TradeKind::Sell => {
+                    let mut quantity = trade.quantity;
+                    let mut cost = zero;
+                    while quantity > zero {
+                        let lot = position.lots.front_mut().ok_or_else(|| {
+                            Error::Validation(anyhow!(
+                                "cannot sell more {} than held on {}",
+                                trade.symbol,
+                                trade.date
+                            ))
+                        })?;
+                        let sold = quantity.min(lot.quantity);
+                        let lot_cost = lot.cost * sold / lot.quantity;
+                        lot.quantity -= sold;
+                        lot.cost -= lot_cost;
+                        if lot.quantity == zero {
+                            position.lots.pop_front();
+                        }
+                        quantity -= sold;
+                        cost += lot_cost;
+                    }
+                    position.realised_gain += amount - cost;
+                }
+                TradeKind::Dividend => position.dividends += amount,
+            }
+        }
+
+        let holdings: Vec<Holding> = positions
+            .into_iter()
+            .map(|(symbol, position)| {
+                let quantity: Decimal = position.lots.iter().map(|lot| lot.quantity).sum();
+                let cost_basis = currency.round(position.lots.iter().map(|lot| lot.cost).sum());
+                let price = prices.iter().find(|price| price.symbol == symbol);
+                let market_value = match price {
+                    Some(price) => currency.round(quantity * price.price),
+                    None => cost_basis,
+                };
+                Holding {
+                    symbol,
+                    quantity,
+                    cost_basis,
+                    price: price.map(|price| price.price),
+                    price_date: price.map(|price| price.date),
+                    market_value,
+                    unrealised_gain: market_value - cost_basis,
+                    realised_gain: currency.round(position.realised_gain),
+                    dividends: position.dividends,
+                }
+            })
+            .collect();
+
+        let market_value = holdings.iter().map(|holding| holding.market_value).sum();
+        Ok(Portfolio {
+            account_id: account.id,
+            currency,
+            method,
+            cash: account.balance,
+            market_value,
+            total: account.balance + market_value,
+            cost_basis: holdings.iter().map(|holding| holding.cost_basis).sum(),
+            unrealised_gain: holdings.iter().map(|holding| holding.unrealised_gain).sum(),
+            realised_gain: holdings.iter().map(|holding| holding.realised_gain).sum(),
+            dividends: holdings.iter().map(|holding| holding.dividends).sum(),
+            holdings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::accounts::{AccountKind, AccountTerms};
+
+    fn get_account(balance: Decimal) -> Account {
+        Account {
+            id: uuid::Uuid::new_v4(),
+            user_id: uuid::Uuid::new_v4(),
+            name: "name".to_string(),
+            balance,
+            currency: Currency::USD,
+            archived: false,
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Investment,
+            terms: AccountTerms::default(),
+        }
+    }
+
+    fn get_trade(day: u32, kind: TradeKind, quantity: i64, price: i64, fees: i64) -> Trade {
+        Trade {
+            id: uuid::Uuid::new_v4(),
+            account_id: uuid::Uuid::new_v4(),
+            movement_id: uuid::Uuid::new_v4(),
+            date: NaiveDate::from_ymd_opt(2026, 1, day).unwrap(),
+            kind,
+            symbol: "AAPL".to_string(),
+            quantity: Decimal::from(quantity),
+            price: Decimal::from(price),
+            fees: Decimal::from(fees),
+        }
+    }
+
+    fn get_trades() -> Vec<Trade> {
+        vec![
+            get_trade(3, TradeKind::Sell, 15, 130, 0),
+            get_trade(1, TradeKind::Buy, 10, 100, 0),
+            get_trade(2, TradeKind::Buy, 10, 120, 0),
+            get_trade(4, TradeKind::Dividend, 0, 5, 0),
+        ]
+    }
+
+    #[test]
+    fn trade_amount() {
+        let currency = Currency::USD;
+        assert_eq!(
+            get_trade(1, TradeKind::Buy, 10, 100, 5).amount(currency),
+            Decimal::from(-1005)
+        );
+        assert_eq!(
+            get_trade(1, TradeKind::Sell, 10, 100, 5).amount(currency),
+            Decimal::from(995)
+        );
+        assert_eq!(
+            get_trade(1, TradeKind::Dividend, 0, 20, 1).amount(currency),
+            Decimal::from(19)
+        );
+    }
+
+    #[test]
+    fn trade_validated() {
+        let currency = Currency::USD;
+        let trade = Trade {
+            symbol: " aapl ".to_string(),
+            ..get_trade(1, TradeKind::Buy, 10, 100, 0)
+        };
+        assert_eq!(trade.validate(currency).unwrap().symbol, "AAPL");
+        assert!(get_trade(1, TradeKind::Buy, 0, 100, 0)
+            .validate(currency)
+            .is_err());
+        assert!(get_trade(1, TradeKind::Dividend, 1, 100, 0)
+            .validate(currency)
+            .is_err());
+        assert!(get_trade(1, TradeKind::Sell, 1, 0, 0)
+            .validate(currency)
+            .is_err());
+        assert!(get_trade(1, TradeKind::Sell, 1, 100, -1)
+            .validate(currency)
+            .is_err());
+        let trade = Trade {
+            price: Decimal::new(1005, 3),
+            ..get_trade(1, TradeKind::Dividend, 0, 0, 0)
+        };
+        assert!(trade.validate(currency).is_err());
+        let trade = Trade {
+            symbol: "AA PL".to_string(),
+            ..get_trade(1, TradeKind::Buy, 10, 100, 0)
+        };
+        assert!(trade.validate(currency).is_err());
+    }
+
+    #[test]
+    fn portfolio_fifo() {
+        let account = get_account(Decimal::from(-250));
+        let prices = vec![Price {
+            id: uuid::Uuid::new_v4(),
+            user_id: account.user_id,
+            symbol: "AAPL".to_string(),
+            currency: Currency::USD,
+            date: NaiveDate::from_ymd_opt(2026, 1, 4).unwrap(),
+            price: Decimal::from(140),
+        }];
+        let portfolio = Portfolio::new(&account, get_trades(), &prices, CostMethod::Fifo).unwrap();
+
+        let holding = &portfolio.holdings[0];
+        assert_eq!(holding.quantity, Decimal::from(5));
+        assert_eq!(holding.cost_basis, Decimal::from(600));
+        assert_eq!(holding.market_value, Decimal::from(700));
+        assert_eq!(holding.unrealised_gain, Decimal::from(100));
+        assert_eq!(holding.realised_gain, Decimal::from(350));
+        assert_eq!(holding.dividends, Decimal::from(5));
+        assert_eq!(portfolio.total, Decimal::from(450));
+    }
+
+    #[test]
+    fn portfolio_average_cost() {
+        let account = get_account(Decimal::from(-250));
+        let portfolio = Portfolio::new(&account, get_trades(), &[], CostMethod::Average).unwrap();
+
+        let holding = &portfolio.holdings[0];
+        assert_eq!(holding.quantity, Decimal::from(5));
+        assert_eq!(holding.cost_basis, Decimal::from(550));
+        assert_eq!(holding.price, None);
+        assert_eq!(holding.market_value, Decimal::from(550));
+        assert_eq!(holding.unrealised_gain, Decimal::from(0));
+        assert_eq!(holding.realised_gain, Decimal::from(300));
+        assert_eq!(portfolio.total, Decimal::from(300));
+    }
+
+    #[test]
+    fn portfolio_oversold() {
+        let account = get_account(Decimal::from(0));
+        let mut trades = get_trades();
+        trades.push(get_trade(5, TradeKind::Sell, 6, 130, 0));
+        assert!(Portfolio::new(&account, trades, &[], CostMethod::Fifo).is_err());
+    }
+}
@@ -1,3 +1,18 @@
 pub mod accounts;
+pub mod analytics;
 pub mod auth;
+pub mod budgets;
+pub mod categories;
+pub mod credit;
+pub mod currencies;
+pub mod exchange_rates;
+pub mod exports;
+pub mod goals;
+pub mod imports;
+pub mod investments;
+pub mod reconciliations;
+pub mod recurring_movements;
+pub mod rules;
+pub mod statements;
+pub mod takeout;
 pub mod users;
@@ -0,0 +1,22 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::accounts::Movement;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Reconciliation {
+    pub id: uuid::Uuid,
+    pub account_id: uuid::Uuid,
+    pub statement_date: NaiveDate,
+    pub statement_balance: Decimal,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ReconciliationReport {
+    pub reconciliation: Reconciliation,
+    pub cleared_balance: Decimal,
+    pub difference: Decimal,
+    pub movements: Vec<Movement>,
+}
@@ -0,0 +1,104 @@
+use chrono::{Days, Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, sqlx::Type, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "UPPERCASE")]
+#[sqlx(type_name = "varchar", rename_all = "UPPERCASE")]
+pub enum Frequency {
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+// Movement repeated on a schedule anchored to the start date: weekly on its weekday, monthly on
+// its day (the last day in shorter months) and yearly on its day and month
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct RecurringMovement {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub account_id: uuid::Uuid,
+    pub category_id: uuid::Uuid,
+    pub title: String,
+    pub amount: Decimal,
+    pub frequency: Frequency,
+    pub start_date: NaiveDate,
+    pub next_date: NaiveDate,
+    pub paused: bool,
+}
+
+impl RecurringMovement {
+    pub fn occurrence(&self, n: u32) -> Option<NaiveDate> {
+        match self.frequency {
+            Frequency::Weekly => self.start_date.checked_add_days(Days::new(7 * n as u64)),
+            Frequency::Monthly => self.start_date.checked_add_months(Months::new(n)),
+            Frequency::Yearly => self.start_date.checked_add_months(Months::new(12 * n)),
+        }
+    }
+
+    pub fn occurrence_after(&self, date: NaiveDate) -> Option<NaiveDate> {
+        (0..)
+            .map(|n| self.occurrence(n))
+            .find(|occurrence| match occurrence {
+                Some(occurrence) => *occurrence > date,
+                None => true,
+            })
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_recurring_movement(frequency: Frequency, start_date: NaiveDate) -> RecurringMovement {
+        RecurringMovement {
+            id: uuid::Uuid::new_v4(),
+            user_id: uuid::Uuid::new_v4(),
+            account_id: uuid::Uuid::new_v4(),
+            category_id: uuid::Uuid::new_v4(),
+            title: "rent".to_string(),
+            amount: Decimal::from(-800),
+            frequency,
+            start_date,
+            next_date: start_date,
+            paused: false,
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn occurrence_monthly_keeps_day() {
+        let recurring = get_recurring_movement(Frequency::Monthly, date(2023, 1, 31));
+        assert_eq!(recurring.occurrence(1), Some(date(2023, 2, 28)));
+        assert_eq!(recurring.occurrence(2), Some(date(2023, 3, 31)));
+        assert_eq!(
+            recurring.occurrence_after(date(2023, 2, 28)),
+            Some(date(2023, 3, 31))
+        );
+    }
+
+    #[test]
+    fn occurrence_weekly() {
+        let recurring = get_recurring_movement(Frequency::Weekly, date(2023, 1, 2));
+        assert_eq!(recurring.occurrence(3), Some(date(2023, 1, 23)));
+        assert_eq!(
+            recurring.occurrence_after(date(2023, 1, 10)),
+            Some(date(2023, 1, 16))
+        );
+    }
+
+    #[test]
+    fn occurrence_yearly() {
+        let recurring = get_recurring_movement(Frequency::Yearly, date(2024, 2, 29));
+        assert_eq!(recurring.occurrence(1), Some(date(2025, 2, 28)));
+        assert_eq!(recurring.occurrence(4), Some(date(2028, 2, 29)));
+        assert_eq!(
+            recurring.occurrence_after(date(2020, 1, 1)),
+            Some(date(2024, 2, 29))
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use anyhow::anyhow;
+use regex::{Regex, RegexBuilder};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::accounts::Movement;
+use crate::domain::error::Error;
+
+// Keeps user supplied patterns from compiling into huge automata
+const MAX_REGEX_SIZE: usize = 1 << 20;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct RuleConditions {
+    pub title_contains: Option<String>,
+    pub title_regex: Option<String>,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub account_id: Option<uuid::Uuid>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct RuleActions {
+    pub category_id: Option<uuid::Uuid>,
+    #[serde(default)]
+    pub add_tags: Vec<String>,
+    pub rename_title: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Rule {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub name: String,
+    pub priority: i32,
+    pub conditions: RuleConditions,
+    pub actions: RuleActions,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct RuleChange {
+    pub before: Movement,
+    pub after: Movement,
+}
+
+fn compile(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .size_limit(MAX_REGEX_SIZE)
+        .build()
+}
+
+impl Rule {
+    pub fn validate(&self) -> Result<(), Error> {
+        let conditions = &self.conditions;
+        if conditions.title_contains.is_none()
+            && conditions.title_regex.is_none()
+            && conditions.min_amount.is_none()
+            && conditions.max_amount.is_none()
+            && conditions.account_id.is_none()
+        {
+            return Err(Error::Validation(anyhow!(
+                "at least one condition is required"
+            )));
+        }
+        if conditions
+            .title_contains
+            .as_ref()
+            .is_some_and(|title| title.trim().is_empty() || title.chars().count() > 64)
+        {
+            return Err(Error::Validation(anyhow!(
+                "title condition must be between 1 and 64 characters"
+            )));
+        }
+        if let Some(pattern) = &conditions.title_regex {
+            if pattern.chars().count() > 256 {
+                return Err(Error::Validation(anyhow!(
+                    "title pattern must be at most 256 characters"
+                )));
+            }
+            compile(pattern)
+                .map_err(|err| Error::Validation(anyhow!("invalid title pattern: {err}")))?;
+        }
+        if let (Some(min), Some(max)) = (conditions.min_amount, conditions.max_amount) {
+            if min > max {
+                return Err(Error::Validation(anyhow!(
+                    "min amount must not be greater than max amount"
+                )));
+            }
+        }
+
+        let actions = &self.actions;
+        if actions.category_id.is_none()
+            && actions.add_tags.is_empty()
+            && actions.rename_title.is_none()
+        {
+            return Err(Error::Validation(anyhow!(
+                "at least one action is required"
+            )));
+        }
+        if actions
+            .rename_title
+            .as_ref()
+            .is_some_and(|title| !(3..=64).contains(&title.chars().count()))
+        {
+            return Err(Error::Validation(anyhow!(
+                "title must be between 3 and 64 characters"
+            )));
+        }
+        Ok(())
+    }
+}
+
+pub struct RuleSet {
+    rules: Vec<(Rule, Option<Regex>)>,
+}
+
+impl RuleSet {
+    pub fn new(mut rules: Vec<Rule>) -> Self {
+        rules.sort_by_key(|rule| rule.priority);
+        RuleSet {
+            rules: rules
+                .into_iter()
+                .map(|rule| {
+                    let regex = rule
+                        .conditions
+                        .title_regex
+                        .as_deref()
+                        .map(|pattern| compile(pattern).ok());
+                    (rule, regex.flatten())
+                })
+                .collect(),
+        }
+    }
+
+    fn matches(rule: &Rule, regex: Option<&Regex>, movement: &Movement) -> bool {
+        let conditions = &rule.conditions;
+        let title_contains = conditions.title_contains.as_ref().is_none_or(|title| {
+            movement
+                .title
+                .to_lowercase()
+                .contains(&title.to_lowercase())
+        });
+        let title_regex = conditions.title_regex.is_none()
+            || regex.is_some_and(|regex| regex.is_match(&movement.title));
+        title_contains
+            && title_regex
+            && conditions
+                .min_amount
+                .is_none_or(|min| movement.amount >= min)
+            && conditions
+                .max_amount
+                .is_none_or(|max| movement.amount <= max)
+            && conditions
+                .account_id
+                .is_none_or(|account_id| movement.account_id == account_id)
+    }
+
+    pub fn apply(&self, mut movement: Movement) -> Movement {
+        for (rule, regex) in &self.rules {
+            if !Self::matches(rule, regex.as_ref(), &movement) {
+                continue;
+            }
+            let actions = &rule.actions;
+            if let Some(category_id) = actions.category_id {
+                movement.category_id = Some(category_id);
+            }
+            if let Some(title) = &actions.rename_title {
+                movement.title = title.clone();
+            }
+            if !actions.add_tags.is_empty() {
+                movement.tags.extend(actions.add_tags.iter().cloned());
+                movement.tags.sort();
+                movement.tags.dedup();
+            }
+        }
+        movement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::entities::accounts::MovementStatus;
+
+    fn get_rule(priority: i32, conditions: RuleConditions, actions: RuleActions) -> Rule {
+        Rule {
+            id: uuid::Uuid::new_v4(),
+            user_id: uuid::Uuid::new_v4(),
+            name: "rule".to_string(),
+            priority,
+            conditions,
+            actions,
+        }
+    }
+
+    fn get_movement(title: &str, amount: i64) -> Movement {
+        Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id: uuid::Uuid::new_v4(),
+            timestamp: Utc::now(),
+            title: title.to_string(),
+            category_id: None,
+            amount: Decimal::from(amount),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec!["home".to_string()],
+            fingerprint: None,
+        }
+    }
+
+    #[test]
+    fn rules_applied_in_priority_order() {
+        let groceries = uuid::Uuid::new_v4();
+        let food = uuid::Uuid::new_v4();
+        let rules = RuleSet::new(vec![
+            get_rule(
+                2,
+                RuleConditions {
+                    title_regex: Some("^Supermarket".to_string()),
+                    max_amount: Some(Decimal::from(0)),
+                    ..Default::default()
+                },
+                RuleActions {
+                    category_id: Some(groceries),
+                    add_tags: vec!["food".to_string()],
+                    ..Default::default()
+                },
+            ),
+            get_rule(
+                1,
+                RuleConditions {
+                    title_contains: Some("market".to_string()),
+                    ..Default::default()
+                },
+                RuleActions {
+                    category_id: Some(food),
+                    rename_title: Some("Supermarket".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let movement = rules.apply(get_movement("SUPERMARKET 1234 ROME", -20));
+        assert_eq!(movement.title, "Supermarket");
+        assert_eq!(movement.category_id, Some(groceries));
+        assert_eq!(movement.tags, vec!["food".to_string(), "home".to_string()]);
+
+        let movement = rules.apply(get_movement("Refund Supermarket", 20));
+        assert_eq!(movement.title, "Supermarket");
+        assert_eq!(movement.category_id, Some(food));
+        assert_eq!(movement.tags, vec!["home".to_string()]);
+
+        let movement = get_movement("Salary", 1000);
+        assert_eq!(rules.apply(movement.clone()), movement);
+    }
+
+    #[test]
+    fn rule_validation() {
+        let actions = RuleActions {
+            add_tags: vec!["food".to_string()],
+            ..Default::default()
+        };
+        let conditions = RuleConditions {
+            title_contains: Some("market".to_string()),
+            ..Default::default()
+        };
+        assert!(get_rule(0, conditions.clone(), actions.clone())
+            .validate()
+            .is_ok());
+        assert!(get_rule(0, RuleConditions::default(), actions.clone())
+            .validate()
+            .is_err());
+        assert!(get_rule(0, conditions.clone(), RuleActions::default())
+            .validate()
+            .is_err());
+        assert!(get_rule(
+            0,
+            RuleConditions {
+                title_regex: Some("(unclosed".to_string()),
+                ..Default::default()
+            },
+            actions.clone()
+        )
+        .validate()
+        .is_err());
+        assert!(get_rule(
+            0,
+            RuleConditions {
+                min_amount: Some(Decimal::from(10)),
+                max_amount: Some(Decimal::from(-10)),
+                ..Default::default()
+            },
+            actions
+        )
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,402 @@
+use anyhow::anyhow;
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::currencies::Currency;
+use crate::domain::error::Error;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum StatementFormat {
+    Ofx,
+    Camt053,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct StatementEntry {
+    pub external_id: String,
+    pub date: NaiveDate,
+    pub title: String,
+    pub amount: Decimal,
+    pub currency: Option<Currency>,
+}
+
+impl StatementFormat {
+    pub fn parse(&self, data: &str) -> Result<Vec<StatementEntry>, Error> {
+        match self {
+            StatementFormat::Ofx => parse_ofx(data),
+            StatementFormat::Camt053 => parse_camt053(data),
+        }
+    }
+}
+
+fn invalid(format: &str, entry: usize, err: &str) -> Error {
+    Error::Validation(anyhow!("invalid {format} transaction {entry}: {err}"))
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn normalize_amount(amount: &str) -> String {
+    let amount: String = amount
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'')
+        .collect();
+    let decimal = amount
+        .rfind(['.', ','])
+        .filter(|index| amount.matches(&amount[*index..*index + 1]).count() == 1);
+    amount
+        .char_indices()
+        .filter_map(|(index, c)| match c {
+            '.' | ',' if Some(index) == decimal => Some('.'),
+            '.' | ',' => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+// Handles both sgml (ofx 1.x, leaf elements are not closed) and xml (ofx 2.x) documents
+fn parse_ofx(data: &str) -> Result<Vec<StatementEntry>, Error> {
+    let body = data
+        .find("<OFX>")
+        .map(|start| &data[start..])
+        .ok_or(Error::Validation(anyhow!("not an ofx document")))?;
+
+    let mut entries = vec![];
+    let mut currency = None;
+    let mut fields: Option<HashMap<&str, String>> = None;
+    for token in body.split('<').skip(1) {
+        let (tag, text) = token
+            .split_once('>')
+            .ok_or(Error::Validation(anyhow!("invalid ofx tag {token}")))?;
+        let text = unescape(text.trim());
+        match tag.trim() {
+            "STMTTRN" => fields = Some(HashMap::new()),
+            "/STMTTRN" => {
+                let fields = fields
+                    .take()
+                    .ok_or(Error::Validation(anyhow!("unexpected ofx </STMTTRN>")))?;
+                entries.push(ofx_entry(&fields, currency, entries.len() + 1)?);
+            }
+            "CURDEF" => currency = Some(Currency::from_str(&text)?),
+            tag if !tag.starts_with('/') && !text.is_empty() => {
+                if let Some(fields) = fields.as_mut() {
+                    fields.entry(tag).or_insert(text);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+fn ofx_entry(
+    fields: &HashMap<&str, String>,
+    currency: Option<Currency>,
+    entry: usize,
+) -> Result<StatementEntry, Error> {
+    let field = |name: &str| {
+        fields
+            .get(name)
+            .ok_or_else(|| invalid("ofx", entry, &format!("missing {name}")))
+    };
+
+    let external_id = field("FITID")?.clone();
+    let date = field("DTPOSTED")?;
+    let date = date
+        .get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or_else(|| invalid("ofx", entry, &format!("invalid date {date}")))?;
+    let amount = field("TRNAMT")?;
+    let amount = Decimal::from_str(&normalize_amount(amount))
+        .map_err(|_| invalid("ofx", entry, &format!("invalid amount {amount}")))?;
+    let title = field("NAME")
+        .or_else(|_| field("MEMO"))
+        .or_else(|_| field("TRNTYPE"))?
+        .clone();
+
+    Ok(StatementEntry {
+        external_id,
+        date,
+        title,
+        amount,
+        currency,
+    })
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|child| child.is_element() && child.tag_name().name() == name)
+}
+
+fn path_text<'a>(node: Node<'a, '_>, path: &[&str]) -> Option<&'a str> {
+    path.iter()
+        .try_fold(node, |node, name| child(node, name))
+        .and_then(|node| node.text())
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
+
+fn parse_camt053(data: &str) -> Result<Vec<StatementEntry>, Error> {
+    let document = Document::parse(data)
+        .map_err(|err| Error::Validation(anyhow!("invalid camt.053 document: {err}")))?;
+    if document.root_element().tag_name().name() != "Document" {
+        return Err(Error::Validation(anyhow!("not a camt.053 document")));
+    }
+
+    let mut entries = vec![];
+    let nodes = document
+        .descendants()
+        .filter(|node| node.is_element() && node.tag_name().name() == "Ntry");
+    for (index, node) in nodes.enumerate() {
+        let entry = index + 1;
+        // Plain text up to version 2, a code element afterwards
+        let status = path_text(node, &["Sts"]).or_else(|| path_text(node, &["Sts", "Cd"]));
+        if status != Some("BOOK") {
+            continue;
+        }
+
+        let external_id = path_text(node, &["AcctSvcrRef"])
+            .or_else(|| path_text(node, &["NtryRef"]))
+            .ok_or_else(|| invalid("camt.053", entry, "missing entry reference"))?
+            .to_string();
+        let date = path_text(node, &["BookgDt", "Dt"])
+            .or_else(|| path_text(node, &["BookgDt", "DtTm"]))
+            .or_else(|| path_text(node, &["ValDt", "Dt"]))
+            .ok_or_else(|| invalid("camt.053", entry, "missing booking date"))?;
+        let date = date
+            .get(..10)
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .ok_or_else(|| invalid("camt.053", entry, &format!("invalid date {date}")))?;
+
+        let amount_node =
+            child(node, "Amt").ok_or_else(|| invalid("camt.053", entry, "missing amount"))?;
+        let amount = amount_node.text().unwrap_or_default().trim();
+        let amount = Decimal::from_str(amount)
+            .map_err(|_| invalid("camt.053", entry, &format!("invalid amount {amount}")))?;
+        let amount = match path_text(node, &["CdtDbtInd"]) {
+            Some("CRDT") => amount,
+            Some("DBIT") => -amount,
+            _ => return Err(invalid("camt.053", entry, "invalid credit debit indicator")),
+        };
+        let currency = amount_node
+            .attribute("Ccy")
+            .map(Currency::from_str)
+            .transpose()?;
+
+        let details = ["NtryDtls", "TxDtls"];
+        let party = match amount.is_sign_negative() {
+            true => "Cdtr",
+            false => "Dbtr",
+        };
+        // The party name is nested in Pty from version 8
+        let title = path_text(node, &[&details[..], &["RmtInf", "Ustrd"]].concat())
+            .or_else(|| path_text(node, &["AddtlNtryInf"]))
+            .or_else(|| path_text(node, &[&details[..], &["RltdPties", party, "Nm"]].concat()))
+            .or_else(|| {
+                path_text(
+                    node,
+                    &[&details[..], &["RltdPties", party, "Pty", "Nm"]].concat(),
+                )
+            })
+            .map(str::to_string)
+            .unwrap_or_else(|| external_id.clone());
+
+        entries.push(StatementEntry {
+            external_id,
+            date,
+            title,
+            amount,
+            currency,
+        });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        external_id: &str,
+        date: (i32, u32, u32),
+        title: &str,
+        amount: Decimal,
+        currency: Currency,
+    ) -> StatementEntry {
+        StatementEntry {
+            external_id: external_id.to_string(),
+            date: NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap(),
+            title: title.to_string(),
+            amount,
+            currency: Some(currency),
+        }
+    }
+
+    #[test]
+    fn parse_ofx_sgml() {
+        let entries = StatementFormat::Ofx
+            .parse(include_str!("../../../fixtures/statements/statement.ofx"))
+            .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                entry(
+                    "202301050001",
+                    (2023, 1, 5),
+                    "GROCERY STORE #123",
+                    Decimal::new(-4520, 2),
+                    Currency::USD
+                ),
+                entry(
+                    "202301310001",
+                    (2023, 1, 31),
+                    "ACME CORP PAYROLL",
+                    Decimal::new(250000, 2),
+                    Currency::USD
+                ),
+                entry(
+                    "202301310002",
+                    (2023, 1, 31),
+                    "MONTHLY SERVICE FEE & CHARGES",
+                    Decimal::new(-495, 2),
+                    Currency::USD
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_qfx_xml() {
+        let entries = StatementFormat::Ofx
+            .parse(include_str!("../../../fixtures/statements/statement.qfx"))
+            .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                entry(
+                    "FITID-0001",
+                    (2023, 1, 12),
+                    "STREAMING SERVICE",
+                    Decimal::new(-1299, 2),
+                    Currency::USD
+                ),
+                entry(
+                    "FITID-0002",
+                    (2023, 1, 20),
+                    "PAYMENT - THANK YOU",
+                    Decimal::new(10000, 2),
+                    Currency::USD
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_ofx_missing_fitid() {
+        let data = "<OFX><STMTTRN><TRNTYPE>DEBIT<DTPOSTED>20230105<TRNAMT>-1.00</STMTTRN></OFX>";
+        assert!(StatementFormat::Ofx.parse(data).is_err());
+    }
+
+    #[test]
+    fn parse_ofx_not_ofx() {
+        assert!(StatementFormat::Ofx.parse("date,amount\n").is_err());
+    }
+
+    #[test]
+    fn parse_camt053() {
+        let entries = StatementFormat::Camt053
+            .parse(include_str!("../../../fixtures/statements/camt053.xml"))
+            .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                entry(
+                    "2023010200001",
+                    (2023, 1, 2),
+                    "Miete Januar",
+                    Decimal::new(-85000, 2),
+                    Currency::EUR
+                ),
+                entry(
+                    "2023012700042",
+                    (2023, 1, 27),
+                    "Gehalt Januar",
+                    Decimal::new(210000, 2),
+                    Currency::EUR
+                ),
+                entry(
+                    "3",
+                    (2023, 1, 30),
+                    "Supermarkt & Co",
+                    Decimal::new(-4570, 2),
+                    Currency::EUR
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_ofx_grouped_amounts() {
+        let entries = StatementFormat::Ofx
+            .parse(include_str!(
+                "../../../fixtures/statements/grouped_amounts.ofx"
+            ))
+            .unwrap();
+
+        assert_eq!(
+            entries.iter().map(|entry| entry.amount).collect::<Vec<_>>(),
+            vec![
+                Decimal::new(-123456, 2),
+                Decimal::new(250000, 2),
+                Decimal::new(-1050000, 2),
+                Decimal::new(-1234567, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_camt053_without_remittance() {
+        let entries = StatementFormat::Camt053
+            .parse(include_str!("../../../fixtures/statements/camt053_v8.xml"))
+            .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                entry(
+                    "2023020100003",
+                    (2023, 2, 1),
+                    "Stadtwerke AG",
+                    Decimal::new(-6200, 2),
+                    Currency::EUR
+                ),
+                entry(
+                    "2023020200011",
+                    (2023, 2, 2),
+                    "2023020200011",
+                    Decimal::new(-250, 2),
+                    Currency::EUR
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_camt053_invalid() {
+        assert!(StatementFormat::Camt053.parse("<Document><Stmt>").is_err());
+        assert!(StatementFormat::Camt053.parse("<OFX></OFX>").is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Serialize;
+use std::io::{Cursor, Write};
+use zip::{write::FileOptions, ZipWriter};
+
+use crate::domain::error::Error;
+
+pub struct Takeout {
+    writer: ZipWriter<Cursor<Vec<u8>>>,
+}
+
+fn external(err: impl std::error::Error + Send + Sync + 'static) -> Error {
+    Error::External(err.into())
+}
+
+impl Default for Takeout {
+    fn default() -> Self {
+        Self {
+            writer: ZipWriter::new(Cursor::new(vec![])),
+        }
+    }
+}
+
+impl Takeout {
+    pub fn add_json<T: Serialize>(&mut self, name: &str, data: &T) -> Result<(), Error> {
+        self.start_file(name)?;
+        serde_json::to_writer_pretty(&mut self.writer, data).map_err(external)
+    }
+
+    pub fn start_file(&mut self, name: &str) -> Result<(), Error> {
+        self.writer
+            .start_file(name, FileOptions::default())
+            .map_err(external)
+    }
+
+    pub fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.writer.write_all(data).map_err(external)
+    }
+
+    pub fn finish(mut self) -> Result<Vec<u8>, Error> {
+        Ok(self.writer.finish().map_err(external)?.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    use super::*;
+
+    #[test]
+    fn takeout_files() {
+        let mut takeout = Takeout::default();
+        takeout.add_json("user.json", &vec!["a", "b"]).unwrap();
+        takeout.start_file("movements.json").unwrap();
+        takeout.write(b"[").unwrap();
+        takeout.write(b"]").unwrap();
+        let data = takeout.finish().unwrap();
+
+        let mut archive = ZipArchive::new(Cursor::new(data)).unwrap();
+        let mut content = String::new();
+        archive
+            .by_name("movements.json")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "[]");
+        let mut content = String::new();
+        archive
+            .by_name("user.json")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<String>>(&content).unwrap(),
+            vec!["a", "b"]
+        );
+    }
+}
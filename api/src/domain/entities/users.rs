@@ -1,7 +1,10 @@
 use serde::Serialize;
 
+use super::currencies::Currency;
+
 #[derive(Debug, Serialize, PartialEq, Clone)]
 pub struct User {
     pub id: uuid::Uuid,
     pub email: String,
+    pub preferred_currency: Currency,
 }
@@ -0,0 +1,136 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::application::services::exchange_rates::ExchangeRateProvider;
+use crate::domain::entities::{currencies::Currency, exchange_rates::Quote};
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+pub struct FileExchangeRateProvider {
+    quotes: Vec<Quote>,
+}
+
+impl FileExchangeRateProvider {
+    pub fn new(quotes: Vec<Quote>) -> Self {
+        Self { quotes }
+    }
+
+    pub fn from_csv(csv: &str) -> Result<Self> {
+        let quotes = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_bytes())
+            .deserialize::<Quote>()
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|err| Error::Validation(anyhow!(err)))?;
+        if quotes.iter().any(|quote| quote.rate <= Decimal::from(0)) {
+            return Err(Error::Validation(anyhow!("exchange rate must be positive")));
+        }
+        Ok(Self::new(quotes))
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for FileExchangeRateProvider {
+    async fn get_quote(&self, _user_id: Uuid, from: Currency, to: Currency) -> Result<Quote> {
+        if from == to {
+            return Err(Error::Validation(anyhow!(
+                "exchange rate currencies must differ"
+            )));
+        }
+
+        let direct = self
+            .quotes
+            .iter()
+            .filter(|quote| quote.from == from && quote.to == to)
+            .cloned();
+        let inverse = self
+            .quotes
+            .iter()
+            .filter(|quote| quote.from == to && quote.to == from)
+            .map(|quote| Quote {
+                from,
+                to,
+                rate: (Decimal::ONE / quote.rate).round_dp(10),
+                date: quote.date,
+            });
+
+        direct
+            .chain(inverse)
+            .max_by_key(|quote| quote.date)
+            .ok_or(Error::Repository(RepositoryErrorType::NotFound))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn get_provider() -> FileExchangeRateProvider {
+        FileExchangeRateProvider::from_csv(
+            "date,from,to,rate\n2023-01-01,USD,EUR,0.90\n2023-02-01,EUR,USD,1.25\n",
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn get_quote_latest() {
+        let provider = get_provider();
+
+        assert_eq!(
+            provider
+                .get_quote(Uuid::new_v4(), Currency::EUR, Currency::USD)
+                .await
+                .unwrap(),
+            Quote {
+                date: NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                from: Currency::EUR,
+                to: Currency::USD,
+                rate: Decimal::new(125, 2),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn get_quote_inverse() {
+        let provider = get_provider();
+
+        assert_eq!(
+            provider
+                .get_quote(Uuid::new_v4(), Currency::USD, Currency::EUR)
+                .await
+                .unwrap(),
+            Quote {
+                date: NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                from: Currency::USD,
+                to: Currency::EUR,
+                rate: Decimal::new(8, 1),
+            }
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn get_quote_not_found() {
+        let provider = FileExchangeRateProvider::new(vec![]);
+
+        provider
+            .get_quote(Uuid::new_v4(), Currency::USD, Currency::EUR)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Validation")]
+    fn from_csv_invalid() {
+        FileExchangeRateProvider::from_csv("date,from,to,rate\n2023-01-01,USD,EUR,abc\n").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Validation")]
+    fn from_csv_zero_rate() {
+        FileExchangeRateProvider::from_csv("date,from,to,rate\n2023-01-01,USD,EUR,0\n").unwrap();
+    }
+}
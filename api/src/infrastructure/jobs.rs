@@ -0,0 +1,63 @@
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{self, MissedTickBehavior};
+
+use crate::application::use_cases::profile::ProfileUseCaseTrait;
+use crate::application::use_cases::recurring_movements::RecurringMovementUseCaseTrait;
+
+pub async fn run_recurring_movements(
+    recurring_movements: Arc<dyn RecurringMovementUseCaseTrait>,
+    period: Duration,
+) {
+    let mut interval = time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        match recurring_movements
+            .materialise_recurring_movements(Utc::now().date_naive())
+            .await
+        {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("Created {count} recurring movements"),
+            Err(err) => tracing::error!("Error creating recurring movements: {err:?}"),
+        }
+    }
+}
+
+pub async fn run_balance_check(
+    profile: Arc<dyn ProfileUseCaseTrait>,
+    period: Duration,
+    repair: bool,
+) {
+    let mut interval = time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        check_balances(profile.as_ref(), repair).await;
+    }
+}
+
+pub async fn check_balances(profile: &dyn ProfileUseCaseTrait, repair: bool) -> bool {
+    match profile.check_balances(repair).await {
+        Ok(drifts) => {
+            for drift in drifts.iter() {
+                tracing::warn!(
+                    account_id = %drift.account_id,
+                    user_id = %drift.user_id,
+                    balance = %drift.balance,
+                    expected = %drift.expected,
+                    repaired = repair,
+                    "Account balance drift"
+                );
+            }
+            drifts.is_empty()
+        }
+        Err(err) => {
+            tracing::error!("Error checking account balances: {err:?}");
+            false
+        }
+    }
+}
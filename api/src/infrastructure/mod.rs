@@ -1,10 +1,25 @@
+use sqlx::PgPool;
 use std::sync::Arc;
 use tokio::signal;
 
+use crate::application::use_cases::analytics::AnalyticsUseCase;
 use crate::application::use_cases::auth::AuthUseCase;
+use crate::application::use_cases::budgets::BudgetUseCase;
+use crate::application::use_cases::categories::CategoryUseCase;
+use crate::application::use_cases::exchange_rates::ExchangeRateUseCase;
+use crate::application::use_cases::goals::GoalUseCase;
+use crate::application::use_cases::imports::ImportUseCase;
+use crate::application::use_cases::investments::InvestmentUseCase;
 use crate::application::use_cases::profile::ProfileUseCase;
+use crate::application::use_cases::reconciliations::ReconciliationUseCase;
+use crate::application::use_cases::recurring_movements::RecurringMovementUseCase;
+use crate::application::use_cases::rules::RuleUseCase;
+use crate::application::use_cases::takeout::TakeoutUseCase;
+use crate::application::use_cases::transfers::TransferUseCase;
 use crate::config::Config;
 
+mod file;
+mod jobs;
 mod paseto;
 mod pg;
 mod redis;
@@ -17,13 +32,12 @@ pub async fn run(config: Config) {
     let pg_pool = config.get_pg_pool();
     let redis_pool = config.get_redis_pool();
     let smtp_client = config.get_smtp_client();
+    let recurring_movements_interval = config.get_recurring_movements_interval();
+    let balance_check_interval = config.get_balance_check_interval();
 
     let (paseto_public_key, paseto_private_key, paseto_expiration) = config.get_paseto_config();
 
-    sqlx::migrate!("./migrations")
-        .run(&pg_pool)
-        .await
-        .expect("Failed to execute migrations");
+    migrate(&pg_pool).await;
 
     let token_service = Box::new(paseto::PasetoTokenService::new(
         &paseto_public_key,
@@ -33,19 +47,173 @@ pub async fn run(config: Config) {
     let otp_service = Box::new(redis::RedisOtpService::new(redis_pool));
     let mail_service = Box::new(smtp::SmtpMailService::new(smtp_client));
     let user_service = Box::new(pg::users::PgUserService::new(pg_pool.clone()));
-    let account_service = Box::new(pg::accounts::PgAccountService::new(pg_pool));
 
     let auth = AuthUseCase::new(otp_service, mail_service, token_service, user_service);
-    let profile = ProfileUseCase::new(account_service);
+    let analytics = AnalyticsUseCase::new(
+        Box::new(pg::analytics::PgAnalyticsService::new(pg_pool.clone())),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+        Box::new(pg::users::PgUserService::new(pg_pool.clone())),
+        Box::new(pg::exchange_rates::PgExchangeRateService::new(
+            pg_pool.clone(),
+        )),
+    );
+    let budgets = BudgetUseCase::new(
+        Box::new(pg::budgets::PgBudgetService::new(pg_pool.clone())),
+        Box::new(pg::categories::PgCategoryService::new(pg_pool.clone())),
+        Box::new(pg::exchange_rates::PgExchangeRateService::new(
+            pg_pool.clone(),
+        )),
+    );
+    let categories = CategoryUseCase::new(Box::new(pg::categories::PgCategoryService::new(
+        pg_pool.clone(),
+    )));
+    let exchange_rates = ExchangeRateUseCase::new(Box::new(
+        pg::exchange_rates::PgExchangeRateService::new(pg_pool.clone()),
+    ));
+    let goals = GoalUseCase::new(
+        Box::new(pg::goals::PgGoalService::new(pg_pool.clone())),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+        Box::new(pg::exchange_rates::PgExchangeRateService::new(
+            pg_pool.clone(),
+        )),
+        Box::new(pg::investments::PgTradeService::new(pg_pool.clone())),
+        Box::new(pg::investments::PgPriceService::new(pg_pool.clone())),
+    );
+    let imports = ImportUseCase::new(
+        Box::new(pg::imports::PgImportMappingService::new(pg_pool.clone())),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+        Box::new(pg::rules::PgRuleService::new(pg_pool.clone())),
+    );
+    let investments = InvestmentUseCase::new(
+        Box::new(pg::investments::PgTradeService::new(pg_pool.clone())),
+        Box::new(pg::investments::PgPriceService::new(pg_pool.clone())),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+    );
+    let reconciliations = ReconciliationUseCase::new(
+        Box::new(pg::reconciliations::PgReconciliationService::new(
+            pg_pool.clone(),
+        )),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+    );
+    let recurring_movements = Arc::new(RecurringMovementUseCase::new(
+        Box::new(pg::recurring_movements::PgRecurringMovementService::new(
+            pg_pool.clone(),
+        )),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+        Box::new(pg::categories::PgCategoryService::new(pg_pool.clone())),
+    ));
+    let rules = RuleUseCase::new(
+        Box::new(pg::rules::PgRuleService::new(pg_pool.clone())),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+        Box::new(pg::categories::PgCategoryService::new(pg_pool.clone())),
+        Box::new(pg::investments::PgTradeService::new(pg_pool.clone())),
+    );
+    let takeout = TakeoutUseCase::new(
+        Box::new(pg::users::PgUserService::new(pg_pool.clone())),
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+        Box::new(pg::categories::PgCategoryService::new(pg_pool.clone())),
+        Box::new(pg::budgets::PgBudgetService::new(pg_pool.clone())),
+        Box::new(pg::recurring_movements::PgRecurringMovementService::new(
+            pg_pool.clone(),
+        )),
+        Box::new(pg::exchange_rates::PgExchangeRateService::new(
+            pg_pool.clone(),
+        )),
+        Box::new(pg::imports::PgImportMappingService::new(pg_pool.clone())),
+        Box::new(pg::reconciliations::PgReconciliationService::new(
+            pg_pool.clone(),
+        )),
+        Box::new(pg::rules::PgRuleService::new(pg_pool.clone())),
+        Box::new(pg::goals::PgGoalService::new(pg_pool.clone())),
+        Box::new(pg::investments::PgTradeService::new(pg_pool.clone())),
+        Box::new(pg::investments::PgPriceService::new(pg_pool.clone())),
+    );
+    let transfers = TransferUseCase::new(
+        Box::new(pg::accounts::PgAccountService::new(pg_pool.clone())),
+        Box::new(pg::exchange_rates::PgExchangeRateService::new(
+            pg_pool.clone(),
+        )),
+    );
+    let profile = Arc::new(get_profile_use_case(&config, pg_pool));
+
+    tokio::spawn(jobs::run_recurring_movements(
+        recurring_movements.clone(),
+        recurring_movements_interval,
+    ));
+    tokio::spawn(jobs::run_balance_check(
+        profile.clone(),
+        balance_check_interval,
+        config.balance_check_repair,
+    ));
 
     web::run(
         config,
-        web::State::new(Arc::new(auth), Arc::new(profile)),
+        web::State {
+            auth: Arc::new(auth),
+            profile,
+            analytics: Arc::new(analytics),
+            budgets: Arc::new(budgets),
+            categories: Arc::new(categories),
+            exchange_rates: Arc::new(exchange_rates),
+            goals: Arc::new(goals),
+            imports: Arc::new(imports),
+            investments: Arc::new(investments),
+            reconciliations: Arc::new(reconciliations),
+            recurring_movements,
+            rules: Arc::new(rules),
+            takeout: Arc::new(takeout),
+            transfers: Arc::new(transfers),
+        },
         shutdown_signal,
     )
     .await;
 }
 
+pub async fn check_balances(config: Config, repair: bool) -> bool {
+    let pg_pool = config.get_pg_pool();
+    migrate(&pg_pool).await;
+
+    let profile = get_profile_use_case(&config, pg_pool);
+    jobs::check_balances(&profile, repair).await
+}
+
+async fn migrate(pg_pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pg_pool)
+        .await
+        .expect("Failed to execute migrations");
+}
+
+fn get_profile_use_case(config: &Config, pg_pool: PgPool) -> ProfileUseCase {
+    let exchange_rates_csv = config.get_exchange_rates_csv();
+
+    let user_service = Box::new(pg::users::PgUserService::new(pg_pool.clone()));
+    let account_service = Box::new(pg::accounts::PgAccountService::new(pg_pool.clone()));
+    let category_service = Box::new(pg::categories::PgCategoryService::new(pg_pool.clone()));
+    let analytics_service = Box::new(pg::analytics::PgAnalyticsService::new(pg_pool.clone()));
+    let rule_service = Box::new(pg::rules::PgRuleService::new(pg_pool.clone()));
+    let trade_service = Box::new(pg::investments::PgTradeService::new(pg_pool.clone()));
+    let price_service = Box::new(pg::investments::PgPriceService::new(pg_pool.clone()));
+    let exchange_rate_provider = Box::new(pg::exchange_rates::PgExchangeRateProvider::new(
+        pg_pool,
+        Box::new(
+            file::FileExchangeRateProvider::from_csv(&exchange_rates_csv)
+                .expect("Invalid exchange rates file"),
+        ),
+    ));
+
+    ProfileUseCase::new(
+        account_service,
+        user_service,
+        exchange_rate_provider,
+        category_service,
+        analytics_service,
+        rule_service,
+        trade_service,
+        price_service,
+    )
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         signal::ctrl_c()
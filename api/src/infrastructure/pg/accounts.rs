@@ -1,11 +1,21 @@
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
+use rust_decimal::Decimal;
 use sqlx::postgres::PgPool;
+use sqlx::{Postgres, Transaction};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
 use uuid::{self, Uuid};
 
 use crate::application::services::accounts::AccountService;
 use crate::application::services::Repository;
-use crate::domain::entities::accounts::{Account, Movement};
-use crate::domain::error::Result;
+use crate::domain::entities::accounts::{
+    Account, AccountKind, AccountTerms, BalanceDrift, Movement, MovementCursor, MovementFilter,
+    MovementSplit, SplitMovement, Tag, Transfer,
+};
+use crate::domain::entities::currencies::Currency;
+use crate::domain::error::{Error, RepositoryErrorType, Result};
 
 pub struct PgAccountService {
     db: PgPool,
@@ -17,158 +27,745 @@ impl PgAccountService {
     }
 }
 
+struct AccountRow {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    balance: Decimal,
+    currency: Currency,
+    archived: bool,
+    opening_balance: Decimal,
+    opening_date: Option<NaiveDate>,
+    kind: AccountKind,
+    credit_limit: Option<Decimal>,
+    apr: Option<Decimal>,
+    statement_day: Option<i16>,
+    loan_principal: Option<Decimal>,
+    loan_term_months: Option<i32>,
+}
+
+impl From<AccountRow> for Account {
+    fn from(row: AccountRow) -> Self {
+        Account {
+            id: row.id,
+            user_id: row.user_id,
+            name: row.name,
+            balance: row.balance,
+            currency: row.currency,
+            archived: row.archived,
+            opening_balance: row.opening_balance,
+            opening_date: row.opening_date,
+            kind: row.kind,
+            terms: AccountTerms {
+                credit_limit: row.credit_limit,
+                apr: row.apr,
+                statement_day: row.statement_day,
+                loan_principal: row.loan_principal,
+                loan_term_months: row.loan_term_months,
+            },
+        }
+    }
+}
+
+pub(super) async fn insert_movement_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    movement: Movement,
+) -> Result<Movement> {
+    let data = sqlx::query_as!(
+        Movement,
+        r#"INSERT INTO movements(id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status, note, tags, fingerprint)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        RETURNING id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint"#,
+        movement.id,
+        movement.account_id,
+        movement.timestamp,
+        movement.title,
+        movement.amount,
+        movement.category_id,
+        movement.transfer_id,
+        movement.external_id,
+        movement.status as _,
+        movement.note,
+        &movement.tags,
+        movement.fingerprint
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    update_balance_tx(tx, data.account_id, data.amount).await?;
+
+    Ok(data)
+}
+
+pub(super) async fn update_balance_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    account_id: Uuid,
+    amount: Decimal,
+) -> Result<()> {
+    sqlx::query!(
+        "UPDATE accounts SET balance = balance + $2 WHERE id = $1",
+        account_id,
+        amount
+    )
+    .execute(&mut *tx)
+    .await?;
+    Ok(())
+}
+
+async fn insert_splits_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    splits: Vec<MovementSplit>,
+) -> Result<Vec<MovementSplit>> {
+    let mut data = vec![];
+    for (position, split) in (0i32..).zip(splits) {
+        data.push(
+            sqlx::query_as!(
+                MovementSplit,
+                r#"INSERT INTO movement_splits(id, movement_id, category_id, amount, memo, position)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING id, movement_id, category_id, amount, memo"#,
+                split.id,
+                split.movement_id,
+                split.category_id,
+                split.amount,
+                split.memo,
+                position
+            )
+            .fetch_one(&mut *tx)
+            .await?,
+        );
+    }
+    Ok(data)
+}
+
+struct TransferRate {
+    id: Uuid,
+    rate: Decimal,
+    rate_date: Option<NaiveDate>,
+}
+
+fn into_transfer(transfer: TransferRate, movements: Vec<Movement>) -> Result<Transfer> {
+    let (debits, credits): (Vec<_>, Vec<_>) = movements
+        .into_iter()
+        .partition(|movement| movement.amount.is_sign_negative());
+
+    match (
+        <[Movement; 1]>::try_from(debits),
+        <[Movement; 1]>::try_from(credits),
+    ) {
+        (Ok([debit]), Ok([credit])) => Ok(Transfer {
+            id: transfer.id,
+            debit,
+            credit,
+            rate: transfer.rate,
+            rate_date: transfer.rate_date,
+        }),
+        _ => Err(Error::Repository(RepositoryErrorType::NotFound)),
+    }
+}
+
 #[async_trait]
 impl AccountService for PgAccountService {
     async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Account> {
         let data = sqlx::query_as!(
-            Account,
-            r#"SELECT id, user_id, name, balance, currency as "currency: _" FROM accounts WHERE id = $1 AND user_id = $2"#,
+            AccountRow,
+            r#"SELECT id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months FROM accounts WHERE id = $1 AND user_id = $2"#,
             id,
             user_id,
         )
         .fetch_one(&self.db)
         .await?;
-        Ok(data)
+        Ok(data.into())
     }
 
     async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Account>> {
         let data = sqlx::query_as!(
-            Account,
-            r#"SELECT id, user_id, name, balance, currency as "currency: _" FROM accounts WHERE user_id = $1 ORDER BY name DESC"#,
+            AccountRow,
+            r#"SELECT id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months FROM accounts WHERE user_id = $1 ORDER BY name DESC"#,
             user_id,
         )
         .fetch_all(&self.db)
         .await?;
-        Ok(data)
+        Ok(data.into_iter().map(Account::from).collect())
     }
 
-    async fn find_movements(&self, account_id: Uuid) -> Result<Vec<Movement>> {
+    async fn find_movements(
+        &self,
+        account_id: Uuid,
+        filter: MovementFilter,
+        cursor: Option<MovementCursor>,
+        limit: i64,
+    ) -> Result<Vec<Movement>> {
+        let (cursor_timestamp, cursor_id) = cursor.map(|c| (c.timestamp, c.id)).unzip();
+
         let data = sqlx::query_as!(
             Movement,
-            r#"SELECT id, account_id, timestamp, title, amount, category as "category: _" 
+            r#"SELECT id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint
             FROM movements
             WHERE account_id = $1
-            ORDER BY timestamp DESC"#,
-            account_id
+            AND ($2::timestamptz IS NULL OR timestamp >= $2)
+            AND ($3::timestamptz IS NULL OR timestamp <= $3)
+            AND ($4::uuid[] IS NULL OR EXISTS (
+                WITH RECURSIVE tree AS (
+                    SELECT id FROM categories WHERE id = ANY($4)
+                    UNION
+                    SELECT categories.id FROM categories JOIN tree ON categories.parent_id = tree.id
+                )
+                SELECT 1 FROM tree
+                WHERE tree.id = movements.category_id
+                OR tree.id IN (SELECT s.category_id FROM movement_splits s WHERE s.movement_id = movements.id)
+            ))
+            AND ($5::numeric IS NULL OR amount >= $5)
+            AND ($6::numeric IS NULL OR amount <= $6)
+            AND ($7::varchar IS NULL OR strpos(lower(title), lower($7)) > 0)
+            AND ($8::timestamptz IS NULL OR (timestamp, id) < ($8, $9))
+            AND (NOT $11 OR transfer_id IS NULL)
+            AND ($12::varchar[] IS NULL OR tags && $12::varchar[])
+            ORDER BY timestamp DESC, id DESC
+            LIMIT $10"#,
+            account_id,
+            filter.from,
+            filter.to,
+            filter.category_ids.as_deref(),
+            filter.min_amount,
+            filter.max_amount,
+            filter.title,
+            cursor_timestamp,
+            cursor_id,
+            limit,
+            filter.exclude_transfers,
+            filter.tags.as_deref()
         )
         .fetch_all(&self.db)
         .await?;
         Ok(data)
     }
+
+    // A background task fetches the rows and hands them over through a bounded channel, so
+    // memory use does not depend on the number of movements
+    fn stream_movements(
+        &self,
+        account_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> BoxStream<'static, Result<Movement>> {
+        let db = self.db.clone();
+        let (sender, receiver) = mpsc::channel(64);
+        tokio::spawn(async move {
+            let mut rows = sqlx::query_as!(
+                Movement,
+                r#"SELECT id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint
+                FROM movements
+                WHERE account_id = $1
+                AND ($2::timestamptz IS NULL OR timestamp >= $2)
+                AND ($3::timestamptz IS NULL OR timestamp <= $3)
+                ORDER BY timestamp ASC, id ASC"#,
+                account_id,
+                from,
+                to
+            )
+            .fetch(&db);
+            while let Some(row) = rows.next().await {
+                if sender.send(row.map_err(Error::from)).await.is_err() {
+                    break;
+                }
+            }
+        });
+        stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|row| (row, receiver))
+        })
+        .boxed()
+    }
+
+    async fn has_movements(&self, account_id: Uuid) -> Result<bool> {
+        let data = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM movements WHERE account_id = $1) as "exists!""#,
+            account_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_balance_at(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Decimal> {
+        let data = sqlx::query_scalar!(
+            r#"SELECT a.balance - COALESCE(SUM(m.amount), 0) as "balance!"
+            FROM accounts a
+            LEFT JOIN movements m ON m.account_id = a.id AND m.timestamp > $2
+            WHERE a.id = $1
+            GROUP BY a.id"#,
+            account_id,
+            at
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_movement(&self, id: Uuid, account_id: Uuid) -> Result<Movement> {
+        let data = sqlx::query_as!(
+            Movement,
+            r#"SELECT id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint
+            FROM movements
+            WHERE id = $1 AND account_id = $2"#,
+            id,
+            account_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
     async fn insert_movement(&self, movement: Movement) -> Result<Movement> {
         let mut tx = self.db.begin().await?;
+        let data = insert_movement_tx(&mut tx, movement).await?;
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn insert_movements(
+        &self,
+        account_id: Uuid,
+        movements: Vec<Movement>,
+    ) -> Result<Vec<Movement>> {
+        let mut tx = self.db.begin().await?;
+        let mut data = vec![];
+        for movement in movements {
+            let movement = sqlx::query_as!(
+                Movement,
+                r#"INSERT INTO movements(id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status, note, tags, fingerprint)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                ON CONFLICT (account_id, external_id) DO NOTHING
+                RETURNING id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint"#,
+                movement.id,
+                account_id,
+                movement.timestamp,
+                movement.title,
+                movement.amount,
+                movement.category_id,
+                movement.transfer_id,
+                movement.external_id,
+                movement.status as _,
+                movement.note,
+                &movement.tags,
+                movement.fingerprint
+            )
+            .fetch_optional(&mut tx)
+            .await?;
+            data.extend(movement);
+        }
+        let total = data.iter().map(|movement| movement.amount).sum();
+        update_balance_tx(&mut tx, account_id, total).await?;
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn update_movement(&self, movement: Movement) -> Result<Movement> {
+        let mut tx = self.db.begin().await?;
+
+        let previous_amount = sqlx::query_scalar!(
+            "SELECT amount FROM movements WHERE id = $1 AND account_id = $2 FOR UPDATE",
+            movement.id,
+            movement.account_id
+        )
+        .fetch_one(&mut tx)
+        .await?;
 
         let data = sqlx::query_as!(
             Movement,
-            r#"INSERT INTO movements(id, account_id, timestamp, title, amount, category) 
-            VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING id, account_id, timestamp, title, amount, category as "category: _""#,
+            r#"UPDATE movements
+            SET title=$3, amount=$4, category_id=$5, status=$6, note=$7, tags=$8
+            WHERE id=$1 AND account_id=$2 AND status <> 'RECONCILED'
+            RETURNING id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint"#,
             movement.id,
             movement.account_id,
-            movement.timestamp,
             movement.title,
             movement.amount,
-            movement.category as _
+            movement.category_id,
+            movement.status as _,
+            movement.note,
+            &movement.tags
+        )
+        .fetch_one(&mut tx)
+        .await?;
+
+        update_balance_tx(&mut tx, data.account_id, data.amount - previous_amount).await?;
+
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn delete_movement(&self, movement: Movement) -> Result<Movement> {
+        let mut tx = self.db.begin().await?;
+
+        let data = sqlx::query_as!(
+            Movement,
+            r#"DELETE FROM movements
+            WHERE id=$1 AND account_id=$2 AND status <> 'RECONCILED'
+            RETURNING id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint"#,
+            movement.id,
+            movement.account_id
         )
         .fetch_one(&mut tx)
         .await?;
 
+        update_balance_tx(&mut tx, data.account_id, -data.amount).await?;
+
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn find_splits(&self, movement_ids: Vec<Uuid>) -> Result<Vec<MovementSplit>> {
+        let data = sqlx::query_as!(
+            MovementSplit,
+            r#"SELECT id, movement_id, category_id, amount, memo
+            FROM movement_splits
+            WHERE movement_id = ANY($1)
+            ORDER BY movement_id, position"#,
+            &movement_ids
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_tags(&self, user_id: Uuid) -> Result<Vec<Tag>> {
+        let data = sqlx::query_as!(
+            Tag,
+            r#"SELECT tag as "name!", COUNT(*) as "count!"
+            FROM movements m
+            JOIN accounts a ON a.id = m.account_id
+            CROSS JOIN unnest(m.tags) tag
+            WHERE a.user_id = $1
+            GROUP BY tag
+            ORDER BY tag"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn insert_split_movement(
+        &self,
+        movement: Movement,
+        splits: Vec<MovementSplit>,
+    ) -> Result<SplitMovement> {
+        let mut tx = self.db.begin().await?;
+        let movement = insert_movement_tx(&mut tx, movement).await?;
+        let splits = insert_splits_tx(&mut tx, splits).await?;
+        tx.commit().await?;
+        Ok(SplitMovement { movement, splits })
+    }
+
+    async fn update_splits(
+        &self,
+        movement: Movement,
+        splits: Vec<MovementSplit>,
+    ) -> Result<SplitMovement> {
+        let mut tx = self.db.begin().await?;
+
+        let movement = sqlx::query_as!(
+            Movement,
+            r#"UPDATE movements
+            SET category_id = NULL
+            WHERE id=$1 AND account_id=$2 AND status <> 'RECONCILED'
+            RETURNING id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint"#,
+            movement.id,
+            movement.account_id
+        )
+        .fetch_one(&mut tx)
+        .await?;
         sqlx::query!(
-            "UPDATE accounts SET balance = balance + $2 WHERE id = $1",
-            movement.account_id,
-            movement.amount
+            "DELETE FROM movement_splits WHERE movement_id = $1",
+            movement.id
         )
         .execute(&mut tx)
         .await?;
+        let splits = insert_splits_tx(&mut tx, splits).await?;
+
+        tx.commit().await?;
+        Ok(SplitMovement { movement, splits })
+    }
+
+    async fn find_transfer(&self, id: Uuid, user_id: Uuid) -> Result<Transfer> {
+        let transfer = sqlx::query_as!(
+            TransferRate,
+            "SELECT id, rate, rate_date FROM transfers WHERE id = $1",
+            id
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        let data = sqlx::query_as!(
+            Movement,
+            r#"SELECT m.id, m.account_id, m.timestamp, m.title, m.amount, m.category_id, m.transfer_id, m.external_id, m.status as "status: _", m.note, m.tags, m.fingerprint
+            FROM movements m
+            JOIN accounts a ON a.id = m.account_id
+            WHERE m.transfer_id = $1 AND a.user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        into_transfer(transfer, data)
+    }
+
+    async fn find_transfers(&self, user_id: Uuid) -> Result<Vec<Transfer>> {
+        let transfers = sqlx::query_as!(
+            TransferRate,
+            r#"SELECT id, rate, rate_date
+            FROM transfers
+            WHERE id IN (
+                SELECT m.transfer_id
+                FROM movements m
+                JOIN accounts a ON a.id = m.account_id
+                WHERE a.user_id = $1
+            )"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let data = sqlx::query_as!(
+            Movement,
+            r#"SELECT m.id, m.account_id, m.timestamp, m.title, m.amount, m.category_id, m.transfer_id, m.external_id, m.status as "status: _", m.note, m.tags, m.fingerprint
+            FROM movements m
+            JOIN accounts a ON a.id = m.account_id
+            WHERE m.transfer_id IS NOT NULL AND a.user_id = $1"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let mut legs: HashMap<Uuid, Vec<Movement>> = HashMap::new();
+        for movement in data {
+            if let Some(transfer_id) = movement.transfer_id {
+                legs.entry(transfer_id).or_default().push(movement);
+            }
+        }
+        transfers
+            .into_iter()
+            .map(|transfer| {
+                let movements = legs.remove(&transfer.id).unwrap_or_default();
+                into_transfer(transfer, movements)
+            })
+            .collect()
+    }
+
+    async fn insert_transfer(&self, transfer: Transfer) -> Result<Transfer> {
+        let mut legs = [transfer.debit, transfer.credit];
+        // Balances are always locked in the same order to avoid deadlocks between opposite transfers
+        legs.sort_by_key(|movement| movement.account_id);
+
+        let mut tx = self.db.begin().await?;
+        let rate = sqlx::query_as!(
+            TransferRate,
+            r#"INSERT INTO transfers(id, rate, rate_date)
+            VALUES ($1, $2, $3)
+            RETURNING id, rate, rate_date"#,
+            transfer.id,
+            transfer.rate,
+            transfer.rate_date
+        )
+        .fetch_one(&mut tx)
+        .await?;
+
+        let mut data = vec![];
+        for movement in legs {
+            data.push(insert_movement_tx(&mut tx, movement).await?);
+        }
+        tx.commit().await?;
+
+        into_transfer(rate, data)
+    }
+
+    async fn delete_transfer(&self, transfer: Transfer) -> Result<Transfer> {
+        let mut tx = self.db.begin().await?;
+
+        let data = sqlx::query_as!(
+            Movement,
+            r#"DELETE FROM movements
+            WHERE transfer_id = $1
+            RETURNING id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint"#,
+            transfer.id
+        )
+        .fetch_all(&mut tx)
+        .await?;
+        let rate = sqlx::query_as!(
+            TransferRate,
+            "DELETE FROM transfers WHERE id = $1 RETURNING id, rate, rate_date",
+            transfer.id
+        )
+        .fetch_one(&mut tx)
+        .await?;
+
+        let transfer = into_transfer(rate, data)?;
+        let mut legs = [&transfer.debit, &transfer.credit];
+        legs.sort_by_key(|movement| movement.account_id);
+        for movement in legs {
+            update_balance_tx(&mut tx, movement.account_id, -movement.amount).await?;
+        }
 
         tx.commit().await?;
+        Ok(transfer)
+    }
+
+    async fn find_balance_drifts(&self) -> Result<Vec<BalanceDrift>> {
+        let data = sqlx::query_as!(
+            BalanceDrift,
+            r#"SELECT a.id as account_id,
+                a.user_id,
+                a.balance,
+                a.opening_balance + COALESCE(SUM(m.amount), 0) as "expected!"
+            FROM accounts a
+            LEFT JOIN movements m ON m.account_id = a.id
+            GROUP BY a.id
+            HAVING a.balance <> a.opening_balance + COALESCE(SUM(m.amount), 0)
+            ORDER BY a.id"#
+        )
+        .fetch_all(&self.db)
+        .await?;
         Ok(data)
     }
+
+    // A single statement, so movements inserted concurrently are either counted or wait for it
+    async fn update_details(&self, account: Account) -> Result<Account> {
+        let data = sqlx::query_as!(
+            AccountRow,
+            r#"UPDATE accounts
+            SET name=$2, archived=$3, credit_limit=$4, apr=$5, statement_day=$6, loan_principal=$7, loan_term_months=$8
+            WHERE id=$1
+            RETURNING id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months"#,
+            account.id,
+            account.name,
+            account.archived,
+            account.terms.credit_limit,
+            account.terms.apr,
+            account.terms.statement_day,
+            account.terms.loan_principal,
+            account.terms.loan_term_months
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn repair_balance(&self, account_id: Uuid) -> Result<Account> {
+        let data = sqlx::query_as!(
+            AccountRow,
+            r#"UPDATE accounts
+            SET balance = opening_balance + (
+                SELECT COALESCE(SUM(amount), 0) FROM movements WHERE account_id = $1
+            )
+            WHERE id = $1
+            RETURNING id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months"#,
+            account_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
 }
 
 #[async_trait]
 impl Repository<Account> for PgAccountService {
     async fn get_all(&self) -> Result<Vec<Account>> {
         let data = sqlx::query_as!(
-            Account,
-            r#"SELECT id, user_id, name, balance, currency as "currency: _" FROM accounts ORDER BY name ASC"#
+            AccountRow,
+            r#"SELECT id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months FROM accounts ORDER BY name ASC"#
         )
         .fetch_all(&self.db)
         .await?;
-        Ok(data)
+        Ok(data.into_iter().map(Account::from).collect())
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Account> {
         let data = sqlx::query_as!(
-            Account,
-            r#"SELECT id, user_id, name, balance, currency as "currency: _" FROM accounts WHERE id = $1"#,
+            AccountRow,
+            r#"SELECT id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months FROM accounts WHERE id = $1"#,
             id
         )
         .fetch_one(&self.db)
         .await?;
-        Ok(data)
+        Ok(data.into())
     }
 
     async fn insert(&self, item: Account) -> Result<Account> {
         let data = sqlx::query_as!(
-            Account,
-            r#"INSERT INTO accounts(id, user_id, name, balance, currency)
-            VALUES($1, $2, $3, $4, $5)
-            RETURNING id, user_id, name, balance, currency as "currency: _""#,
+            AccountRow,
+            r#"INSERT INTO accounts(id, user_id, name, balance, currency, archived, opening_balance, opening_date, kind, credit_limit, apr, statement_day, loan_principal, loan_term_months)
+            VALUES($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            RETURNING id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months"#,
             item.id,
             item.user_id,
             item.name,
             item.balance,
-            item.currency as _
+            item.currency as _,
+            item.archived,
+            item.opening_balance,
+            item.opening_date,
+            item.kind as _,
+            item.terms.credit_limit,
+            item.terms.apr,
+            item.terms.statement_day,
+            item.terms.loan_principal,
+            item.terms.loan_term_months
         )
         .fetch_one(&self.db)
         .await?;
-        Ok(data)
+        Ok(data.into())
     }
 
     async fn update(&self, item: Account) -> Result<Account> {
         let data = sqlx::query_as!(
-            Account,
+            AccountRow,
             r#"UPDATE accounts
-            SET user_id=$2, name=$3, balance=$4, currency=$5
+            SET user_id=$2, name=$3, balance=$4, currency=$5, archived=$6, opening_balance=$7, opening_date=$8,
+                kind=$9, credit_limit=$10, apr=$11, statement_day=$12, loan_principal=$13, loan_term_months=$14
             WHERE id=$1
-            RETURNING id, user_id, name, balance, currency as "currency: _""#,
+            RETURNING id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months"#,
             item.id,
             item.user_id,
             item.name,
             item.balance,
-            item.currency as _
+            item.currency as _,
+            item.archived,
+            item.opening_balance,
+            item.opening_date,
+            item.kind as _,
+            item.terms.credit_limit,
+            item.terms.apr,
+            item.terms.statement_day,
+            item.terms.loan_principal,
+            item.terms.loan_term_months
         )
         .fetch_one(&self.db)
         .await?;
-        Ok(data)
+        Ok(data.into())
     }
 
     async fn delete(&self, item: Account) -> Result<Account> {
         let data = sqlx::query_as!(
-            Account,
-            r#"DELETE FROM accounts WHERE id=$1 RETURNING id, user_id, name, balance, currency as "currency: _""#,
+            AccountRow,
+            r#"DELETE FROM accounts WHERE id=$1 RETURNING id, user_id, name, balance, currency as "currency: _", archived, opening_balance, opening_date, kind as "kind: _", credit_limit, apr, statement_day, loan_principal, loan_term_months"#,
             item.id
         )
         .fetch_one(&self.db)
         .await?;
-        Ok(data)
+        Ok(data.into())
     }
 }
 
 #[cfg(test)]
 mod integration_tests {
-    use chrono::Utc;
+    use chrono::{TimeZone, Utc};
     use rust_decimal::Decimal;
     use sqlx::{Pool, Postgres};
 
     use super::*;
     use crate::{
-        domain::entities::{
-            accounts::{CategoryType, CurrencyType},
-            users::User,
-        },
-        infrastructure::pg::users::PgUserService,
+        domain::entities::{accounts::MovementStatus, categories::Category, users::User},
+        infrastructure::pg::{categories::PgCategoryService, users::PgUserService},
     };
 
     async fn insert_user(pool: Pool<Postgres>) -> User {
@@ -177,6 +774,26 @@ mod integration_tests {
             .insert(User {
                 id: Uuid::new_v4(),
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn insert_category(
+        pool: Pool<Postgres>,
+        user_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Category {
+        let category_service = PgCategoryService::new(pool);
+        category_service
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id,
+                parent_id,
+                name: "".to_string(),
+                color: None,
+                icon: None,
             })
             .await
             .unwrap()
@@ -192,7 +809,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -210,7 +832,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -235,7 +862,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -267,7 +899,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -276,14 +913,23 @@ mod integration_tests {
                 id: movement_id,
                 account_id: id,
                 amount: Decimal::from(0),
-                category: CategoryType::Generic,
+                category_id: None,
                 timestamp: Utc::now(),
                 title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
             })
             .await
             .unwrap();
         assert_eq!(
-            service.find_movements(account.id).await.unwrap(),
+            service
+                .find_movements(account.id, MovementFilter::default(), None, 50)
+                .await
+                .unwrap(),
             vec![movement]
         );
     }
@@ -300,7 +946,12 @@ mod integration_tests {
                     user_id: user.id,
                     name: "".to_string(),
                     balance: Decimal::from(0),
-                    currency: CurrencyType::Usd,
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
                 })
                 .await
                 .unwrap(),
@@ -309,11 +960,41 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             }
         );
     }
 
+    #[sqlx::test]
+    async fn insert_with_terms(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = Account {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            name: "".to_string(),
+            balance: Decimal::from(-250),
+            currency: Currency::USD,
+            archived: false,
+            opening_balance: Decimal::from(-250),
+            opening_date: None,
+            kind: AccountKind::CreditCard,
+            terms: AccountTerms {
+                credit_limit: Some(Decimal::from(2000)),
+                apr: Some(Decimal::new(1999, 2)),
+                statement_day: Some(15),
+                ..Default::default()
+            },
+        };
+        assert_eq!(service.insert(account.clone()).await.unwrap(), account);
+        assert_eq!(service.find_by_id(account.id).await.unwrap(), account);
+    }
+
     #[sqlx::test]
     #[should_panic(expected = "Repository(Conflict)")]
     async fn insert_conflict(pool: Pool<Postgres>) {
@@ -327,7 +1008,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -337,10 +1023,71 @@ mod integration_tests {
                 user_id: user2.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn update_details(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(5),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "title".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+
+        let updated = service
+            .update_details(Account {
+                name: "name".to_string(),
+                archived: true,
+                ..account.clone()
             })
             .await
             .unwrap();
+        assert_eq!(
+            updated,
+            Account {
+                name: "name".to_string(),
+                archived: true,
+                balance: Decimal::from(5),
+                ..account
+            }
+        );
     }
 
     #[sqlx::test]
@@ -355,7 +1102,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -364,9 +1116,15 @@ mod integration_tests {
                 id: movement_id,
                 account_id: account.id,
                 amount: Decimal::from(0),
-                category: CategoryType::Generic,
+                category_id: None,
                 timestamp,
                 title: "title".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
             })
             .await
             .unwrap();
@@ -374,45 +1132,785 @@ mod integration_tests {
         assert_eq!(movement.id, movement_id);
         assert_eq!(movement.account_id, account.id);
         assert_eq!(movement.amount, Decimal::from(0));
-        assert_eq!(movement.category, CategoryType::Generic);
+        assert_eq!(movement.category_id, None);
         assert_eq!(movement.title, "title".to_string());
     }
 
     #[sqlx::test]
-    #[should_panic(expected = "Repository(Conflict)")]
-    async fn insert_movement_conflict(pool: Pool<Postgres>) {
+    async fn insert_movements(pool: Pool<Postgres>) {
         let service = PgAccountService::new(pool.clone());
-        let movement_id = Uuid::new_v4();
         let user = insert_user(pool).await;
         let account = service
             .insert(Account {
                 id: Uuid::new_v4(),
                 user_id: user.id,
                 name: "".to_string(),
-                balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                balance: Decimal::from(10),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
-        service
-            .insert_movement(Movement {
-                id: movement_id,
+        let movements: Vec<Movement> = [Decimal::from(-3), Decimal::from(5)]
+            .into_iter()
+            .map(|amount| Movement {
+                id: Uuid::new_v4(),
                 account_id: account.id,
-                amount: Decimal::from(0),
-                category: CategoryType::Generic,
+                amount,
+                category_id: None,
                 timestamp: Utc::now(),
-                title: "".to_string(),
+                title: "title".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
             })
+            .collect();
+
+        let result = service
+            .insert_movements(account.id, movements.clone())
             .await
             .unwrap();
-        service
-            .insert_movement(Movement {
-                id: movement_id,
-                account_id: account.id,
-                amount: Decimal::from(0),
-                category: CategoryType::Generic,
-                timestamp: Utc::now(),
+
+        assert_eq!(
+            result.iter().map(|m| m.id).collect::<Vec<_>>(),
+            movements.iter().map(|m| m.id).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            service.find_by_id(account.id).await.unwrap().balance,
+            Decimal::from(12)
+        );
+    }
+
+    #[sqlx::test]
+    async fn insert_movements_skips_imported_external_ids(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let get_movements = |external_ids: &[&str]| -> Vec<Movement> {
+            external_ids
+                .iter()
+                .map(|external_id| Movement {
+                    id: Uuid::new_v4(),
+                    account_id: account.id,
+                    amount: Decimal::from(5),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "title".to_string(),
+                    transfer_id: None,
+                    external_id: Some(external_id.to_string()),
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+                .collect()
+        };
+
+        service
+            .insert_movements(account.id, get_movements(&["1", "2"]))
+            .await
+            .unwrap();
+        let result = service
+            .insert_movements(account.id, get_movements(&["2", "3", "3"]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|movement| movement.external_id.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some("3")]
+        );
+        assert_eq!(
+            service.find_by_id(account.id).await.unwrap().balance,
+            Decimal::from(15)
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(Conflict)")]
+    async fn insert_movement_conflict(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let movement_id = Uuid::new_v4();
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        service
+            .insert_movement(Movement {
+                id: movement_id,
+                account_id: account.id,
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+        service
+            .insert_movement(Movement {
+                id: movement_id,
+                account_id: account.id,
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_movements_paginated(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let timestamp = Utc::now();
+        let mut movements = vec![];
+        for _ in 0..3 {
+            movements.push(
+                service
+                    .insert_movement(Movement {
+                        id: Uuid::new_v4(),
+                        account_id: account.id,
+                        amount: Decimal::from(0),
+                        category_id: None,
+                        timestamp,
+                        title: "".to_string(),
+                        transfer_id: None,
+                        external_id: None,
+                        status: MovementStatus::Pending,
+                        note: None,
+                        tags: vec![],
+                        fingerprint: None,
+                    })
+                    .await
+                    .unwrap(),
+            );
+        }
+        movements.sort_by_key(|movement| std::cmp::Reverse(movement.id));
+
+        let first_page = service
+            .find_movements(account.id, MovementFilter::default(), None, 2)
+            .await
+            .unwrap();
+        let second_page = service
+            .find_movements(
+                account.id,
+                MovementFilter::default(),
+                Some(MovementCursor::from(first_page.last().unwrap())),
+                2,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(first_page, movements[..2].to_vec());
+        assert_eq!(second_page, movements[2..].to_vec());
+    }
+
+    #[sqlx::test]
+    async fn find_movements_filtered(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let bills = insert_category(pool.clone(), user.id, None).await;
+        let food = insert_category(pool.clone(), user.id, None).await;
+        let groceries = insert_category(pool.clone(), user.id, Some(food.id)).await;
+        let income = insert_category(pool, user.id, None).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let rent = service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(-500),
+                category_id: Some(bills.id),
+                timestamp: Utc::now(),
+                title: "Monthly Rent".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+        let groceries = service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(-20),
+                category_id: Some(groceries.id),
+                timestamp: Utc::now(),
+                title: "Groceries".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+        service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(1000),
+                category_id: Some(income.id),
+                timestamp: Utc::now(),
+                title: "Salary".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service
+                .find_movements(
+                    account.id,
+                    MovementFilter {
+                        category_ids: Some(vec![bills.id, food.id]),
+                        max_amount: Some(Decimal::from(-100)),
+                        title: Some("rent".to_string()),
+                        from: Some(rent.timestamp - chrono::Duration::days(1)),
+                        ..Default::default()
+                    },
+                    None,
+                    50
+                )
+                .await
+                .unwrap(),
+            vec![rent]
+        );
+        assert_eq!(
+            service
+                .find_movements(
+                    account.id,
+                    MovementFilter {
+                        category_ids: Some(vec![food.id]),
+                        ..Default::default()
+                    },
+                    None,
+                    50
+                )
+                .await
+                .unwrap(),
+            vec![groceries]
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_movements_by_tag_and_find_tags(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let mut movements = vec![];
+        for (title, tags) in [
+            ("Groceries", vec!["food", "home"]),
+            ("Plumber", vec!["home"]),
+            ("Salary", vec![]),
+        ] {
+            let movement = service
+                .insert_movement(Movement {
+                    id: Uuid::new_v4(),
+                    account_id: account.id,
+                    amount: Decimal::from(-10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: title.to_string(),
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: Some("note".to_string()),
+                    tags: tags.into_iter().map(str::to_string).collect(),
+                    fingerprint: None,
+                })
+                .await
+                .unwrap();
+            movements.push(movement);
+        }
+
+        assert_eq!(
+            service
+                .find_movements(
+                    account.id,
+                    MovementFilter {
+                        tags: Some(vec!["food".to_string(), "travel".to_string()]),
+                        ..Default::default()
+                    },
+                    None,
+                    50
+                )
+                .await
+                .unwrap(),
+            vec![movements[0].clone()]
+        );
+        assert_eq!(
+            service.find_tags(user.id).await.unwrap(),
+            vec![
+                Tag {
+                    name: "food".to_string(),
+                    count: 1,
+                },
+                Tag {
+                    name: "home".to_string(),
+                    count: 2,
+                },
+            ]
+        );
+        assert_eq!(service.find_tags(Uuid::new_v4()).await.unwrap(), vec![]);
+    }
+
+    #[sqlx::test]
+    async fn stream_movements(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let timestamps = [
+            Utc.with_ymd_and_hms(2023, 1, 3, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap(),
+        ];
+        for timestamp in timestamps {
+            service
+                .insert_movement(Movement {
+                    id: Uuid::new_v4(),
+                    account_id: account.id,
+                    amount: Decimal::from(1),
+                    category_id: None,
+                    timestamp,
+                    title: "title".to_string(),
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let movements: Vec<Movement> = service
+            .stream_movements(account.id, Some(timestamps[2]), None)
+            .map(|movement| movement.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(
+            movements
+                .iter()
+                .map(|movement| movement.timestamp)
+                .collect::<Vec<_>>(),
+            vec![timestamps[2], timestamps[0]]
+        );
+    }
+
+    #[sqlx::test]
+    async fn has_movements(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        assert!(!service.has_movements(account.id).await.unwrap());
+        service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+        assert!(service.has_movements(account.id).await.unwrap());
+    }
+
+    #[sqlx::test]
+    async fn find_balance_at(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        for (day, amount) in [(1, 10), (2, 20), (3, 40)] {
+            service
+                .insert_movement(Movement {
+                    id: Uuid::new_v4(),
+                    account_id: account.id,
+                    amount: Decimal::from(amount),
+                    category_id: None,
+                    timestamp: Utc.with_ymd_and_hms(2023, 1, day, 0, 0, 0).unwrap(),
+                    title: "title".to_string(),
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let at = |day| Utc.with_ymd_and_hms(2023, 1, day, 0, 0, 0).unwrap();
+        assert_eq!(
+            service.find_balance_at(account.id, at(2)).await.unwrap(),
+            Decimal::from(30)
+        );
+        assert_eq!(
+            service.find_balance_at(account.id, at(4)).await.unwrap(),
+            Decimal::from(70)
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_movement(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let movement = service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            service
+                .find_movement(movement.id, account.id)
+                .await
+                .unwrap(),
+            movement
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_movement_not_found(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool);
+        service
+            .find_movement(Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn update_movement(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool, user.id, None).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let movement = service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(10),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "title".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+        let updated = service
+            .update_movement(Movement {
+                amount: Decimal::from(-5),
+                category_id: Some(category.id),
+                title: "new title".to_string(),
+                ..movement.clone()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(updated.id, movement.id);
+        assert_eq!(updated.timestamp, movement.timestamp);
+        assert_eq!(updated.amount, Decimal::from(-5));
+        assert_eq!(updated.category_id, Some(category.id));
+        assert_eq!(updated.title, "new title".to_string());
+        assert_eq!(
+            service.find_by_id(account.id).await.unwrap().balance,
+            Decimal::from(-5)
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn update_movement_not_found(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool);
+        service
+            .update_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: Uuid::new_v4(),
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn delete_movement(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let movement = service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(10),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.delete_movement(movement.clone()).await.unwrap(),
+            movement
+        );
+        assert_eq!(
+            service
+                .find_movements(account.id, MovementFilter::default(), None, 50)
+                .await
+                .unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            service.find_by_id(account.id).await.unwrap().balance,
+            Decimal::from(0)
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn delete_movement_not_found(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool);
+        service
+            .delete_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: Uuid::new_v4(),
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: Utc::now(),
                 title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
             })
             .await
             .unwrap();
@@ -429,7 +1927,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -440,7 +1943,12 @@ mod integration_tests {
                     user_id: user.id,
                     name: "name".to_string(),
                     balance: Decimal::from(1),
-                    currency: CurrencyType::Eur,
+                    currency: Currency::EUR,
+                    archived: true,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
                 })
                 .await
                 .unwrap(),
@@ -449,7 +1957,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "name".to_string(),
                 balance: Decimal::from(1),
-                currency: CurrencyType::Eur,
+                currency: Currency::EUR,
+                archived: true,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             }
         );
     }
@@ -464,7 +1977,12 @@ mod integration_tests {
                 user_id: Uuid::new_v4(),
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -481,7 +1999,12 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             })
             .await
             .unwrap();
@@ -492,7 +2015,12 @@ mod integration_tests {
                     user_id: user.id,
                     name: "".to_string(),
                     balance: Decimal::from(0),
-                    currency: CurrencyType::Usd,
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
                 })
                 .await
                 .unwrap(),
@@ -501,11 +2029,56 @@ mod integration_tests {
                 user_id: user.id,
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
             }
         );
     }
 
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(Conflict)")]
+    async fn delete_conflict(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        service
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                amount: Decimal::from(0),
+                category_id: None,
+                timestamp: Utc::now(),
+                title: "".to_string(),
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap();
+        service.delete(account).await.unwrap();
+    }
+
     #[sqlx::test]
     #[should_panic(expected = "Repository(NotFound)")]
     async fn delete_not_found(pool: Pool<Postgres>) {
@@ -516,9 +2089,520 @@ mod integration_tests {
                 user_id: Uuid::new_v4(),
                 name: "".to_string(),
                 balance: Decimal::from(0),
-                currency: CurrencyType::Usd,
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn insert_transfer(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let from = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let to = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let id = Uuid::new_v4();
+        let transfer = service
+            .insert_transfer(Transfer {
+                id,
+                debit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: from.id,
+                    amount: Decimal::from(-10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                credit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: to.id,
+                    amount: Decimal::from(10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                rate: Decimal::from(1),
+                rate_date: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(service.find_transfer(id, user.id).await.unwrap(), transfer);
+        assert_eq!(
+            service.find_transfers(user.id).await.unwrap(),
+            vec![transfer]
+        );
+        assert_eq!(
+            service.find_transfers(Uuid::new_v4()).await.unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            service.find_by_id(from.id).await.unwrap().balance,
+            Decimal::from(-10)
+        );
+        assert_eq!(
+            service.find_by_id(to.id).await.unwrap().balance,
+            Decimal::from(10)
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_transfer_not_found(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool);
+        service
+            .find_transfer(Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_movements_exclude_transfers(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let from = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let to = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let id = Uuid::new_v4();
+        let transfer = service
+            .insert_transfer(Transfer {
+                id,
+                debit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: from.id,
+                    amount: Decimal::from(-10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                credit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: to.id,
+                    amount: Decimal::from(10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                rate: Decimal::from(1),
+                rate_date: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service
+                .find_movements(from.id, MovementFilter::default(), None, 50)
+                .await
+                .unwrap(),
+            vec![transfer.debit]
+        );
+        assert_eq!(
+            service
+                .find_movements(
+                    from.id,
+                    MovementFilter {
+                        exclude_transfers: true,
+                        ..Default::default()
+                    },
+                    None,
+                    50
+                )
+                .await
+                .unwrap(),
+            vec![]
+        );
+    }
+
+    #[sqlx::test]
+    async fn delete_transfer(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let from = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let to = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let id = Uuid::new_v4();
+        let transfer = service
+            .insert_transfer(Transfer {
+                id,
+                debit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: from.id,
+                    amount: Decimal::from(-10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                credit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: to.id,
+                    amount: Decimal::from(10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                rate: Decimal::from(1),
+                rate_date: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.delete_transfer(transfer.clone()).await.unwrap(),
+            transfer
+        );
+        assert_eq!(
+            service.find_by_id(from.id).await.unwrap().balance,
+            Decimal::from(0)
+        );
+        assert_eq!(
+            service.find_by_id(to.id).await.unwrap().balance,
+            Decimal::from(0)
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn delete_transfer_not_found(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool);
+        let id = Uuid::new_v4();
+        service
+            .delete_transfer(Transfer {
+                id,
+                debit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: Uuid::new_v4(),
+                    amount: Decimal::from(-10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                credit: Movement {
+                    id: Uuid::new_v4(),
+                    account_id: Uuid::new_v4(),
+                    amount: Decimal::from(10),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "".to_string(),
+                    transfer_id: Some(id),
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                rate: Decimal::from(1),
+                rate_date: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_balance_drifts_and_repair(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let mut accounts = vec![];
+        for opening_balance in [100, 50] {
+            let account = service
+                .insert(Account {
+                    id: Uuid::new_v4(),
+                    user_id: user.id,
+                    name: "".to_string(),
+                    balance: Decimal::from(opening_balance),
+                    currency: Currency::USD,
+                    archived: false,
+                    opening_balance: Decimal::from(opening_balance),
+                    opening_date: NaiveDate::from_ymd_opt(2023, 1, 1),
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+                .await
+                .unwrap();
+            service
+                .insert_movement(Movement {
+                    id: Uuid::new_v4(),
+                    account_id: account.id,
+                    amount: Decimal::from(-20),
+                    category_id: None,
+                    timestamp: Utc::now(),
+                    title: "title".to_string(),
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+                .await
+                .unwrap();
+            accounts.push(account);
+        }
+        assert_eq!(service.find_balance_drifts().await.unwrap(), vec![]);
+
+        sqlx::query!(
+            "UPDATE movements SET amount = -30 WHERE account_id = $1",
+            accounts[0].id
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            service.find_balance_drifts().await.unwrap(),
+            vec![BalanceDrift {
+                account_id: accounts[0].id,
+                user_id: user.id,
+                balance: Decimal::from(80),
+                expected: Decimal::from(70),
+            }]
+        );
+        assert_eq!(
+            service
+                .repair_balance(accounts[0].id)
+                .await
+                .unwrap()
+                .balance,
+            Decimal::from(70)
+        );
+        assert_eq!(service.find_balance_drifts().await.unwrap(), vec![]);
+        assert_eq!(
+            service.find_by_id(accounts[1].id).await.unwrap().balance,
+            Decimal::from(30)
+        );
+    }
+
+    #[sqlx::test]
+    async fn split_movement(pool: Pool<Postgres>) {
+        let service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let groceries = insert_category(pool.clone(), user.id, None).await;
+        let household = insert_category(pool, user.id, None).await;
+        let account = service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let movement = Movement {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            amount: Decimal::from(-20),
+            category_id: None,
+            timestamp: Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+            title: "supermarket".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let split = |category_id, amount| MovementSplit {
+            id: Uuid::new_v4(),
+            movement_id: movement.id,
+            category_id,
+            amount,
+            memo: None,
+        };
+        let splits = vec![
+            split(groceries.id, Decimal::from(-15)),
+            split(household.id, Decimal::from(-5)),
+        ];
+
+        let inserted = service
+            .insert_split_movement(movement.clone(), splits.clone())
+            .await
+            .unwrap();
+        assert_eq!(inserted.movement, movement);
+        assert_eq!(
+            service.find_by_id(account.id).await.unwrap().balance,
+            Decimal::from(-20)
+        );
+        assert_eq!(
+            service.find_splits(vec![movement.id]).await.unwrap(),
+            splits
+        );
+        assert_eq!(
+            service
+                .find_movements(
+                    account.id,
+                    MovementFilter {
+                        category_ids: Some(vec![household.id]),
+                        ..MovementFilter::default()
+                    },
+                    None,
+                    10
+                )
+                .await
+                .unwrap(),
+            vec![movement.clone()]
+        );
+
+        let replaced = vec![
+            split(groceries.id, Decimal::from(-10)),
+            split(household.id, Decimal::from(-10)),
+        ];
+        service
+            .update_splits(movement.clone(), replaced.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            service.find_splits(vec![movement.id]).await.unwrap(),
+            replaced
+        );
+
+        service
+            .update_movement(Movement {
+                status: MovementStatus::Cleared,
+                ..movement.clone()
             })
             .await
             .unwrap();
+        assert_eq!(
+            service.find_splits(vec![movement.id]).await.unwrap(),
+            replaced
+        );
+        assert_eq!(
+            service.find_by_id(account.id).await.unwrap().balance,
+            Decimal::from(-20)
+        );
     }
 }
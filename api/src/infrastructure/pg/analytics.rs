@@ -0,0 +1,842 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::application::services::analytics::AnalyticsService;
+use crate::domain::entities::analytics::{
+    BalancePoint, CategoryTotal, Interval, PeriodTotal, TitleTotal,
+};
+use crate::domain::error::Result;
+
+pub struct PgAnalyticsService {
+    db: PgPool,
+}
+
+impl PgAnalyticsService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AnalyticsService for PgAnalyticsService {
+    async fn find_period_totals(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+    ) -> Result<Vec<PeriodTotal>> {
+        let data = sqlx::query_as!(
+            PeriodTotal,
+            r#"SELECT date_trunc($5::text, m.timestamp AT TIME ZONE 'UTC')::date as "period!",
+                a.currency as "currency: _",
+                COALESCE(SUM(m.amount) FILTER (WHERE m.amount > 0), 0) as "income!",
+                COALESCE(-SUM(m.amount) FILTER (WHERE m.amount < 0), 0) as "expense!"
+            FROM movements m
+            JOIN accounts a ON a.id = m.account_id
+            WHERE a.user_id = $1
+            AND ($2::uuid IS NULL OR a.id = $2)
+            AND m.transfer_id IS NULL
+            AND NOT EXISTS (SELECT 1 FROM trades t WHERE t.movement_id = m.id)
+            AND m.timestamp >= $3::date::timestamp AT TIME ZONE 'UTC'
+            AND m.timestamp < $4::date::timestamp AT TIME ZONE 'UTC'
+            AND ($6::varchar IS NULL OR $6 = ANY(m.tags))
+            GROUP BY 1, 2
+            ORDER BY 1, 2"#,
+            user_id,
+            account_id,
+            from,
+            to,
+            interval.as_str(),
+            tag
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_category_totals(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<CategoryTotal>> {
+        let data = sqlx::query_as!(
+            CategoryTotal,
+            r#"SELECT COALESCE(s.category_id, m.category_id) as category_id,
+                a.currency as "currency: _",
+                COALESCE(SUM(COALESCE(s.amount, m.amount)) FILTER (WHERE COALESCE(s.amount, m.amount) > 0), 0) as "income!",
+                COALESCE(-SUM(COALESCE(s.amount, m.amount)) FILTER (WHERE COALESCE(s.amount, m.amount) < 0), 0) as "expense!"
+            FROM movements m
+            JOIN accounts a ON a.id = m.account_id
+            LEFT JOIN movement_splits s ON s.movement_id = m.id
+            WHERE a.user_id = $1
+            AND ($2::uuid IS NULL OR a.id = $2)
+            AND m.transfer_id IS NULL
+            AND NOT EXISTS (SELECT 1 FROM trades t WHERE t.movement_id = m.id)
+            AND m.timestamp >= $3::date::timestamp AT TIME ZONE 'UTC'
+            AND m.timestamp < $4::date::timestamp AT TIME ZONE 'UTC'
+            AND ($5::varchar IS NULL OR $5 = ANY(m.tags))
+            GROUP BY 1, 2
+            ORDER BY 4 DESC, 1, 2"#,
+            user_id,
+            account_id,
+            from,
+            to,
+            tag
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_top_titles(
+        &self,
+        user_id: Uuid,
+        account_id: Option<Uuid>,
+        tag: Option<String>,
+        from: NaiveDate,
+        to: NaiveDate,
+        limit: i64,
+    ) -> Result<Vec<TitleTotal>> {
+        let data = sqlx::query_as!(
+            TitleTotal,
+            r#"SELECT title as "title!",
+                currency as "currency!: _",
+                count as "count!",
+                expense as "expense!"
+            FROM (
+                SELECT m.title,
+                    a.currency,
+                    COUNT(*) as count,
+                    -SUM(m.amount) as expense,
+                    ROW_NUMBER() OVER (PARTITION BY a.currency ORDER BY -SUM(m.amount) DESC, m.title) as rank
+                FROM movements m
+                JOIN accounts a ON a.id = m.account_id
+                WHERE a.user_id = $1
+                AND ($2::uuid IS NULL OR a.id = $2)
+                AND m.transfer_id IS NULL
+                AND NOT EXISTS (SELECT 1 FROM trades t WHERE t.movement_id = m.id)
+                AND m.amount < 0
+                AND m.timestamp >= $3::date::timestamp AT TIME ZONE 'UTC'
+                AND m.timestamp < $4::date::timestamp AT TIME ZONE 'UTC'
+                AND ($6::varchar IS NULL OR $6 = ANY(m.tags))
+                GROUP BY m.title, a.currency
+            ) titles
+            WHERE rank <= $5
+            ORDER BY expense DESC, title, currency"#,
+            user_id,
+            account_id,
+            from,
+            to,
+            limit,
+            tag
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    // The balance before the first movement is the current balance minus all the movements, so
+    // an opening balance without a movement is carried through the whole series
+    async fn find_balances(
+        &self,
+        account_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+        interval: Interval,
+    ) -> Result<Vec<BalancePoint>> {
+        let data = sqlx::query_as!(
+            BalancePoint,
+            r#"WITH bounds AS (
+                SELECT GREATEST($2::date, date_trunc($4::text, opening_date)::date) as start
+                FROM accounts
+                WHERE id = $1
+            ), amounts AS (
+                SELECT date_trunc($4::text, timestamp AT TIME ZONE 'UTC')::date as period, amount
+                FROM movements
+                WHERE account_id = $1
+                UNION ALL
+                SELECT generate_series(start, $3::date, ('1 ' || $4::text)::interval)::date, 0
+                FROM bounds
+            ), balances AS (
+                SELECT period,
+                    a.balance
+                        - SUM(SUM(amount)) OVER ()
+                        + SUM(SUM(amount)) OVER (ORDER BY period) as balance
+                FROM amounts
+                JOIN accounts a ON a.id = $1
+                GROUP BY period, a.balance
+            )
+            SELECT period as "period!", balance as "balance!"
+            FROM balances
+            WHERE period >= (SELECT start FROM bounds) AND period <= $3
+            ORDER BY period"#,
+            account_id,
+            from,
+            to,
+            interval.as_str()
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use chrono::{TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        application::services::{accounts::AccountService, investments::TradeService, Repository},
+        domain::entities::{
+            accounts::{
+                Account, AccountKind, AccountTerms, Movement, MovementSplit, MovementStatus,
+            },
+            categories::Category,
+            currencies::Currency,
+            investments::{Trade, TradeKind},
+            users::User,
+        },
+        infrastructure::pg::{
+            accounts::PgAccountService, categories::PgCategoryService, investments::PgTradeService,
+            users::PgUserService,
+        },
+    };
+
+    struct Fixture {
+        user_id: Uuid,
+        usd_account_id: Uuid,
+        category_id: Uuid,
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    async fn insert_fixture(pool: Pool<Postgres>) -> Fixture {
+        let user = PgUserService::new(pool.clone())
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap();
+        let category = PgCategoryService::new(pool.clone())
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                parent_id: None,
+                name: "Groceries".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+
+        let account_service = PgAccountService::new(pool);
+        let mut account_ids = vec![];
+        for currency in [Currency::USD, Currency::EUR] {
+            let account = account_service
+                .insert(Account {
+                    id: Uuid::new_v4(),
+                    user_id: user.id,
+                    name: "name".to_string(),
+                    currency,
+                    balance: Decimal::from(0),
+                    archived: false,
+                    opening_balance: Decimal::from(0),
+                    opening_date: None,
+                    kind: AccountKind::Checking,
+                    terms: AccountTerms::default(),
+                })
+                .await
+                .unwrap();
+            account_ids.push(account.id);
+        }
+
+        for (account_id, timestamp, title, amount, category_id) in [
+            (
+                account_ids[0],
+                Utc.with_ymd_and_hms(2023, 1, 2, 10, 0, 0),
+                "Grocery",
+                -50,
+                Some(category.id),
+            ),
+            (
+                account_ids[0],
+                Utc.with_ymd_and_hms(2023, 1, 20, 10, 0, 0),
+                "Grocery",
+                -30,
+                Some(category.id),
+            ),
+            (
+                account_ids[0],
+                Utc.with_ymd_and_hms(2023, 1, 31, 23, 0, 0),
+                "Salary",
+                1000,
+                None,
+            ),
+            (
+                account_ids[0],
+                Utc.with_ymd_and_hms(2023, 2, 3, 10, 0, 0),
+                "Rent",
+                -500,
+                None,
+            ),
+            (
+                account_ids[1],
+                Utc.with_ymd_and_hms(2023, 1, 10, 10, 0, 0),
+                "Bakery",
+                -5,
+                Some(category.id),
+            ),
+            (
+                account_ids[0],
+                Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0),
+                "Grocery",
+                -40,
+                Some(category.id),
+            ),
+        ] {
+            account_service
+                .insert_movement(Movement {
+                    id: Uuid::new_v4(),
+                    account_id,
+                    timestamp: timestamp.unwrap(),
+                    title: title.to_string(),
+                    amount: Decimal::from(amount),
+                    category_id,
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: match title {
+                        "Grocery" | "Bakery" => vec!["food".to_string()],
+                        _ => vec![],
+                    },
+                    fingerprint: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        Fixture {
+            user_id: user.id,
+            usd_account_id: account_ids[0],
+            category_id: category.id,
+        }
+    }
+
+    #[sqlx::test]
+    async fn find_period_totals(pool: Pool<Postgres>) {
+        let service = PgAnalyticsService::new(pool.clone());
+        let fixture = insert_fixture(pool).await;
+
+        assert_eq!(
+            service
+                .find_period_totals(
+                    fixture.user_id,
+                    None,
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 3, 1),
+                    Interval::Month
+                )
+                .await
+                .unwrap(),
+            vec![
+                PeriodTotal {
+                    period: date(2023, 1, 1),
+                    currency: Currency::EUR,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(5),
+                },
+                PeriodTotal {
+                    period: date(2023, 1, 1),
+                    currency: Currency::USD,
+                    income: Decimal::from(1000),
+                    expense: Decimal::from(80),
+                },
+                PeriodTotal {
+                    period: date(2023, 2, 1),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(500),
+                },
+            ]
+        );
+
+        assert_eq!(
+            service
+                .find_period_totals(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 2, 1),
+                    Interval::Week
+                )
+                .await
+                .unwrap(),
+            vec![
+                PeriodTotal {
+                    period: date(2023, 1, 2),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(50),
+                },
+                PeriodTotal {
+                    period: date(2023, 1, 16),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(30),
+                },
+                PeriodTotal {
+                    period: date(2023, 1, 30),
+                    currency: Currency::USD,
+                    income: Decimal::from(1000),
+                    expense: Decimal::from(0),
+                },
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_category_totals(pool: Pool<Postgres>) {
+        let service = PgAnalyticsService::new(pool.clone());
+        let fixture = insert_fixture(pool).await;
+
+        assert_eq!(
+            service
+                .find_category_totals(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 3, 1)
+                )
+                .await
+                .unwrap(),
+            vec![
+                CategoryTotal {
+                    category_id: None,
+                    currency: Currency::USD,
+                    income: Decimal::from(1000),
+                    expense: Decimal::from(500),
+                },
+                CategoryTotal {
+                    category_id: Some(fixture.category_id),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(80),
+                },
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_top_titles(pool: Pool<Postgres>) {
+        let service = PgAnalyticsService::new(pool.clone());
+        let fixture = insert_fixture(pool).await;
+
+        assert_eq!(
+            service
+                .find_top_titles(
+                    fixture.user_id,
+                    None,
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 3, 1),
+                    1
+                )
+                .await
+                .unwrap(),
+            vec![
+                TitleTotal {
+                    title: "Rent".to_string(),
+                    currency: Currency::USD,
+                    count: 1,
+                    expense: Decimal::from(500),
+                },
+                TitleTotal {
+                    title: "Bakery".to_string(),
+                    currency: Currency::EUR,
+                    count: 1,
+                    expense: Decimal::from(5),
+                },
+            ]
+        );
+        assert_eq!(
+            service
+                .find_top_titles(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 3, 1),
+                    10
+                )
+                .await
+                .unwrap()
+                .iter()
+                .map(|total| (total.title.as_str(), total.count))
+                .collect::<Vec<_>>(),
+            vec![("Rent", 1), ("Grocery", 2)]
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_totals_by_tag(pool: Pool<Postgres>) {
+        let service = PgAnalyticsService::new(pool.clone());
+        let fixture = insert_fixture(pool).await;
+
+        assert_eq!(
+            service
+                .find_period_totals(
+                    fixture.user_id,
+                    None,
+                    Some("food".to_string()),
+                    date(2023, 1, 1),
+                    date(2023, 3, 1),
+                    Interval::Month
+                )
+                .await
+                .unwrap(),
+            vec![
+                PeriodTotal {
+                    period: date(2023, 1, 1),
+                    currency: Currency::EUR,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(5),
+                },
+                PeriodTotal {
+                    period: date(2023, 1, 1),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(80),
+                },
+            ]
+        );
+        assert_eq!(
+            service
+                .find_category_totals(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    Some("food".to_string()),
+                    date(2023, 1, 1),
+                    date(2023, 3, 1)
+                )
+                .await
+                .unwrap(),
+            vec![CategoryTotal {
+                category_id: Some(fixture.category_id),
+                currency: Currency::USD,
+                income: Decimal::from(0),
+                expense: Decimal::from(80),
+            }]
+        );
+        assert_eq!(
+            service
+                .find_top_titles(
+                    fixture.user_id,
+                    None,
+                    Some("travel".to_string()),
+                    date(2023, 1, 1),
+                    date(2023, 3, 1),
+                    10
+                )
+                .await
+                .unwrap(),
+            vec![]
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_balances(pool: Pool<Postgres>) {
+        let service = PgAnalyticsService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let fixture = insert_fixture(pool).await;
+        let account = account_service
+            .find_by_id(fixture.usd_account_id)
+            .await
+            .unwrap();
+        account_service
+            .update(Account {
+                balance: Decimal::from(480),
+                ..account
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service
+                .find_balances(
+                    fixture.usd_account_id,
+                    date(2022, 12, 1),
+                    date(2023, 4, 1),
+                    Interval::Month
+                )
+                .await
+                .unwrap(),
+            [
+                (2022, 12, 100),
+                (2023, 1, 1020),
+                (2023, 2, 520),
+                (2023, 3, 480),
+                (2023, 4, 480)
+            ]
+            .into_iter()
+            .map(|(year, month, balance)| BalancePoint {
+                period: date(year, month, 1),
+                balance: Decimal::from(balance),
+            })
+            .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            service
+                .find_balances(
+                    fixture.usd_account_id,
+                    date(2023, 1, 19),
+                    date(2023, 1, 21),
+                    Interval::Day
+                )
+                .await
+                .unwrap(),
+            [(19, 50), (20, 20), (21, 20)]
+                .into_iter()
+                .map(|(day, balance)| BalancePoint {
+                    period: date(2023, 1, day),
+                    balance: Decimal::from(balance),
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let account = account_service
+            .find_by_id(fixture.usd_account_id)
+            .await
+            .unwrap();
+        account_service
+            .update(Account {
+                opening_date: Some(date(2023, 1, 2)),
+                ..account
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            service
+                .find_balances(
+                    fixture.usd_account_id,
+                    date(2022, 12, 1),
+                    date(2023, 2, 1),
+                    Interval::Month
+                )
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|point| point.period)
+                .collect::<Vec<_>>(),
+            vec![date(2023, 1, 1), date(2023, 2, 1)]
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_category_totals_split(pool: Pool<Postgres>) {
+        let service = PgAnalyticsService::new(pool.clone());
+        let fixture = insert_fixture(pool.clone()).await;
+        let household = PgCategoryService::new(pool.clone())
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: fixture.user_id,
+                parent_id: None,
+                name: "Household".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+        let movement_id = Uuid::new_v4();
+        PgAccountService::new(pool)
+            .insert_split_movement(
+                Movement {
+                    id: movement_id,
+                    account_id: fixture.usd_account_id,
+                    timestamp: Utc.with_ymd_and_hms(2023, 1, 15, 10, 0, 0).unwrap(),
+                    title: "Supermarket".to_string(),
+                    amount: Decimal::from(-40),
+                    category_id: None,
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                },
+                vec![
+                    MovementSplit {
+                        id: Uuid::new_v4(),
+                        movement_id,
+                        category_id: fixture.category_id,
+                        amount: Decimal::from(-25),
+                        memo: None,
+                    },
+                    MovementSplit {
+                        id: Uuid::new_v4(),
+                        movement_id,
+                        category_id: household.id,
+                        amount: Decimal::from(-15),
+                        memo: Some("Detergent".to_string()),
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service
+                .find_category_totals(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 3, 1)
+                )
+                .await
+                .unwrap(),
+            vec![
+                CategoryTotal {
+                    category_id: None,
+                    currency: Currency::USD,
+                    income: Decimal::from(1000),
+                    expense: Decimal::from(500),
+                },
+                CategoryTotal {
+                    category_id: Some(fixture.category_id),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(105),
+                },
+                CategoryTotal {
+                    category_id: Some(household.id),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(15),
+                },
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_totals_without_trades(pool: Pool<Postgres>) {
+        let service = PgAnalyticsService::new(pool.clone());
+        let fixture = insert_fixture(pool.clone()).await;
+        let trade_service = PgTradeService::new(pool);
+        for (kind, amount) in [(TradeKind::Buy, -301), (TradeKind::Sell, 200)] {
+            let movement = Movement {
+                id: Uuid::new_v4(),
+                account_id: fixture.usd_account_id,
+                timestamp: Utc.with_ymd_and_hms(2023, 1, 15, 10, 0, 0).unwrap(),
+                title: "AAPL".to_string(),
+                amount: Decimal::from(amount),
+                category_id: None,
+                transfer_id: None,
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            };
+            trade_service
+                .insert_trade(
+                    Trade {
+                        id: Uuid::new_v4(),
+                        account_id: fixture.usd_account_id,
+                        movement_id: movement.id,
+                        date: date(2023, 1, 15),
+                        kind,
+                        symbol: "AAPL".to_string(),
+                        quantity: Decimal::from(2),
+                        price: Decimal::from(150),
+                        fees: Decimal::from(0),
+                    },
+                    movement,
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            service
+                .find_period_totals(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 2, 1),
+                    Interval::Month
+                )
+                .await
+                .unwrap(),
+            vec![PeriodTotal {
+                period: date(2023, 1, 1),
+                currency: Currency::USD,
+                income: Decimal::from(1000),
+                expense: Decimal::from(80),
+            }]
+        );
+        assert_eq!(
+            service
+                .find_category_totals(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 3, 1)
+                )
+                .await
+                .unwrap(),
+            vec![
+                CategoryTotal {
+                    category_id: None,
+                    currency: Currency::USD,
+                    income: Decimal::from(1000),
+                    expense: Decimal::from(500),
+                },
+                CategoryTotal {
+                    category_id: Some(fixture.category_id),
+                    currency: Currency::USD,
+                    income: Decimal::from(0),
+                    expense: Decimal::from(80),
+                },
+            ]
+        );
+        assert_eq!(
+            service
+                .find_top_titles(
+                    fixture.user_id,
+                    Some(fixture.usd_account_id),
+                    None,
+                    date(2023, 1, 1),
+                    date(2023, 3, 1),
+                    10
+                )
+                .await
+                .unwrap()
+                .iter()
+                .map(|total| (total.title.as_str(), total.count))
+                .collect::<Vec<_>>(),
+            vec![("Rent", 1), ("Grocery", 2)]
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::application::services::budgets::BudgetService;
+use crate::domain::entities::budgets::{Budget, Spending};
+use crate::domain::error::Result;
+
+pub struct PgBudgetService {
+    db: PgPool,
+}
+
+impl PgBudgetService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl BudgetService for PgBudgetService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Budget> {
+        let data = sqlx::query_as!(
+            Budget,
+            r#"SELECT id, user_id, category_id, month, amount, currency as "currency: _", rollover
+            FROM budgets
+            WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Budget>> {
+        let data = sqlx::query_as!(
+            Budget,
+            r#"SELECT id, user_id, category_id, month, amount, currency as "currency: _", rollover
+            FROM budgets
+            WHERE user_id = $1
+            ORDER BY month DESC, category_id"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn upsert(&self, budget: Budget) -> Result<Budget> {
+        let data = sqlx::query_as!(
+            Budget,
+            r#"INSERT INTO budgets(id, user_id, category_id, month, amount, currency, rollover)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, category_id, month) DO UPDATE
+            SET amount = EXCLUDED.amount, currency = EXCLUDED.currency, rollover = EXCLUDED.rollover
+            RETURNING id, user_id, category_id, month, amount, currency as "currency: _", rollover"#,
+            budget.id,
+            budget.user_id,
+            budget.category_id,
+            budget.month,
+            budget.amount,
+            budget.currency as _,
+            budget.rollover
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn delete(&self, budget: Budget) -> Result<Budget> {
+        let data = sqlx::query_as!(
+            Budget,
+            r#"DELETE FROM budgets
+            WHERE id = $1
+            RETURNING id, user_id, category_id, month, amount, currency as "currency: _", rollover"#,
+            budget.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_spending(
+        &self,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<Spending>> {
+        let data = sqlx::query_as!(
+            Spending,
+            r#"SELECT COALESCE(s.category_id, m.category_id) as "category_id!",
+                date_trunc('month', m.timestamp AT TIME ZONE 'UTC')::date as "month!",
+                a.currency as "currency: _",
+                -SUM(COALESCE(s.amount, m.amount)) as "amount!"
+            FROM movements m
+            JOIN accounts a ON a.id = m.account_id
+            LEFT JOIN movement_splits s ON s.movement_id = m.id
+            WHERE a.user_id = $1
+            AND COALESCE(s.category_id, m.category_id) IS NOT NULL
+            AND m.timestamp >= $2::date::timestamp AT TIME ZONE 'UTC'
+            AND m.timestamp < $3::date::timestamp AT TIME ZONE 'UTC'
+            GROUP BY 1, 2, 3
+            ORDER BY 2, 1, 3"#,
+            user_id,
+            from,
+            to
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use chrono::{TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        application::services::{accounts::AccountService, Repository},
+        domain::entities::{
+            accounts::{Account, AccountKind, AccountTerms, Movement, MovementStatus},
+            categories::Category,
+            currencies::Currency,
+            users::User,
+        },
+        infrastructure::pg::{
+            accounts::PgAccountService, categories::PgCategoryService, users::PgUserService,
+        },
+    };
+
+    async fn insert_user(pool: Pool<Postgres>) -> User {
+        let user_service = PgUserService::new(pool);
+        user_service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn insert_category(pool: Pool<Postgres>, user_id: Uuid) -> Category {
+        let category_service = PgCategoryService::new(pool);
+        category_service
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id,
+                parent_id: None,
+                name: "Groceries".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    fn get_budget(user_id: Uuid, category_id: Uuid) -> Budget {
+        Budget {
+            id: Uuid::new_v4(),
+            user_id,
+            category_id,
+            month: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            amount: Decimal::from(300),
+            currency: Currency::USD,
+            rollover: false,
+        }
+    }
+
+    #[sqlx::test]
+    async fn upsert(pool: Pool<Postgres>) {
+        let service = PgBudgetService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool, user.id).await;
+        let budget = get_budget(user.id, category.id);
+        assert_eq!(service.upsert(budget.clone()).await.unwrap(), budget);
+
+        let revised = service
+            .upsert(Budget {
+                id: Uuid::new_v4(),
+                amount: Decimal::from(400),
+                rollover: true,
+                ..budget.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            revised,
+            Budget {
+                amount: Decimal::from(400),
+                rollover: true,
+                ..budget
+            }
+        );
+        assert_eq!(
+            service.find_many_by_user_id(user.id).await.unwrap(),
+            vec![revised]
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(Conflict)")]
+    async fn upsert_missing_category(pool: Pool<Postgres>) {
+        let service = PgBudgetService::new(pool.clone());
+        let user = insert_user(pool).await;
+        service
+            .upsert(get_budget(user.id, Uuid::new_v4()))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_and_user_id(pool: Pool<Postgres>) {
+        let service = PgBudgetService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool, user.id).await;
+        let budget = service
+            .upsert(get_budget(user.id, category.id))
+            .await
+            .unwrap();
+        assert_eq!(
+            service
+                .find_by_id_and_user_id(budget.id, user.id)
+                .await
+                .unwrap(),
+            budget
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_by_id_and_user_id_not_found(pool: Pool<Postgres>) {
+        let service = PgBudgetService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool, user.id).await;
+        let budget = service
+            .upsert(get_budget(user.id, category.id))
+            .await
+            .unwrap();
+        service
+            .find_by_id_and_user_id(budget.id, Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn delete(pool: Pool<Postgres>) {
+        let service = PgBudgetService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool, user.id).await;
+        let budget = service
+            .upsert(get_budget(user.id, category.id))
+            .await
+            .unwrap();
+        assert_eq!(service.delete(budget.clone()).await.unwrap(), budget);
+        assert_eq!(service.find_many_by_user_id(user.id).await.unwrap(), vec![]);
+    }
+
+    #[sqlx::test]
+    async fn find_spending(pool: Pool<Postgres>) {
+        let service = PgBudgetService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool, user.id).await;
+        let account = account_service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "name".to_string(),
+                currency: Currency::EUR,
+                balance: Decimal::from(0),
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        for (day, amount, category_id) in [
+            (
+                Utc.with_ymd_and_hms(2023, 1, 5, 10, 0, 0),
+                -50,
+                Some(category.id),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 31, 23, 0, 0),
+                -30,
+                Some(category.id),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 20, 10, 0, 0),
+                10,
+                Some(category.id),
+            ),
+            (Utc.with_ymd_and_hms(2023, 1, 20, 10, 0, 0), -99, None),
+            (
+                Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0),
+                -20,
+                Some(category.id),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0),
+                -40,
+                Some(category.id),
+            ),
+        ] {
+            account_service
+                .insert_movement(Movement {
+                    id: Uuid::new_v4(),
+                    account_id: account.id,
+                    timestamp: day.unwrap(),
+                    title: "title".to_string(),
+                    amount: Decimal::from(amount),
+                    category_id,
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            service
+                .find_spending(
+                    user.id,
+                    NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                    NaiveDate::from_ymd_opt(2023, 3, 1).unwrap(),
+                )
+                .await
+                .unwrap(),
+            vec![
+                Spending {
+                    category_id: category.id,
+                    month: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                    currency: Currency::EUR,
+                    amount: Decimal::from(70),
+                },
+                Spending {
+                    category_id: category.id,
+                    month: NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                    currency: Currency::EUR,
+                    amount: Decimal::from(20),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,466 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::application::services::categories::CategoryService;
+use crate::application::services::Repository;
+use crate::domain::entities::categories::{Category, CategoryStats, ClassifierData, TokenCount};
+use crate::domain::error::Result;
+
+pub struct PgCategoryService {
+    db: PgPool,
+}
+
+impl PgCategoryService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CategoryService for PgCategoryService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Category> {
+        let data = sqlx::query_as!(
+            Category,
+            "SELECT id, user_id, parent_id, name, color, icon FROM categories WHERE id = $1 AND user_id = $2",
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Category>> {
+        let data = sqlx::query_as!(
+            Category,
+            "SELECT id, user_id, parent_id, name, color, icon FROM categories WHERE user_id = $1 ORDER BY name ASC",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_classifier_data(&self, user_id: Uuid, title: String) -> Result<ClassifierData> {
+        let tokens = sqlx::query_scalar!(
+            r#"SELECT token AS "token!" FROM title_tokens($1) token ORDER BY token"#,
+            title
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let vocabulary = sqlx::query_scalar!(
+            r#"SELECT count(DISTINCT token) AS "count!" FROM category_token_counts WHERE user_id = $1"#,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        let categories = sqlx::query_as!(
+            CategoryStats,
+            "SELECT category_id, movements, tokens FROM category_movement_counts WHERE user_id = $1",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let token_counts = sqlx::query_as!(
+            TokenCount,
+            "SELECT category_id, token, count FROM category_token_counts WHERE user_id = $1 AND token = ANY($2)",
+            user_id,
+            &tokens
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(ClassifierData {
+            tokens,
+            vocabulary,
+            categories,
+            token_counts,
+        })
+    }
+}
+
+#[async_trait]
+impl Repository<Category> for PgCategoryService {
+    async fn get_all(&self) -> Result<Vec<Category>> {
+        let data = sqlx::query_as!(
+            Category,
+            "SELECT id, user_id, parent_id, name, color, icon FROM categories ORDER BY name ASC"
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Category> {
+        let data = sqlx::query_as!(
+            Category,
+            "SELECT id, user_id, parent_id, name, color, icon FROM categories WHERE id = $1",
+            id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn insert(&self, item: Category) -> Result<Category> {
+        let data = sqlx::query_as!(
+            Category,
+            r#"INSERT INTO categories(id, user_id, parent_id, name, color, icon)
+            VALUES($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, parent_id, name, color, icon"#,
+            item.id,
+            item.user_id,
+            item.parent_id,
+            item.name,
+            item.color,
+            item.icon
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn update(&self, item: Category) -> Result<Category> {
+        let data = sqlx::query_as!(
+            Category,
+            r#"UPDATE categories
+            SET parent_id=$2, name=$3, color=$4, icon=$5
+            WHERE id=$1
+            RETURNING id, user_id, parent_id, name, color, icon"#,
+            item.id,
+            item.parent_id,
+            item.name,
+            item.color,
+            item.icon
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn delete(&self, item: Category) -> Result<Category> {
+        let data = sqlx::query_as!(
+            Category,
+            "DELETE FROM categories WHERE id=$1 RETURNING id, user_id, parent_id, name, color, icon",
+            item.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        application::services::accounts::AccountService,
+        domain::entities::{
+            accounts::{Account, AccountKind, AccountTerms, Movement, MovementStatus},
+            currencies::Currency,
+            users::User,
+        },
+        infrastructure::pg::{accounts::PgAccountService, users::PgUserService},
+    };
+
+    async fn insert_user(pool: Pool<Postgres>) -> User {
+        let user_service = PgUserService::new(pool);
+        user_service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn insert(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let parent = Category {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            parent_id: None,
+            name: "Food".to_string(),
+            color: Some("#00ff00".to_string()),
+            icon: Some("utensils".to_string()),
+        };
+        let child = Category {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            parent_id: Some(parent.id),
+            name: "Groceries".to_string(),
+            color: None,
+            icon: None,
+        };
+        assert_eq!(service.insert(parent.clone()).await.unwrap(), parent);
+        assert_eq!(service.insert(child.clone()).await.unwrap(), child);
+        assert_eq!(
+            service
+                .find_by_id_and_user_id(child.id, user.id)
+                .await
+                .unwrap(),
+            child
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(Conflict)")]
+    async fn insert_missing_parent(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool.clone());
+        let user = insert_user(pool).await;
+        service
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                parent_id: Some(Uuid::new_v4()),
+                name: "".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_many_by_user_id(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let names = service
+            .find_many_by_user_id(user.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|category| category.name)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["Bills", "Generic", "Income", "Insurance", "Shopping"]
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_by_id_and_user_id_not_found(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool);
+        service
+            .find_by_id_and_user_id(Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn update(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let category = service
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                parent_id: None,
+                name: "".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+        let updated = Category {
+            name: "name".to_string(),
+            color: Some("#ff0000".to_string()),
+            ..category
+        };
+        assert_eq!(service.update(updated.clone()).await.unwrap(), updated);
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn update_not_found(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool);
+        service
+            .update(Category {
+                id: Uuid::new_v4(),
+                user_id: Uuid::new_v4(),
+                parent_id: None,
+                name: "".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn delete(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let category = service
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                parent_id: None,
+                name: "".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(service.delete(category.clone()).await.unwrap(), category);
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(Conflict)")]
+    async fn delete_conflict(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let parent = service
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                parent_id: None,
+                name: "".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+        service
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                parent_id: Some(parent.id),
+                name: "".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+        service.delete(parent).await.unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_classifier_data(pool: Pool<Postgres>) {
+        let service = PgCategoryService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let categories = service.find_many_by_user_id(user.id).await.unwrap();
+        let (bills, shopping) = (categories[0].id, categories[4].id);
+        let account = account_service
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let mut movements = vec![];
+        for (title, category_id) in [
+            ("Supermarket Rome 1234", Some(shopping)),
+            ("SUPERMARKET Milan", Some(shopping)),
+            ("Electricity Rome", Some(bills)),
+            ("Supermarket", None),
+        ] {
+            let movement = account_service
+                .insert_movement(Movement {
+                    id: Uuid::new_v4(),
+                    account_id: account.id,
+                    amount: Decimal::from(-10),
+                    category_id,
+                    timestamp: Utc::now(),
+                    title: title.to_string(),
+                    transfer_id: None,
+                    external_id: None,
+                    status: MovementStatus::Pending,
+                    note: None,
+                    tags: vec![],
+                    fingerprint: None,
+                })
+                .await
+                .unwrap();
+            movements.push(movement);
+        }
+
+        let data = service
+            .find_classifier_data(user.id, "Supermarket in Rome, 42".to_string())
+            .await
+            .unwrap();
+        assert_eq!(data.tokens, vec!["in", "rome", "supermarket"]);
+        assert_eq!(data.vocabulary, 4);
+        let mut stats = data
+            .categories
+            .iter()
+            .map(|stats| (stats.category_id, stats.movements, stats.tokens))
+            .collect::<Vec<_>>();
+        stats.sort();
+        let mut expected = vec![(shopping, 2, 4), (bills, 1, 2)];
+        expected.sort();
+        assert_eq!(stats, expected);
+        let mut counts = data
+            .token_counts
+            .iter()
+            .map(|count| (count.category_id, count.token.as_str(), count.count))
+            .collect::<Vec<_>>();
+        counts.sort();
+        let mut expected = vec![
+            (shopping, "rome", 1),
+            (shopping, "supermarket", 2),
+            (bills, "rome", 1),
+        ];
+        expected.sort();
+        assert_eq!(counts, expected);
+        assert_eq!(data.suggest().unwrap().category_id, shopping);
+
+        account_service
+            .update_movement(Movement {
+                category_id: Some(bills),
+                ..movements[0].clone()
+            })
+            .await
+            .unwrap();
+        account_service
+            .delete_movement(movements[1].clone())
+            .await
+            .unwrap();
+        let data = service
+            .find_classifier_data(user.id, "Supermarket".to_string())
+            .await
+            .unwrap();
+        assert_eq!(data.vocabulary, 3);
+        assert_eq!(
+            data.categories,
+            vec![CategoryStats {
+                category_id: bills,
+                movements: 2,
+                tokens: 4,
+            }]
+        );
+        assert_eq!(
+            data.token_counts,
+            vec![TokenCount {
+                category_id: bills,
+                token: "supermarket".to_string(),
+                count: 1,
+            }]
+        );
+        assert_eq!(data.suggest().unwrap().category_id, bills);
+
+        let data = service
+            .find_classifier_data(user.id, "Salary".to_string())
+            .await
+            .unwrap();
+        assert_eq!(data.suggest(), None);
+    }
+}
@@ -5,6 +5,7 @@ impl From<sqlx::Error> for Error {
         match err {
             sqlx::Error::Database(e) => match e.code().unwrap_or_default().as_ref() {
                 "23505" => Error::Repository(RepositoryErrorType::Conflict),
+                "23503" => Error::Repository(RepositoryErrorType::Conflict),
                 _ => Error::External(e.into()),
             },
             sqlx::Error::RowNotFound => Error::Repository(RepositoryErrorType::NotFound),
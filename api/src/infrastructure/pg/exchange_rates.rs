@@ -0,0 +1,437 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::application::services::exchange_rates::{ExchangeRateProvider, ExchangeRateService};
+use crate::domain::entities::{
+    currencies::Currency,
+    exchange_rates::{ExchangeRate, Quote},
+};
+use crate::domain::error::{Error, RepositoryErrorType, Result};
+
+pub struct PgExchangeRateService {
+    db: PgPool,
+}
+
+impl PgExchangeRateService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ExchangeRateService for PgExchangeRateService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<ExchangeRate> {
+        let data = sqlx::query_as!(
+            ExchangeRate,
+            r#"SELECT id, user_id, date, from_currency as "from: _", to_currency as "to: _", rate
+            FROM exchange_rates
+            WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<ExchangeRate>> {
+        let data = sqlx::query_as!(
+            ExchangeRate,
+            r#"SELECT id, user_id, date, from_currency as "from: _", to_currency as "to: _", rate
+            FROM exchange_rates
+            WHERE user_id = $1
+            ORDER BY date DESC, from_currency, to_currency"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    // Latest rate on or before date, a stored rate in the opposite direction is inverted
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        from: Currency,
+        to: Currency,
+        date: NaiveDate,
+    ) -> Result<ExchangeRate> {
+        let data = sqlx::query_as!(
+            ExchangeRate,
+            r#"SELECT id, user_id, date, from_currency as "from: _", to_currency as "to: _", rate
+            FROM exchange_rates
+            WHERE user_id = $1
+            AND ((from_currency = $2 AND to_currency = $3) OR (from_currency = $3 AND to_currency = $2))
+            AND date <= $4
+            ORDER BY date DESC, from_currency = $2 DESC
+            LIMIT 1"#,
+            user_id,
+            from as _,
+            to as _,
+            date
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        match data.from == from {
+            true => Ok(data),
+            false => Ok(ExchangeRate {
+                from,
+                to,
+                rate: (Decimal::ONE / data.rate).round_dp(10),
+                ..data
+            }),
+        }
+    }
+
+    async fn upsert_many(&self, rates: Vec<ExchangeRate>) -> Result<Vec<ExchangeRate>> {
+        let mut tx = self.db.begin().await?;
+        let mut data = vec![];
+        for rate in rates {
+            data.push(
+                sqlx::query_as!(
+                    ExchangeRate,
+                    r#"INSERT INTO exchange_rates(id, user_id, date, from_currency, to_currency, rate)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    ON CONFLICT (user_id, date, from_currency, to_currency) DO UPDATE SET rate = EXCLUDED.rate
+                    RETURNING id, user_id, date, from_currency as "from: _", to_currency as "to: _", rate"#,
+                    rate.id,
+                    rate.user_id,
+                    rate.date,
+                    rate.from as _,
+                    rate.to as _,
+                    rate.rate
+                )
+                .fetch_one(&mut tx)
+                .await?,
+            );
+        }
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn delete(&self, rate: ExchangeRate) -> Result<ExchangeRate> {
+        let data = sqlx::query_as!(
+            ExchangeRate,
+            r#"DELETE FROM exchange_rates
+            WHERE id = $1
+            RETURNING id, user_id, date, from_currency as "from: _", to_currency as "to: _", rate"#,
+            rate.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+pub struct PgExchangeRateProvider {
+    rates: PgExchangeRateService,
+    fallback: Box<dyn ExchangeRateProvider>,
+}
+
+impl PgExchangeRateProvider {
+    pub fn new(db: PgPool, fallback: Box<dyn ExchangeRateProvider>) -> Self {
+        Self {
+            rates: PgExchangeRateService::new(db),
+            fallback,
+        }
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for PgExchangeRateProvider {
+    async fn get_quote(&self, user_id: Uuid, from: Currency, to: Currency) -> Result<Quote> {
+        if from == to {
+            return Err(Error::Validation(anyhow!(
+                "exchange rate currencies must differ"
+            )));
+        }
+
+        match self
+            .rates
+            .find_latest(user_id, from, to, Utc::now().date_naive())
+            .await
+        {
+            Ok(rate) => Ok(Quote {
+                date: rate.date,
+                from: rate.from,
+                to: rate.to,
+                rate: rate.rate,
+            }),
+            Err(Error::Repository(RepositoryErrorType::NotFound)) => {
+                self.fallback.get_quote(user_id, from, to).await
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use rust_decimal::Decimal;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        application::services::Repository,
+        domain::entities::users::User,
+        infrastructure::{file::FileExchangeRateProvider, pg::users::PgUserService},
+    };
+
+    async fn insert_user(pool: Pool<Postgres>) -> User {
+        let user_service = PgUserService::new(pool);
+        user_service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn upsert_many(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let rate = ExchangeRate {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            from: Currency::USD,
+            to: Currency::EUR,
+            rate: Decimal::new(92, 2),
+        };
+        let inserted = service.upsert_many(vec![rate.clone()]).await.unwrap();
+        assert_eq!(inserted, vec![rate.clone()]);
+
+        let revised = service
+            .upsert_many(vec![ExchangeRate {
+                id: Uuid::new_v4(),
+                rate: Decimal::new(93, 2),
+                ..rate.clone()
+            }])
+            .await
+            .unwrap();
+        assert_eq!(
+            revised,
+            vec![ExchangeRate {
+                rate: Decimal::new(93, 2),
+                ..rate
+            }]
+        );
+        assert_eq!(
+            service.find_many_by_user_id(user.id).await.unwrap(),
+            revised
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_and_user_id(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let rate = service
+            .upsert_many(vec![ExchangeRate {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                from: Currency::USD,
+                to: Currency::EUR,
+                rate: Decimal::new(92, 2),
+            }])
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(
+            service
+                .find_by_id_and_user_id(rate.id, user.id)
+                .await
+                .unwrap(),
+            rate
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_by_id_and_user_id_not_found(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool);
+        service
+            .find_by_id_and_user_id(Uuid::new_v4(), Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_latest(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let rates = service
+            .upsert_many(vec![
+                ExchangeRate {
+                    id: Uuid::new_v4(),
+                    user_id: user.id,
+                    date: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                    from: Currency::USD,
+                    to: Currency::EUR,
+                    rate: Decimal::new(90, 2),
+                },
+                ExchangeRate {
+                    id: Uuid::new_v4(),
+                    user_id: user.id,
+                    date: NaiveDate::from_ymd_opt(2023, 1, 15).unwrap(),
+                    from: Currency::EUR,
+                    to: Currency::USD,
+                    rate: Decimal::new(125, 2),
+                },
+                ExchangeRate {
+                    id: Uuid::new_v4(),
+                    user_id: user.id,
+                    date: NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+                    from: Currency::USD,
+                    to: Currency::EUR,
+                    rate: Decimal::new(92, 2),
+                },
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service
+                .find_latest(
+                    user.id,
+                    Currency::USD,
+                    Currency::EUR,
+                    NaiveDate::from_ymd_opt(2023, 1, 10).unwrap()
+                )
+                .await
+                .unwrap(),
+            rates[0]
+        );
+        assert_eq!(
+            service
+                .find_latest(
+                    user.id,
+                    Currency::USD,
+                    Currency::EUR,
+                    NaiveDate::from_ymd_opt(2023, 1, 20).unwrap()
+                )
+                .await
+                .unwrap(),
+            ExchangeRate {
+                from: Currency::USD,
+                to: Currency::EUR,
+                rate: Decimal::new(8, 1),
+                ..rates[1].clone()
+            }
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_latest_not_found(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool.clone());
+        let user = insert_user(pool).await;
+        service
+            .find_latest(
+                user.id,
+                Currency::USD,
+                Currency::EUR,
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn delete(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let rate = service
+            .upsert_many(vec![ExchangeRate {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                from: Currency::USD,
+                to: Currency::EUR,
+                rate: Decimal::new(92, 2),
+            }])
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(service.delete(rate.clone()).await.unwrap(), rate);
+        assert_eq!(service.find_many_by_user_id(user.id).await.unwrap(), vec![]);
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn delete_not_found(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool);
+        service
+            .delete(ExchangeRate {
+                id: Uuid::new_v4(),
+                user_id: Uuid::new_v4(),
+                date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+                from: Currency::USD,
+                to: Currency::EUR,
+                rate: Decimal::new(92, 2),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn get_quote_stored_by_user(pool: Pool<Postgres>) {
+        let service = PgExchangeRateService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        service
+            .upsert_many(vec![ExchangeRate {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                date,
+                from: Currency::USD,
+                to: Currency::JPY,
+                rate: Decimal::from(130),
+            }])
+            .await
+            .unwrap();
+        let provider = PgExchangeRateProvider::new(
+            pool,
+            Box::new(FileExchangeRateProvider::new(vec![Quote {
+                date,
+                from: Currency::USD,
+                to: Currency::EUR,
+                rate: Decimal::new(92, 2),
+            }])),
+        );
+
+        assert_eq!(
+            provider
+                .get_quote(user.id, Currency::USD, Currency::JPY)
+                .await
+                .unwrap(),
+            Quote {
+                date,
+                from: Currency::USD,
+                to: Currency::JPY,
+                rate: Decimal::from(130),
+            }
+        );
+        assert_eq!(
+            provider
+                .get_quote(user.id, Currency::USD, Currency::EUR)
+                .await
+                .unwrap()
+                .rate,
+            Decimal::new(92, 2)
+        );
+        assert!(provider
+            .get_quote(Uuid::new_v4(), Currency::USD, Currency::JPY)
+            .await
+            .is_err());
+    }
+}
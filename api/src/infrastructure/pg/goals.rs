@@ -0,0 +1,369 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::application::services::goals::GoalService;
+use crate::application::services::Repository;
+use crate::domain::entities::goals::Goal;
+use crate::domain::error::Result;
+
+pub struct PgGoalService {
+    db: PgPool,
+}
+
+impl PgGoalService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+async fn set_accounts_tx(tx: &mut Transaction<'_, Postgres>, goal: &Goal) -> Result<Goal> {
+    sqlx::query!("DELETE FROM goal_accounts WHERE goal_id = $1", goal.id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query!(
+        r#"INSERT INTO goal_accounts(goal_id, account_id)
+        SELECT $1, account_id FROM unnest($2::uuid[]) account_id"#,
+        goal.id,
+        &goal.account_ids
+    )
+    .execute(&mut *tx)
+    .await?;
+    let data = sqlx::query_as!(
+        Goal,
+        r#"SELECT id, user_id, name, target_amount, currency as "currency: _", start_date, deadline,
+            ARRAY(SELECT account_id FROM goal_accounts WHERE goal_id = goals.id ORDER BY account_id) as "account_ids!"
+        FROM goals
+        WHERE id = $1"#,
+        goal.id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    Ok(data)
+}
+
+#[async_trait]
+impl GoalService for PgGoalService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Goal> {
+        let data = sqlx::query_as!(
+            Goal,
+            r#"SELECT id, user_id, name, target_amount, currency as "currency: _", start_date, deadline,
+                ARRAY(SELECT account_id FROM goal_accounts WHERE goal_id = goals.id ORDER BY account_id) as "account_ids!"
+            FROM goals
+            WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Goal>> {
+        let data = sqlx::query_as!(
+            Goal,
+            r#"SELECT id, user_id, name, target_amount, currency as "currency: _", start_date, deadline,
+                ARRAY(SELECT account_id FROM goal_accounts WHERE goal_id = goals.id ORDER BY account_id) as "account_ids!"
+            FROM goals
+            WHERE user_id = $1
+            ORDER BY deadline ASC, name ASC"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl Repository<Goal> for PgGoalService {
+    async fn get_all(&self) -> Result<Vec<Goal>> {
+        let data = sqlx::query_as!(
+            Goal,
+            r#"SELECT id, user_id, name, target_amount, currency as "currency: _", start_date, deadline,
+                ARRAY(SELECT account_id FROM goal_accounts WHERE goal_id = goals.id ORDER BY account_id) as "account_ids!"
+            FROM goals
+            ORDER BY deadline ASC, name ASC"#
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Goal> {
+        let data = sqlx::query_as!(
+            Goal,
+            r#"SELECT id, user_id, name, target_amount, currency as "currency: _", start_date, deadline,
+                ARRAY(SELECT account_id FROM goal_accounts WHERE goal_id = goals.id ORDER BY account_id) as "account_ids!"
+            FROM goals
+            WHERE id = $1"#,
+            id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn insert(&self, item: Goal) -> Result<Goal> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query!(
+            r#"INSERT INTO goals(id, user_id, name, target_amount, currency, start_date, deadline)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            item.id,
+            item.user_id,
+            item.name,
+            item.target_amount,
+            item.currency as _,
+            item.start_date,
+            item.deadline
+        )
+        .execute(&mut tx)
+        .await?;
+        let data = set_accounts_tx(&mut tx, &item).await?;
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn update(&self, item: Goal) -> Result<Goal> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query!(
+            r#"UPDATE goals
+            SET name=$2, target_amount=$3, currency=$4, deadline=$5
+            WHERE id=$1
+            RETURNING id"#,
+            item.id,
+            item.name,
+            item.target_amount,
+            item.currency as _,
+            item.deadline
+        )
+        .fetch_one(&mut tx)
+        .await?;
+        let data = set_accounts_tx(&mut tx, &item).await?;
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn delete(&self, item: Goal) -> Result<Goal> {
+        let data = sqlx::query_as!(
+            Goal,
+            r#"WITH deleted AS (
+                DELETE FROM goals
+                WHERE id=$1
+                RETURNING id, user_id, name, target_amount, currency, start_date, deadline
+            )
+            SELECT id as "id!", user_id as "user_id!", name as "name!", target_amount as "target_amount!",
+                currency as "currency!: _", start_date as "start_date!", deadline as "deadline!",
+                ARRAY(SELECT account_id FROM goal_accounts WHERE goal_id = deleted.id ORDER BY account_id) as "account_ids!"
+            FROM deleted"#,
+            item.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        domain::entities::{
+            accounts::{Account, AccountKind, AccountTerms},
+            currencies::Currency,
+            users::User,
+        },
+        infrastructure::pg::{accounts::PgAccountService, users::PgUserService},
+    };
+
+    async fn insert_user(pool: Pool<Postgres>) -> User {
+        let user_service = PgUserService::new(pool);
+        user_service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn insert_account(pool: Pool<Postgres>, user_id: Uuid) -> Account {
+        PgAccountService::new(pool)
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id,
+                name: "".to_string(),
+                balance: Decimal::from(0),
+                currency: Currency::EUR,
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap()
+    }
+
+    fn get_goal(user_id: Uuid, name: &str, deadline: NaiveDate, account_ids: Vec<Uuid>) -> Goal {
+        let mut account_ids = account_ids;
+        account_ids.sort();
+        Goal {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.to_string(),
+            target_amount: Decimal::from(5000),
+            currency: Currency::EUR,
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            deadline,
+            account_ids,
+        }
+    }
+
+    #[sqlx::test]
+    async fn insert(pool: Pool<Postgres>) {
+        let service = PgGoalService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let account = insert_account(pool.clone(), user.id).await;
+        let account2 = insert_account(pool, user.id).await;
+        let goal = get_goal(
+            user.id,
+            "Emergency fund",
+            NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+            vec![account.id, account2.id],
+        );
+        let other = get_goal(
+            user.id,
+            "Holiday",
+            NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
+            vec![],
+        );
+        assert_eq!(service.insert(goal.clone()).await.unwrap(), goal);
+        assert_eq!(service.insert(other.clone()).await.unwrap(), other);
+        assert_eq!(
+            service
+                .find_by_id_and_user_id(goal.id, user.id)
+                .await
+                .unwrap(),
+            goal
+        );
+        assert_eq!(
+            service.find_many_by_user_id(user.id).await.unwrap(),
+            vec![other, goal]
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(Conflict)")]
+    async fn insert_missing_account(pool: Pool<Postgres>) {
+        let service = PgGoalService::new(pool.clone());
+        let user = insert_user(pool).await;
+        service
+            .insert(get_goal(
+                user.id,
+                "Emergency fund",
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                vec![Uuid::new_v4()],
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_by_id_and_user_id_not_found(pool: Pool<Postgres>) {
+        let service = PgGoalService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let goal = service
+            .insert(get_goal(
+                user.id,
+                "Emergency fund",
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                vec![],
+            ))
+            .await
+            .unwrap();
+        service
+            .find_by_id_and_user_id(goal.id, Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn update(pool: Pool<Postgres>) {
+        let service = PgGoalService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let account = insert_account(pool.clone(), user.id).await;
+        let account2 = insert_account(pool, user.id).await;
+        let goal = service
+            .insert(get_goal(
+                user.id,
+                "Emergency fund",
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                vec![account.id],
+            ))
+            .await
+            .unwrap();
+        let updated = Goal {
+            name: "Car".to_string(),
+            target_amount: Decimal::from(12000),
+            currency: Currency::USD,
+            deadline: NaiveDate::from_ymd_opt(2027, 6, 30).unwrap(),
+            account_ids: vec![account2.id],
+            ..goal
+        };
+        assert_eq!(service.update(updated.clone()).await.unwrap(), updated);
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn update_not_found(pool: Pool<Postgres>) {
+        let service = PgGoalService::new(pool.clone());
+        let user = insert_user(pool).await;
+        service
+            .update(get_goal(
+                user.id,
+                "Emergency fund",
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                vec![],
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn delete(pool: Pool<Postgres>) {
+        let service = PgGoalService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let account = insert_account(pool.clone(), user.id).await;
+        let account2 = insert_account(pool.clone(), user.id).await;
+        let goal = service
+            .insert(get_goal(
+                user.id,
+                "Emergency fund",
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                vec![account.id, account2.id],
+            ))
+            .await
+            .unwrap();
+
+        PgAccountService::new(pool)
+            .delete(account.clone())
+            .await
+            .unwrap();
+        let goal = Goal {
+            account_ids: vec![account2.id],
+            ..goal
+        };
+        assert_eq!(service.find_by_id(goal.id).await.unwrap(), goal);
+
+        assert_eq!(service.delete(goal.clone()).await.unwrap(), goal);
+        assert_eq!(service.find_many_by_user_id(user.id).await.unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,268 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::application::services::imports::ImportMappingService;
+use crate::application::services::Repository;
+use crate::domain::entities::imports::{CsvFormat, ImportMapping};
+use crate::domain::error::Result;
+
+pub struct PgImportMappingService {
+    db: PgPool,
+}
+
+impl PgImportMappingService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+struct ImportMappingRow {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    delimiter: String,
+    date_column: String,
+    description_column: String,
+    amount_column: Option<String>,
+    debit_column: Option<String>,
+    credit_column: Option<String>,
+    date_format: String,
+    decimal_separator: String,
+}
+
+impl From<ImportMappingRow> for ImportMapping {
+    fn from(row: ImportMappingRow) -> Self {
+        ImportMapping {
+            id: row.id,
+            user_id: row.user_id,
+            name: row.name,
+            format: CsvFormat {
+                delimiter: row.delimiter.chars().next().unwrap_or(','),
+                date_column: row.date_column,
+                description_column: row.description_column,
+                amount_column: row.amount_column,
+                debit_column: row.debit_column,
+                credit_column: row.credit_column,
+                date_format: row.date_format,
+                decimal_separator: row.decimal_separator.chars().next().unwrap_or('.'),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl ImportMappingService for PgImportMappingService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<ImportMapping> {
+        let data = sqlx::query_as!(
+            ImportMappingRow,
+            r#"SELECT id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator
+            FROM import_mappings
+            WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<ImportMapping>> {
+        let data = sqlx::query_as!(
+            ImportMappingRow,
+            r#"SELECT id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator
+            FROM import_mappings
+            WHERE user_id = $1
+            ORDER BY name ASC"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data.into_iter().map(ImportMapping::from).collect())
+    }
+}
+
+#[async_trait]
+impl Repository<ImportMapping> for PgImportMappingService {
+    async fn get_all(&self) -> Result<Vec<ImportMapping>> {
+        let data = sqlx::query_as!(
+            ImportMappingRow,
+            r#"SELECT id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator
+            FROM import_mappings
+            ORDER BY name ASC"#
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data.into_iter().map(ImportMapping::from).collect())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<ImportMapping> {
+        let data = sqlx::query_as!(
+            ImportMappingRow,
+            r#"SELECT id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator
+            FROM import_mappings
+            WHERE id = $1"#,
+            id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn insert(&self, item: ImportMapping) -> Result<ImportMapping> {
+        let data = sqlx::query_as!(
+            ImportMappingRow,
+            r#"INSERT INTO import_mappings(id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator"#,
+            item.id,
+            item.user_id,
+            item.name,
+            item.format.delimiter.to_string(),
+            item.format.date_column,
+            item.format.description_column,
+            item.format.amount_column,
+            item.format.debit_column,
+            item.format.credit_column,
+            item.format.date_format,
+            item.format.decimal_separator.to_string()
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn update(&self, item: ImportMapping) -> Result<ImportMapping> {
+        let data = sqlx::query_as!(
+            ImportMappingRow,
+            r#"UPDATE import_mappings
+            SET name=$2, delimiter=$3, date_column=$4, description_column=$5, amount_column=$6, debit_column=$7, credit_column=$8, date_format=$9, decimal_separator=$10
+            WHERE id=$1
+            RETURNING id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator"#,
+            item.id,
+            item.name,
+            item.format.delimiter.to_string(),
+            item.format.date_column,
+            item.format.description_column,
+            item.format.amount_column,
+            item.format.debit_column,
+            item.format.credit_column,
+            item.format.date_format,
+            item.format.decimal_separator.to_string()
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn delete(&self, item: ImportMapping) -> Result<ImportMapping> {
+        let data = sqlx::query_as!(
+            ImportMappingRow,
+            r#"DELETE FROM import_mappings
+            WHERE id=$1
+            RETURNING id, user_id, name, delimiter, date_column, description_column, amount_column, debit_column, credit_column, date_format, decimal_separator"#,
+            item.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        domain::entities::{currencies::Currency, users::User},
+        infrastructure::pg::users::PgUserService,
+    };
+
+    async fn insert_user(pool: Pool<Postgres>) -> User {
+        let user_service = PgUserService::new(pool);
+        user_service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    fn get_mapping(user_id: Uuid) -> ImportMapping {
+        ImportMapping {
+            id: Uuid::new_v4(),
+            user_id,
+            name: "bank".to_string(),
+            format: CsvFormat {
+                delimiter: ';',
+                date_column: "Date".to_string(),
+                description_column: "Description".to_string(),
+                amount_column: None,
+                debit_column: Some("Debit".to_string()),
+                credit_column: Some("Credit".to_string()),
+                date_format: "%d/%m/%Y".to_string(),
+                decimal_separator: ',',
+            },
+        }
+    }
+
+    #[sqlx::test]
+    async fn insert(pool: Pool<Postgres>) {
+        let service = PgImportMappingService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let mapping = get_mapping(user.id);
+        assert_eq!(service.insert(mapping.clone()).await.unwrap(), mapping);
+        assert_eq!(
+            service
+                .find_by_id_and_user_id(mapping.id, user.id)
+                .await
+                .unwrap(),
+            mapping
+        );
+        assert_eq!(
+            service.find_many_by_user_id(user.id).await.unwrap(),
+            vec![mapping]
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_by_id_and_user_id_not_found(pool: Pool<Postgres>) {
+        let service = PgImportMappingService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let mapping = service.insert(get_mapping(user.id)).await.unwrap();
+        service
+            .find_by_id_and_user_id(mapping.id, Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn update(pool: Pool<Postgres>) {
+        let service = PgImportMappingService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let mapping = service.insert(get_mapping(user.id)).await.unwrap();
+        let updated = ImportMapping {
+            name: "other bank".to_string(),
+            format: CsvFormat {
+                delimiter: ',',
+                decimal_separator: '.',
+                ..mapping.format.clone()
+            },
+            ..mapping
+        };
+        assert_eq!(service.update(updated.clone()).await.unwrap(), updated);
+    }
+
+    #[sqlx::test]
+    async fn delete(pool: Pool<Postgres>) {
+        let service = PgImportMappingService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let mapping = service.insert(get_mapping(user.id)).await.unwrap();
+        assert_eq!(service.delete(mapping.clone()).await.unwrap(), mapping);
+        assert_eq!(service.find_many_by_user_id(user.id).await.unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,382 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use super::accounts::{insert_movement_tx, update_balance_tx};
+use crate::application::services::investments::{PriceService, TradeService};
+use crate::domain::entities::{
+    accounts::Movement,
+    currencies::Currency,
+    investments::{Price, Trade},
+};
+use crate::domain::error::Result;
+
+pub struct PgTradeService {
+    db: PgPool,
+}
+
+impl PgTradeService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TradeService for PgTradeService {
+    async fn find_trade(&self, id: Uuid, account_id: Uuid) -> Result<Trade> {
+        let data = sqlx::query_as!(
+            Trade,
+            r#"SELECT id, account_id, movement_id, date, kind as "kind: _", symbol, quantity, price, fees
+            FROM trades
+            WHERE id = $1 AND account_id = $2"#,
+            id,
+            account_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_trades(&self, account_id: Uuid) -> Result<Vec<Trade>> {
+        let data = sqlx::query_as!(
+            Trade,
+            r#"SELECT t.id, t.account_id, t.movement_id, t.date, t.kind as "kind: _", t.symbol, t.quantity, t.price, t.fees
+            FROM trades t
+            JOIN movements m ON m.id = t.movement_id
+            WHERE t.account_id = $1
+            ORDER BY t.date, m.timestamp, t.id"#,
+            account_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn is_trade_movement(&self, movement_id: Uuid) -> Result<bool> {
+        let data = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM trades WHERE movement_id = $1) as "exists!""#,
+            movement_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn insert_trade(&self, trade: Trade, movement: Movement) -> Result<Trade> {
+        let mut tx = self.db.begin().await?;
+        let movement = insert_movement_tx(&mut tx, movement).await?;
+        let data = sqlx::query_as!(
+            Trade,
+            r#"INSERT INTO trades(id, account_id, movement_id, date, kind, symbol, quantity, price, fees)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, account_id, movement_id, date, kind as "kind: _", symbol, quantity, price, fees"#,
+            trade.id,
+            trade.account_id,
+            movement.id,
+            trade.date,
+            trade.kind as _,
+            trade.symbol,
+            trade.quantity,
+            trade.price,
+            trade.fees
+        )
+        .fetch_one(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn delete_trade(&self, trade: Trade) -> Result<Trade> {
+        let mut tx = self.db.begin().await?;
+        let data = sqlx::query_as!(
+            Trade,
+            r#"DELETE FROM trades
+            WHERE id = $1
+            RETURNING id, account_id, movement_id, date, kind as "kind: _", symbol, quantity, price, fees"#,
+            trade.id
+        )
+        .fetch_one(&mut tx)
+        .await?;
+        let amount = sqlx::query_scalar!(
+            "DELETE FROM movements WHERE id = $1 AND status <> 'RECONCILED' RETURNING amount",
+            data.movement_id
+        )
+        .fetch_one(&mut tx)
+        .await?;
+        update_balance_tx(&mut tx, data.account_id, -amount).await?;
+        tx.commit().await?;
+        Ok(data)
+    }
+}
+
+pub struct PgPriceService {
+    db: PgPool,
+}
+
+impl PgPriceService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PriceService for PgPriceService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Price> {
+        let data = sqlx::query_as!(
+            Price,
+            r#"SELECT id, user_id, symbol, currency as "currency: _", date, price
+            FROM prices
+            WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Price>> {
+        let data = sqlx::query_as!(
+            Price,
+            r#"SELECT id, user_id, symbol, currency as "currency: _", date, price
+            FROM prices
+            WHERE user_id = $1
+            ORDER BY date DESC, symbol, currency"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        currency: Currency,
+        date: NaiveDate,
+    ) -> Result<Vec<Price>> {
+        let data = sqlx::query_as!(
+            Price,
+            r#"SELECT DISTINCT ON (symbol) id, user_id, symbol, currency as "currency: _", date, price
+            FROM prices
+            WHERE user_id = $1 AND currency = $2 AND date <= $3
+            ORDER BY symbol, date DESC"#,
+            user_id,
+            currency as _,
+            date
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn upsert_many(&self, prices: Vec<Price>) -> Result<Vec<Price>> {
+        let mut tx = self.db.begin().await?;
+        let mut data = vec![];
+        for price in prices {
+            data.push(
+                sqlx::query_as!(
+                    Price,
+                    r#"INSERT INTO prices(id, user_id, symbol, currency, date, price)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    ON CONFLICT (user_id, symbol, currency, date) DO UPDATE SET price = EXCLUDED.price
+                    RETURNING id, user_id, symbol, currency as "currency: _", date, price"#,
+                    price.id,
+                    price.user_id,
+                    price.symbol,
+                    price.currency as _,
+                    price.date,
+                    price.price
+                )
+                .fetch_one(&mut tx)
+                .await?,
+            );
+        }
+        tx.commit().await?;
+        Ok(data)
+    }
+
+    async fn delete(&self, price: Price) -> Result<Price> {
+        let data = sqlx::query_as!(
+            Price,
+            r#"DELETE FROM prices
+            WHERE id = $1
+            RETURNING id, user_id, symbol, currency as "currency: _", date, price"#,
+            price.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        application::services::{accounts::AccountService, Repository},
+        domain::entities::{
+            accounts::{Account, AccountKind, AccountTerms, MovementStatus},
+            investments::TradeKind,
+            users::User,
+        },
+        infrastructure::pg::{accounts::PgAccountService, users::PgUserService},
+    };
+
+    async fn insert_user(pool: Pool<Postgres>) -> User {
+        let user_service = PgUserService::new(pool);
+        user_service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn insert_account(pool: Pool<Postgres>, user_id: Uuid) -> Account {
+        PgAccountService::new(pool)
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id,
+                name: "".to_string(),
+                balance: Decimal::from(1000),
+                currency: Currency::USD,
+                archived: false,
+                opening_balance: Decimal::from(1000),
+                opening_date: None,
+                kind: AccountKind::Investment,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap()
+    }
+
+    fn get_trade(account_id: Uuid) -> (Trade, Movement) {
+        let movement = Movement {
+            id: Uuid::new_v4(),
+            account_id,
+            timestamp: Utc::now(),
+            title: "Buy 2 AAPL".to_string(),
+            category_id: None,
+            amount: Decimal::from(-301),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let trade = Trade {
+            id: Uuid::new_v4(),
+            account_id,
+            movement_id: movement.id,
+            date: NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+            kind: TradeKind::Buy,
+            symbol: "AAPL".to_string(),
+            quantity: Decimal::from(2),
+            price: Decimal::from(150),
+            fees: Decimal::from(1),
+        };
+        (trade, movement)
+    }
+
+    #[sqlx::test]
+    async fn insert_trade(pool: Pool<Postgres>) {
+        let service = PgTradeService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let account = insert_account(pool, user.id).await;
+        let (trade, movement) = get_trade(account.id);
+
+        let inserted = service
+            .insert_trade(trade.clone(), movement.clone())
+            .await
+            .unwrap();
+        assert_eq!(inserted, trade);
+        assert_eq!(service.find_trades(account.id).await.unwrap(), vec![trade]);
+        assert!(service.is_trade_movement(movement.id).await.unwrap());
+        assert_eq!(
+            account_service
+                .find_by_id(account.id)
+                .await
+                .unwrap()
+                .balance,
+            Decimal::from(699)
+        );
+    }
+
+    #[sqlx::test]
+    async fn delete_trade(pool: Pool<Postgres>) {
+        let service = PgTradeService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let account = insert_account(pool, user.id).await;
+        let (trade, movement) = get_trade(account.id);
+        service
+            .insert_trade(trade.clone(), movement.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(service.delete_trade(trade.clone()).await.unwrap(), trade);
+        assert!(service.find_trades(account.id).await.unwrap().is_empty());
+        assert!(!account_service.has_movements(account.id).await.unwrap());
+        assert_eq!(
+            account_service
+                .find_by_id(account.id)
+                .await
+                .unwrap()
+                .balance,
+            Decimal::from(1000)
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_latest_prices(pool: Pool<Postgres>) {
+        let service = PgPriceService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let price = |symbol: &str, currency: Currency, day: u32, price: i64| Price {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            symbol: symbol.to_string(),
+            currency,
+            date: NaiveDate::from_ymd_opt(2026, 1, day).unwrap(),
+            price: Decimal::from(price),
+        };
+        let prices = service
+            .upsert_many(vec![
+                price("AAPL", Currency::USD, 1, 150),
+                price("AAPL", Currency::USD, 5, 155),
+                price("AAPL", Currency::USD, 10, 160),
+                price("AAPL", Currency::EUR, 5, 140),
+                price("MSFT", Currency::USD, 2, 400),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service
+                .find_latest(
+                    user.id,
+                    Currency::USD,
+                    NaiveDate::from_ymd_opt(2026, 1, 7).unwrap()
+                )
+                .await
+                .unwrap(),
+            vec![prices[1].clone(), prices[4].clone()]
+        );
+
+        let revised = service
+            .upsert_many(vec![price("MSFT", Currency::USD, 2, 410)])
+            .await
+            .unwrap();
+        assert_eq!(revised[0].id, prices[4].id);
+        assert_eq!(revised[0].price, Decimal::from(410));
+    }
+}
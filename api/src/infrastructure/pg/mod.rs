@@ -1,3 +1,14 @@
 pub mod accounts;
+pub mod analytics;
+pub mod budgets;
+pub mod categories;
+mod currencies;
 mod error;
+pub mod exchange_rates;
+pub mod goals;
+pub mod imports;
+pub mod investments;
+pub mod reconciliations;
+pub mod recurring_movements;
+pub mod rules;
 pub mod users;
@@ -0,0 +1,417 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::application::services::reconciliations::ReconciliationService;
+use crate::application::services::Repository;
+use crate::domain::entities::{accounts::Movement, reconciliations::Reconciliation};
+use crate::domain::error::{Error, Result};
+
+pub struct PgReconciliationService {
+    db: PgPool,
+}
+
+impl PgReconciliationService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+// Movements are dated in utc, so the statement date ends at the following utc midnight
+#[async_trait]
+impl ReconciliationService for PgReconciliationService {
+    async fn find_open_by_account_id(&self, account_id: Uuid) -> Result<Reconciliation> {
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"SELECT id, account_id, statement_date, statement_balance, finished_at
+            FROM reconciliations
+            WHERE account_id = $1 AND finished_at IS NULL"#,
+            account_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Reconciliation>> {
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"SELECT r.id, r.account_id, r.statement_date, r.statement_balance, r.finished_at
+            FROM reconciliations r
+            JOIN accounts a ON a.id = r.account_id
+            WHERE a.user_id = $1
+            ORDER BY r.statement_date"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_cleared_balance(&self, account_id: Uuid, date: NaiveDate) -> Result<Decimal> {
+        let data = sqlx::query_scalar!(
+            r#"SELECT a.opening_balance + COALESCE(SUM(m.amount), 0) as "balance!"
+            FROM accounts a
+            LEFT JOIN movements m ON m.account_id = a.id AND (
+                m.status = 'RECONCILED'
+                OR (m.status = 'CLEARED' AND m.timestamp < ($2::date + 1)::timestamp AT TIME ZONE 'UTC')
+            )
+            WHERE a.id = $1
+            GROUP BY a.id"#,
+            account_id,
+            date
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_unreconciled_movements(
+        &self,
+        account_id: Uuid,
+        date: NaiveDate,
+    ) -> Result<Vec<Movement>> {
+        let data = sqlx::query_as!(
+            Movement,
+            r#"SELECT id, account_id, timestamp, title, amount, category_id, transfer_id, external_id, status as "status: _", note, tags, fingerprint
+            FROM movements
+            WHERE account_id = $1
+            AND status <> 'RECONCILED'
+            AND timestamp < ($2::date + 1)::timestamp AT TIME ZONE 'UTC'
+            ORDER BY timestamp ASC, id ASC"#,
+            account_id,
+            date
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn finish(&self, reconciliation: Reconciliation) -> Result<Reconciliation> {
+        let mut tx = self.db.begin().await?;
+
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"UPDATE reconciliations
+            SET finished_at = now()
+            WHERE id = $1 AND finished_at IS NULL
+            RETURNING id, account_id, statement_date, statement_balance, finished_at"#,
+            reconciliation.id
+        )
+        .fetch_one(&mut tx)
+        .await?;
+        sqlx::query!(
+            r#"UPDATE movements
+            SET status = 'RECONCILED'
+            WHERE account_id = $1
+            AND status = 'CLEARED'
+            AND timestamp < ($2::date + 1)::timestamp AT TIME ZONE 'UTC'"#,
+            data.account_id,
+            data.statement_date
+        )
+        .execute(&mut tx)
+        .await?;
+        // Movements may have changed since the report, the transaction is rolled back on a difference
+        let balance = sqlx::query_scalar!(
+            r#"SELECT a.opening_balance + COALESCE(SUM(m.amount), 0) as "balance!"
+            FROM accounts a
+            LEFT JOIN movements m ON m.account_id = a.id AND m.status = 'RECONCILED'
+            WHERE a.id = $1
+            GROUP BY a.id"#,
+            data.account_id
+        )
+        .fetch_one(&mut tx)
+        .await?;
+        let difference = data.statement_balance - balance;
+        if !difference.is_zero() {
+            return Err(Error::Validation(anyhow!(
+                "cleared balance differs from the statement balance by {difference}"
+            )));
+        }
+
+        tx.commit().await?;
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl Repository<Reconciliation> for PgReconciliationService {
+    async fn get_all(&self) -> Result<Vec<Reconciliation>> {
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"SELECT id, account_id, statement_date, statement_balance, finished_at
+            FROM reconciliations"#
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Reconciliation> {
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"SELECT id, account_id, statement_date, statement_balance, finished_at
+            FROM reconciliations
+            WHERE id = $1"#,
+            id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn insert(&self, item: Reconciliation) -> Result<Reconciliation> {
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"INSERT INTO reconciliations(id, account_id, statement_date, statement_balance, finished_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, account_id, statement_date, statement_balance, finished_at"#,
+            item.id,
+            item.account_id,
+            item.statement_date,
+            item.statement_balance,
+            item.finished_at
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn update(&self, item: Reconciliation) -> Result<Reconciliation> {
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"UPDATE reconciliations
+            SET statement_date=$2, statement_balance=$3
+            WHERE id=$1
+            RETURNING id, account_id, statement_date, statement_balance, finished_at"#,
+            item.id,
+            item.statement_date,
+            item.statement_balance
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn delete(&self, item: Reconciliation) -> Result<Reconciliation> {
+        let data = sqlx::query_as!(
+            Reconciliation,
+            r#"DELETE FROM reconciliations
+            WHERE id=$1
+            RETURNING id, account_id, statement_date, statement_balance, finished_at"#,
+            item.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use chrono::{TimeZone, Utc};
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        application::services::accounts::AccountService,
+        domain::entities::{
+            accounts::{Account, AccountKind, AccountTerms, MovementStatus},
+            currencies::Currency,
+            users::User,
+        },
+        domain::error::{Error, RepositoryErrorType},
+        infrastructure::pg::{accounts::PgAccountService, users::PgUserService},
+    };
+
+    async fn insert_account(pool: Pool<Postgres>) -> Account {
+        let user = PgUserService::new(pool.clone())
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap();
+        PgAccountService::new(pool)
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "name".to_string(),
+                currency: Currency::USD,
+                balance: Decimal::from(100),
+                archived: false,
+                opening_balance: Decimal::from(100),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn insert_movement(
+        pool: Pool<Postgres>,
+        account_id: Uuid,
+        day: u32,
+        amount: i64,
+        status: MovementStatus,
+    ) -> Movement {
+        PgAccountService::new(pool)
+            .insert_movement(Movement {
+                id: Uuid::new_v4(),
+                account_id,
+                timestamp: Utc.with_ymd_and_hms(2023, 1, day, 12, 0, 0).unwrap(),
+                title: "title".to_string(),
+                amount: Decimal::from(amount),
+                category_id: None,
+                transfer_id: None,
+                external_id: None,
+                status,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    fn get_reconciliation(account_id: Uuid) -> Reconciliation {
+        Reconciliation {
+            id: Uuid::new_v4(),
+            account_id,
+            statement_date: NaiveDate::from_ymd_opt(2023, 1, 10).unwrap(),
+            statement_balance: Decimal::from(80),
+            finished_at: None,
+        }
+    }
+
+    #[sqlx::test]
+    async fn find_open_by_account_id(pool: Pool<Postgres>) {
+        let service = PgReconciliationService::new(pool.clone());
+        let account = insert_account(pool).await;
+        let reconciliation = service
+            .insert(get_reconciliation(account.id))
+            .await
+            .unwrap();
+        assert_eq!(
+            service.find_open_by_account_id(account.id).await.unwrap(),
+            reconciliation
+        );
+        assert_eq!(
+            service.find_many_by_user_id(account.user_id).await.unwrap(),
+            vec![reconciliation.clone()]
+        );
+        assert!(matches!(
+            service.insert(get_reconciliation(account.id)).await,
+            Err(Error::Repository(RepositoryErrorType::Conflict))
+        ));
+
+        service.delete(reconciliation).await.unwrap();
+        assert!(matches!(
+            service.find_open_by_account_id(account.id).await,
+            Err(Error::Repository(RepositoryErrorType::NotFound))
+        ));
+    }
+
+    #[sqlx::test]
+    async fn cleared_balance_and_movements(pool: Pool<Postgres>) {
+        let service = PgReconciliationService::new(pool.clone());
+        let account = insert_account(pool.clone()).await;
+        let pending =
+            insert_movement(pool.clone(), account.id, 5, -10, MovementStatus::Pending).await;
+        let cleared =
+            insert_movement(pool.clone(), account.id, 10, -20, MovementStatus::Cleared).await;
+        insert_movement(pool.clone(), account.id, 11, -30, MovementStatus::Cleared).await;
+        insert_movement(pool, account.id, 1, 5, MovementStatus::Reconciled).await;
+        let date = NaiveDate::from_ymd_opt(2023, 1, 10).unwrap();
+
+        assert_eq!(
+            service
+                .find_cleared_balance(account.id, date)
+                .await
+                .unwrap(),
+            Decimal::from(85)
+        );
+        assert_eq!(
+            service
+                .find_unreconciled_movements(account.id, date)
+                .await
+                .unwrap(),
+            vec![pending, cleared]
+        );
+    }
+
+    #[sqlx::test]
+    async fn finish(pool: Pool<Postgres>) {
+        let service = PgReconciliationService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let account = insert_account(pool.clone()).await;
+        let pending =
+            insert_movement(pool.clone(), account.id, 5, -10, MovementStatus::Pending).await;
+        let cleared =
+            insert_movement(pool.clone(), account.id, 10, -20, MovementStatus::Cleared).await;
+        let later = insert_movement(pool, account.id, 11, -30, MovementStatus::Cleared).await;
+        let reconciliation = service
+            .insert(get_reconciliation(account.id))
+            .await
+            .unwrap();
+
+        let finished = service.finish(reconciliation.clone()).await.unwrap();
+        assert!(finished.finished_at.is_some());
+        assert!(matches!(
+            service.find_open_by_account_id(account.id).await,
+            Err(Error::Repository(RepositoryErrorType::NotFound))
+        ));
+        assert!(matches!(
+            service.finish(reconciliation).await,
+            Err(Error::Repository(RepositoryErrorType::NotFound))
+        ));
+
+        for (movement, status) in [
+            (pending, MovementStatus::Pending),
+            (cleared, MovementStatus::Reconciled),
+            (later, MovementStatus::Cleared),
+        ] {
+            assert_eq!(
+                account_service
+                    .find_movement(movement.id, account.id)
+                    .await
+                    .unwrap()
+                    .status,
+                status
+            );
+        }
+    }
+
+    #[sqlx::test]
+    async fn finish_with_difference(pool: Pool<Postgres>) {
+        let service = PgReconciliationService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let account = insert_account(pool.clone()).await;
+        let reconciliation = service
+            .insert(get_reconciliation(account.id))
+            .await
+            .unwrap();
+        let cleared =
+            insert_movement(pool.clone(), account.id, 10, -20, MovementStatus::Cleared).await;
+        insert_movement(pool, account.id, 9, -5, MovementStatus::Cleared).await;
+
+        assert!(matches!(
+            service.finish(reconciliation).await,
+            Err(Error::Validation(_))
+        ));
+        assert!(service.find_open_by_account_id(account.id).await.is_ok());
+        assert_eq!(
+            account_service
+                .find_movement(cleared.id, account.id)
+                .await
+                .unwrap()
+                .status,
+            MovementStatus::Cleared
+        );
+    }
+}
@@ -0,0 +1,410 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use super::accounts::insert_movement_tx;
+use crate::application::services::recurring_movements::RecurringMovementService;
+use crate::application::services::Repository;
+use crate::domain::entities::{accounts::Movement, recurring_movements::RecurringMovement};
+use crate::domain::error::Result;
+
+pub struct PgRecurringMovementService {
+    db: PgPool,
+}
+
+impl PgRecurringMovementService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl RecurringMovementService for PgRecurringMovementService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<RecurringMovement> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"SELECT id, user_id, account_id, category_id, title, amount, frequency as "frequency: _", start_date, next_date, paused
+            FROM recurring_movements
+            WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<RecurringMovement>> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"SELECT id, user_id, account_id, category_id, title, amount, frequency as "frequency: _", start_date, next_date, paused
+            FROM recurring_movements
+            WHERE user_id = $1
+            ORDER BY next_date, title"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_due(&self, date: NaiveDate) -> Result<Vec<RecurringMovement>> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"SELECT r.id, r.user_id, r.account_id, r.category_id, r.title, r.amount, r.frequency as "frequency: _", r.start_date, r.next_date, r.paused
+            FROM recurring_movements r
+            JOIN accounts a ON a.id = r.account_id
+            WHERE r.next_date <= $1 AND NOT r.paused AND NOT a.archived
+            ORDER BY r.next_date, r.id"#,
+            date
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn materialise(
+        &self,
+        recurring_movement: RecurringMovement,
+        movement: Movement,
+        next_date: NaiveDate,
+    ) -> Result<Option<Movement>> {
+        let mut tx = self.db.begin().await?;
+
+        // The row lock serializes concurrent runs, the loser sees the already advanced date
+        let current_date = sqlx::query_scalar!(
+            "SELECT next_date FROM recurring_movements WHERE id = $1 AND NOT paused FOR UPDATE",
+            recurring_movement.id
+        )
+        .fetch_optional(&mut tx)
+        .await?;
+        if current_date != Some(recurring_movement.next_date) {
+            return Ok(None);
+        }
+
+        let data = insert_movement_tx(&mut tx, movement).await?;
+        sqlx::query!(
+            "UPDATE recurring_movements SET next_date = $2 WHERE id = $1",
+            recurring_movement.id,
+            next_date
+        )
+        .execute(&mut tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(data))
+    }
+}
+
+#[async_trait]
+impl Repository<RecurringMovement> for PgRecurringMovementService {
+    async fn get_all(&self) -> Result<Vec<RecurringMovement>> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"SELECT id, user_id, account_id, category_id, title, amount, frequency as "frequency: _", start_date, next_date, paused
+            FROM recurring_movements"#
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<RecurringMovement> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"SELECT id, user_id, account_id, category_id, title, amount, frequency as "frequency: _", start_date, next_date, paused
+            FROM recurring_movements
+            WHERE id = $1"#,
+            id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn insert(&self, item: RecurringMovement) -> Result<RecurringMovement> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"INSERT INTO recurring_movements(id, user_id, account_id, category_id, title, amount, frequency, start_date, next_date, paused)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id, user_id, account_id, category_id, title, amount, frequency as "frequency: _", start_date, next_date, paused"#,
+            item.id,
+            item.user_id,
+            item.account_id,
+            item.category_id,
+            item.title,
+            item.amount,
+            item.frequency as _,
+            item.start_date,
+            item.next_date,
+            item.paused
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn update(&self, item: RecurringMovement) -> Result<RecurringMovement> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"UPDATE recurring_movements
+            SET category_id=$2, title=$3, amount=$4, next_date=$5, paused=$6
+            WHERE id=$1
+            RETURNING id, user_id, account_id, category_id, title, amount, frequency as "frequency: _", start_date, next_date, paused"#,
+            item.id,
+            item.category_id,
+            item.title,
+            item.amount,
+            item.next_date,
+            item.paused
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+
+    async fn delete(&self, item: RecurringMovement) -> Result<RecurringMovement> {
+        let data = sqlx::query_as!(
+            RecurringMovement,
+            r#"DELETE FROM recurring_movements
+            WHERE id=$1
+            RETURNING id, user_id, account_id, category_id, title, amount, frequency as "frequency: _", start_date, next_date, paused"#,
+            item.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use chrono::{TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        application::services::accounts::AccountService,
+        domain::entities::{
+            accounts::{Account, AccountKind, AccountTerms, MovementFilter, MovementStatus},
+            categories::Category,
+            currencies::Currency,
+            recurring_movements::Frequency,
+            users::User,
+        },
+        infrastructure::pg::{
+            accounts::PgAccountService, categories::PgCategoryService, users::PgUserService,
+        },
+    };
+
+    async fn insert_recurring_movement(pool: Pool<Postgres>) -> RecurringMovement {
+        let user = PgUserService::new(pool.clone())
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap();
+        let account = PgAccountService::new(pool.clone())
+            .insert(Account {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                name: "name".to_string(),
+                currency: Currency::USD,
+                balance: Decimal::from(0),
+                archived: false,
+                opening_balance: Decimal::from(0),
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            })
+            .await
+            .unwrap();
+        let category = PgCategoryService::new(pool.clone())
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                parent_id: None,
+                name: "Rent".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap();
+        PgRecurringMovementService::new(pool)
+            .insert(RecurringMovement {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                account_id: account.id,
+                category_id: category.id,
+                title: "rent".to_string(),
+                amount: Decimal::from(-800),
+                frequency: Frequency::Monthly,
+                start_date: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                next_date: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                paused: false,
+            })
+            .await
+            .unwrap()
+    }
+
+    fn get_movement(recurring_movement: &RecurringMovement) -> Movement {
+        Movement {
+            id: Uuid::new_v4(),
+            account_id: recurring_movement.account_id,
+            timestamp: Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+            title: recurring_movement.title.clone(),
+            amount: recurring_movement.amount,
+            category_id: Some(recurring_movement.category_id),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        }
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_and_user_id(pool: Pool<Postgres>) {
+        let service = PgRecurringMovementService::new(pool.clone());
+        let recurring_movement = insert_recurring_movement(pool).await;
+        assert_eq!(
+            service
+                .find_by_id_and_user_id(recurring_movement.id, recurring_movement.user_id)
+                .await
+                .unwrap(),
+            recurring_movement
+        );
+        assert_eq!(
+            service
+                .find_many_by_user_id(recurring_movement.user_id)
+                .await
+                .unwrap(),
+            vec![recurring_movement]
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_by_id_and_user_id_not_found(pool: Pool<Postgres>) {
+        let service = PgRecurringMovementService::new(pool.clone());
+        let recurring_movement = insert_recurring_movement(pool).await;
+        service
+            .find_by_id_and_user_id(recurring_movement.id, Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn find_due(pool: Pool<Postgres>) {
+        let service = PgRecurringMovementService::new(pool.clone());
+        let recurring_movement = insert_recurring_movement(pool).await;
+        assert_eq!(
+            service
+                .find_due(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap())
+                .await
+                .unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            service
+                .find_due(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+                .await
+                .unwrap(),
+            vec![recurring_movement.clone()]
+        );
+
+        service
+            .update(RecurringMovement {
+                paused: true,
+                ..recurring_movement
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            service
+                .find_due(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+                .await
+                .unwrap(),
+            vec![]
+        );
+    }
+
+    #[sqlx::test]
+    async fn materialise(pool: Pool<Postgres>) {
+        let service = PgRecurringMovementService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let recurring_movement = insert_recurring_movement(pool).await;
+        let next_date = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+        let movement = get_movement(&recurring_movement);
+
+        assert_eq!(
+            service
+                .materialise(recurring_movement.clone(), movement.clone(), next_date)
+                .await
+                .unwrap(),
+            Some(movement.clone())
+        );
+        assert_eq!(
+            service
+                .materialise(
+                    recurring_movement.clone(),
+                    get_movement(&recurring_movement),
+                    next_date
+                )
+                .await
+                .unwrap(),
+            None
+        );
+
+        assert_eq!(
+            service
+                .find_by_id(recurring_movement.id)
+                .await
+                .unwrap()
+                .next_date,
+            next_date
+        );
+        assert_eq!(
+            account_service
+                .find_movements(
+                    recurring_movement.account_id,
+                    MovementFilter::default(),
+                    None,
+                    10
+                )
+                .await
+                .unwrap(),
+            vec![movement]
+        );
+        assert_eq!(
+            account_service
+                .find_by_id(recurring_movement.account_id)
+                .await
+                .unwrap()
+                .balance,
+            Decimal::from(-800)
+        );
+    }
+
+    #[sqlx::test]
+    async fn delete(pool: Pool<Postgres>) {
+        let service = PgRecurringMovementService::new(pool.clone());
+        let recurring_movement = insert_recurring_movement(pool).await;
+        assert_eq!(
+            service.delete(recurring_movement.clone()).await.unwrap(),
+            recurring_movement
+        );
+        assert_eq!(
+            service
+                .find_many_by_user_id(recurring_movement.user_id)
+                .await
+                .unwrap(),
+            vec![]
+        );
+    }
+}
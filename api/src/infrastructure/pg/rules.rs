@@ -0,0 +1,314 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::application::services::rules::RuleService;
+use crate::application::services::Repository;
+use crate::domain::entities::rules::{Rule, RuleActions, RuleConditions};
+use crate::domain::error::Result;
+
+pub struct PgRuleService {
+    db: PgPool,
+}
+
+impl PgRuleService {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+struct RuleRow {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    priority: i32,
+    title_contains: Option<String>,
+    title_regex: Option<String>,
+    min_amount: Option<Decimal>,
+    max_amount: Option<Decimal>,
+    account_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    add_tags: Vec<String>,
+    rename_title: Option<String>,
+}
+
+impl From<RuleRow> for Rule {
+    fn from(row: RuleRow) -> Self {
+        Rule {
+            id: row.id,
+            user_id: row.user_id,
+            name: row.name,
+            priority: row.priority,
+            conditions: RuleConditions {
+                title_contains: row.title_contains,
+                title_regex: row.title_regex,
+                min_amount: row.min_amount,
+                max_amount: row.max_amount,
+                account_id: row.account_id,
+            },
+            actions: RuleActions {
+                category_id: row.category_id,
+                add_tags: row.add_tags,
+                rename_title: row.rename_title,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl RuleService for PgRuleService {
+    async fn find_by_id_and_user_id(&self, id: Uuid, user_id: Uuid) -> Result<Rule> {
+        let data = sqlx::query_as!(
+            RuleRow,
+            r#"SELECT id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title
+            FROM rules
+            WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn find_many_by_user_id(&self, user_id: Uuid) -> Result<Vec<Rule>> {
+        let data = sqlx::query_as!(
+            RuleRow,
+            r#"SELECT id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title
+            FROM rules
+            WHERE user_id = $1
+            ORDER BY priority ASC, name ASC"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data.into_iter().map(Rule::from).collect())
+    }
+}
+
+#[async_trait]
+impl Repository<Rule> for PgRuleService {
+    async fn get_all(&self) -> Result<Vec<Rule>> {
+        let data = sqlx::query_as!(
+            RuleRow,
+            r#"SELECT id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title
+            FROM rules
+            ORDER BY priority ASC, name ASC"#
+        )
+        .fetch_all(&self.db)
+        .await?;
+        Ok(data.into_iter().map(Rule::from).collect())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Rule> {
+        let data = sqlx::query_as!(
+            RuleRow,
+            r#"SELECT id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title
+            FROM rules
+            WHERE id = $1"#,
+            id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn insert(&self, item: Rule) -> Result<Rule> {
+        let data = sqlx::query_as!(
+            RuleRow,
+            r#"INSERT INTO rules(id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title"#,
+            item.id,
+            item.user_id,
+            item.name,
+            item.priority,
+            item.conditions.title_contains,
+            item.conditions.title_regex,
+            item.conditions.min_amount,
+            item.conditions.max_amount,
+            item.conditions.account_id,
+            item.actions.category_id,
+            &item.actions.add_tags,
+            item.actions.rename_title
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn update(&self, item: Rule) -> Result<Rule> {
+        let data = sqlx::query_as!(
+            RuleRow,
+            r#"UPDATE rules
+            SET name=$2, priority=$3, title_contains=$4, title_regex=$5, min_amount=$6, max_amount=$7, account_id=$8, category_id=$9, add_tags=$10, rename_title=$11
+            WHERE id=$1
+            RETURNING id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title"#,
+            item.id,
+            item.name,
+            item.priority,
+            item.conditions.title_contains,
+            item.conditions.title_regex,
+            item.conditions.min_amount,
+            item.conditions.max_amount,
+            item.conditions.account_id,
+            item.actions.category_id,
+            &item.actions.add_tags,
+            item.actions.rename_title
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+
+    async fn delete(&self, item: Rule) -> Result<Rule> {
+        let data = sqlx::query_as!(
+            RuleRow,
+            r#"DELETE FROM rules
+            WHERE id=$1
+            RETURNING id, user_id, name, priority, title_contains, title_regex, min_amount, max_amount, account_id, category_id, add_tags, rename_title"#,
+            item.id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(data.into())
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use sqlx::{Pool, Postgres};
+
+    use super::*;
+    use crate::{
+        domain::entities::{categories::Category, currencies::Currency, users::User},
+        infrastructure::pg::{categories::PgCategoryService, users::PgUserService},
+    };
+
+    async fn insert_user(pool: Pool<Postgres>) -> User {
+        let user_service = PgUserService::new(pool);
+        user_service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn insert_category(pool: Pool<Postgres>, user_id: Uuid) -> Category {
+        PgCategoryService::new(pool)
+            .insert(Category {
+                id: Uuid::new_v4(),
+                user_id,
+                parent_id: None,
+                name: "Groceries".to_string(),
+                color: None,
+                icon: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    fn get_rule(user_id: Uuid, name: &str, priority: i32, category_id: Option<Uuid>) -> Rule {
+        Rule {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.to_string(),
+            priority,
+            conditions: RuleConditions {
+                title_contains: Some("market".to_string()),
+                min_amount: Some(Decimal::new(-1005, 1)),
+                ..Default::default()
+            },
+            actions: RuleActions {
+                category_id,
+                add_tags: vec!["food".to_string()],
+                ..Default::default()
+            },
+        }
+    }
+
+    #[sqlx::test]
+    async fn insert(pool: Pool<Postgres>) {
+        let service = PgRuleService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool, user.id).await;
+        let rule = get_rule(user.id, "groceries", 1, Some(category.id));
+        let other = get_rule(user.id, "market", 0, None);
+        assert_eq!(service.insert(rule.clone()).await.unwrap(), rule);
+        assert_eq!(service.insert(other.clone()).await.unwrap(), other);
+        assert_eq!(
+            service
+                .find_by_id_and_user_id(rule.id, user.id)
+                .await
+                .unwrap(),
+            rule
+        );
+        assert_eq!(
+            service.find_many_by_user_id(user.id).await.unwrap(),
+            vec![other, rule]
+        );
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "Repository(NotFound)")]
+    async fn find_by_id_and_user_id_not_found(pool: Pool<Postgres>) {
+        let service = PgRuleService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let rule = service
+            .insert(get_rule(user.id, "groceries", 0, None))
+            .await
+            .unwrap();
+        service
+            .find_by_id_and_user_id(rule.id, Uuid::new_v4())
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn update(pool: Pool<Postgres>) {
+        let service = PgRuleService::new(pool.clone());
+        let user = insert_user(pool).await;
+        let rule = service
+            .insert(get_rule(user.id, "groceries", 0, None))
+            .await
+            .unwrap();
+        let updated = Rule {
+            name: "supermarket".to_string(),
+            priority: 5,
+            conditions: RuleConditions {
+                title_regex: Some("^SUPERMARKET".to_string()),
+                ..Default::default()
+            },
+            actions: RuleActions {
+                rename_title: Some("Supermarket".to_string()),
+                ..Default::default()
+            },
+            ..rule
+        };
+        assert_eq!(service.update(updated.clone()).await.unwrap(), updated);
+    }
+
+    #[sqlx::test]
+    async fn delete_with_category(pool: Pool<Postgres>) {
+        let service = PgRuleService::new(pool.clone());
+        let user = insert_user(pool.clone()).await;
+        let category = insert_category(pool.clone(), user.id).await;
+        let rule = service
+            .insert(get_rule(user.id, "groceries", 0, Some(category.id)))
+            .await
+            .unwrap();
+        let other = service
+            .insert(get_rule(user.id, "market", 1, None))
+            .await
+            .unwrap();
+        assert_eq!(service.delete(other.clone()).await.unwrap(), other);
+
+        PgCategoryService::new(pool).delete(category).await.unwrap();
+        assert_eq!(service.find_many_by_user_id(user.id).await.unwrap(), vec![]);
+        assert!(service.find_by_id(rule.id).await.is_err());
+    }
+}
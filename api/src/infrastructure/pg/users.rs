@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::application::services::users::UserService;
 use crate::application::services::Repository;
+use crate::domain::entities::categories::DEFAULT_CATEGORIES;
 use crate::domain::entities::users::User;
 use crate::domain::error::Result;
 
@@ -20,7 +21,11 @@ impl PgUserService {
 #[async_trait]
 impl UserService for PgUserService {
     async fn find_by_email(&self, email: &str) -> Result<User> {
-        let data = sqlx::query_as!(User, "SELECT * FROM users WHERE email = $1", email)
+        let data = sqlx::query_as!(
+            User,
+            r#"SELECT id, email, preferred_currency as "preferred_currency: _" FROM users WHERE email = $1"#,
+            email
+        )
             .fetch_one(&self.db)
             .await?;
         Ok(data)
@@ -30,30 +35,47 @@ impl UserService for PgUserService {
 #[async_trait]
 impl Repository<User> for PgUserService {
     async fn get_all(&self) -> Result<Vec<User>> {
-        let data = sqlx::query_as!(User, r#"SELECT * FROM users"#)
-            .fetch_all(&self.db)
-            .await?;
+        let data = sqlx::query_as!(
+            User,
+            r#"SELECT id, email, preferred_currency as "preferred_currency: _" FROM users"#
+        )
+        .fetch_all(&self.db)
+        .await?;
         Ok(data)
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<User> {
-        let data = sqlx::query_as!(User, r#"SELECT * FROM users WHERE id = $1"#, id)
+        let data = sqlx::query_as!(User, r#"SELECT id, email, preferred_currency as "preferred_currency: _" FROM users WHERE id = $1"#, id)
             .fetch_one(&self.db)
             .await?;
         Ok(data)
     }
 
     async fn insert(&self, item: User) -> Result<User> {
+        let mut tx = self.db.begin().await?;
+
         let data = sqlx::query_as!(
             User,
-            r#"INSERT INTO users(id, email)
-            VALUES($1, $2)
-            RETURNING *"#,
+            r#"INSERT INTO users(id, email, preferred_currency)
+            VALUES($1, $2, $3)
+            RETURNING id, email, preferred_currency as "preferred_currency: _""#,
             item.id,
-            item.email
+            item.email,
+            item.preferred_currency as _
         )
-        .fetch_one(&self.db)
+        .fetch_one(&mut tx)
         .await?;
+
+        sqlx::query!(
+            r#"INSERT INTO categories(id, user_id, name)
+            SELECT gen_random_uuid(), $1, name FROM UNNEST($2::varchar[]) AS name"#,
+            data.id,
+            &DEFAULT_CATEGORIES[..] as _
+        )
+        .execute(&mut tx)
+        .await?;
+
+        tx.commit().await?;
         Ok(data)
     }
 
@@ -61,21 +83,50 @@ impl Repository<User> for PgUserService {
         let data = sqlx::query_as!(
             User,
             r#"UPDATE users
-            SET email=$2
+            SET email=$2, preferred_currency=$3
             WHERE id=$1
-            RETURNING *"#,
+            RETURNING id, email, preferred_currency as "preferred_currency: _""#,
             item.id,
-            item.email
+            item.email,
+            item.preferred_currency as _
         )
         .fetch_one(&self.db)
         .await?;
         Ok(data)
     }
 
+    // Movements are deleted explicitly, so that an account with movements still cannot be deleted
+    // on its own, and the rest of the user's data is removed by the foreign keys cascade
     async fn delete(&self, item: User) -> Result<User> {
-        let data = sqlx::query_as!(User, "DELETE FROM users WHERE id=$1 RETURNING *", item.id)
-            .fetch_one(&self.db)
+        let mut tx = self.db.begin().await?;
+
+        let transfer_ids = sqlx::query_scalar!(
+            r#"DELETE FROM movements m
+            USING accounts a
+            WHERE a.id = m.account_id AND a.user_id = $1
+            RETURNING m.transfer_id"#,
+            item.id
+        )
+        .fetch_all(&mut tx)
+        .await?;
+        let transfer_ids: Vec<Uuid> = transfer_ids.into_iter().flatten().collect();
+
+        let data = sqlx::query_as!(
+            User,
+            r#"DELETE FROM users WHERE id=$1 RETURNING id, email, preferred_currency as "preferred_currency: _""#,
+            item.id
+        )
+            .fetch_one(&mut tx)
             .await?;
+
+        sqlx::query!(
+            "DELETE FROM transfers WHERE id = ANY($1)",
+            &transfer_ids[..]
+        )
+        .execute(&mut tx)
+        .await?;
+
+        tx.commit().await?;
         Ok(data)
     }
 }
@@ -84,7 +135,16 @@ impl Repository<User> for PgUserService {
 mod integration_tests {
     use sqlx::{Pool, Postgres};
 
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
     use super::*;
+    use crate::application::services::accounts::AccountService;
+    use crate::domain::entities::accounts::{
+        Account, AccountKind, AccountTerms, Movement, MovementStatus, Transfer,
+    };
+    use crate::domain::entities::currencies::Currency;
+    use crate::infrastructure::pg::accounts::PgAccountService;
 
     #[sqlx::test]
     async fn get_all(pool: Pool<Postgres>) {
@@ -93,6 +153,7 @@ mod integration_tests {
             .insert(User {
                 id: Uuid::new_v4(),
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -107,6 +168,7 @@ mod integration_tests {
             .insert(User {
                 id,
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -128,6 +190,7 @@ mod integration_tests {
             .insert(User {
                 id,
                 email: "email".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -150,12 +213,14 @@ mod integration_tests {
                 .insert(User {
                     id,
                     email: "".to_string(),
+                    preferred_currency: Currency::USD,
                 })
                 .await
                 .unwrap(),
             User {
                 id,
-                email: "".to_string()
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
             }
         );
     }
@@ -169,6 +234,7 @@ mod integration_tests {
             .insert(User {
                 id,
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -176,6 +242,7 @@ mod integration_tests {
             .insert(User {
                 id,
                 email: "email".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -189,6 +256,7 @@ mod integration_tests {
             .insert(User {
                 id,
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -197,12 +265,14 @@ mod integration_tests {
                 .update(User {
                     id,
                     email: "email".to_string(),
+                    preferred_currency: Currency::EUR,
                 })
                 .await
                 .unwrap(),
             User {
                 id,
-                email: "email".to_string()
+                email: "email".to_string(),
+                preferred_currency: Currency::EUR,
             }
         );
     }
@@ -215,6 +285,7 @@ mod integration_tests {
             .update(User {
                 id: Uuid::new_v4(),
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -228,6 +299,7 @@ mod integration_tests {
             .insert(User {
                 id,
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -236,16 +308,92 @@ mod integration_tests {
                 .delete(User {
                     id,
                     email: "".to_string(),
+                    preferred_currency: Currency::USD,
                 })
                 .await
                 .unwrap(),
             User {
                 id,
-                email: "".to_string()
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
             }
         );
     }
 
+    #[sqlx::test]
+    async fn delete_cascades(pool: Pool<Postgres>) {
+        let service = PgUserService::new(pool.clone());
+        let account_service = PgAccountService::new(pool.clone());
+        let user = service
+            .insert(User {
+                id: Uuid::new_v4(),
+                email: "".to_string(),
+                preferred_currency: Currency::USD,
+            })
+            .await
+            .unwrap();
+        let mut accounts = vec![];
+        for _ in 0..2 {
+            accounts.push(
+                account_service
+                    .insert(Account {
+                        id: Uuid::new_v4(),
+                        user_id: user.id,
+                        name: "".to_string(),
+                        balance: Decimal::from(0),
+                        currency: Currency::USD,
+                        archived: false,
+                        opening_balance: Decimal::from(0),
+                        opening_date: None,
+                        kind: AccountKind::Checking,
+                        terms: AccountTerms::default(),
+                    })
+                    .await
+                    .unwrap(),
+            );
+        }
+        let transfer_id = Uuid::new_v4();
+        let movement = |account_id: Uuid, amount: i64| Movement {
+            id: Uuid::new_v4(),
+            account_id,
+            amount: Decimal::from(amount),
+            category_id: None,
+            timestamp: Utc::now(),
+            title: "".to_string(),
+            transfer_id: Some(transfer_id),
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        account_service
+            .insert_transfer(Transfer {
+                id: transfer_id,
+                debit: movement(accounts[0].id, -10),
+                credit: movement(accounts[1].id, 10),
+                rate: Decimal::from(1),
+                rate_date: None,
+            })
+            .await
+            .unwrap();
+
+        service.delete(user.clone()).await.unwrap();
+
+        let remaining = sqlx::query_scalar!(
+            r#"SELECT
+                (SELECT COUNT(*) FROM accounts WHERE user_id = $1)
+                + (SELECT COUNT(*) FROM movements WHERE transfer_id = $2)
+                + (SELECT COUNT(*) FROM transfers WHERE id = $2) as "count!""#,
+            user.id,
+            transfer_id
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(remaining, 0);
+    }
+
     #[sqlx::test]
     #[should_panic(expected = "Repository(NotFound)")]
     async fn delete_not_found(pool: Pool<Postgres>) {
@@ -254,6 +402,7 @@ mod integration_tests {
             .delete(User {
                 id: Uuid::new_v4(),
                 email: "".to_string(),
+                preferred_currency: Currency::USD,
             })
             .await
             .unwrap();
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use axum::{
-    extract::rejection::{JsonRejection, TypedHeaderRejection},
+    extract::rejection::{JsonRejection, QueryRejection, TypedHeaderRejection},
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
@@ -31,6 +31,12 @@ impl From<JsonRejection> for Error {
     }
 }
 
+impl From<QueryRejection> for Error {
+    fn from(e: QueryRejection) -> Self {
+        Error::Validation(e.into())
+    }
+}
+
 impl IntoResponse for Error {
     fn into_response(self) -> Response {
         self.log();
@@ -72,6 +78,9 @@ impl IntoResponse for Error {
             Error::Validation(err) if matches!(err.downcast_ref::<JsonRejection>(), Some(_)) => {
                 StatusCode::BAD_REQUEST
             }
+            Error::Validation(err) if err.downcast_ref::<QueryRejection>().is_some() => {
+                StatusCode::BAD_REQUEST
+            }
             Error::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
             Error::External(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
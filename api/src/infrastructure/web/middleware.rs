@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use axum::{
-    extract::{rejection::JsonRejection, FromRef, FromRequest, FromRequestParts, Json},
+    extract::{rejection::JsonRejection, FromRef, FromRequest, FromRequestParts, Json, Query},
     headers::{authorization::Bearer, Authorization},
     http::{request::Parts, Request},
     RequestPartsExt, TypedHeader,
@@ -31,6 +31,24 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedQuery<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(value) = Query::<T>::from_request_parts(parts, state).await?;
+        value.validate()?;
+        Ok(ValidatedQuery(value))
+    }
+}
+
 #[async_trait]
 impl<S> FromRequestParts<S> for Claims
 where
@@ -3,7 +3,14 @@ use std::{future::Future, net::SocketAddr, sync::Arc};
 use tower_http::trace;
 
 use crate::{
-    application::use_cases::{auth::AuthUseCaseTrait, profile::ProfileUseCaseTrait},
+    application::use_cases::{
+        analytics::AnalyticsUseCaseTrait, auth::AuthUseCaseTrait, budgets::BudgetUseCaseTrait,
+        categories::CategoryUseCaseTrait, exchange_rates::ExchangeRateUseCaseTrait,
+        goals::GoalUseCaseTrait, imports::ImportUseCaseTrait, investments::InvestmentUseCaseTrait,
+        profile::ProfileUseCaseTrait, reconciliations::ReconciliationUseCaseTrait,
+        recurring_movements::RecurringMovementUseCaseTrait, rules::RuleUseCaseTrait,
+        takeout::TakeoutUseCaseTrait, transfers::TransferUseCaseTrait,
+    },
     config::Config,
 };
 
@@ -13,14 +20,20 @@ mod routes;
 
 #[derive(Clone)]
 pub struct State {
-    auth: Arc<dyn AuthUseCaseTrait>,
-    profile: Arc<dyn ProfileUseCaseTrait>,
-}
-
-impl State {
-    pub fn new(auth: Arc<dyn AuthUseCaseTrait>, profile: Arc<dyn ProfileUseCaseTrait>) -> Self {
-        State { auth, profile }
-    }
+    pub auth: Arc<dyn AuthUseCaseTrait>,
+    pub profile: Arc<dyn ProfileUseCaseTrait>,
+    pub analytics: Arc<dyn AnalyticsUseCaseTrait>,
+    pub budgets: Arc<dyn BudgetUseCaseTrait>,
+    pub categories: Arc<dyn CategoryUseCaseTrait>,
+    pub exchange_rates: Arc<dyn ExchangeRateUseCaseTrait>,
+    pub goals: Arc<dyn GoalUseCaseTrait>,
+    pub imports: Arc<dyn ImportUseCaseTrait>,
+    pub investments: Arc<dyn InvestmentUseCaseTrait>,
+    pub reconciliations: Arc<dyn ReconciliationUseCaseTrait>,
+    pub recurring_movements: Arc<dyn RecurringMovementUseCaseTrait>,
+    pub rules: Arc<dyn RuleUseCaseTrait>,
+    pub takeout: Arc<dyn TakeoutUseCaseTrait>,
+    pub transfers: Arc<dyn TransferUseCaseTrait>,
 }
 
 pub async fn run(config: Config, state: State, shutdown_signal: impl Future<Output = ()>) {
@@ -30,7 +43,22 @@ pub async fn run(config: Config, state: State, shutdown_signal: impl Future<Outp
 
     let app = Router::new()
         .nest("/api/v1/auth", routes::auth::router())
-        .nest("/api/v1/profile", routes::profile::router())
+        .nest(
+            "/api/v1/profile",
+            routes::profile::router()
+                .merge(routes::analytics::router())
+                .merge(routes::budgets::router())
+                .merge(routes::categories::router())
+                .merge(routes::exchange_rates::router())
+                .merge(routes::goals::router())
+                .merge(routes::imports::router())
+                .merge(routes::investments::router())
+                .merge(routes::reconciliations::router())
+                .merge(routes::recurring_movements::router())
+                .merge(routes::rules::router())
+                .merge(routes::takeout::router())
+                .merge(routes::transfers::router()),
+        )
         .with_state(state)
         .layer(config.get_cors_layer())
         .layer(
@@ -58,5 +86,25 @@ pub fn get_mock_state(
     State {
         auth: Arc::new(auth),
         profile: Arc::new(profile),
+        analytics: Arc::new(crate::application::use_cases::analytics::MockAnalyticsUseCase::new()),
+        budgets: Arc::new(crate::application::use_cases::budgets::MockBudgetUseCase::new()),
+        categories: Arc::new(crate::application::use_cases::categories::MockCategoryUseCase::new()),
+        exchange_rates: Arc::new(
+            crate::application::use_cases::exchange_rates::MockExchangeRateUseCase::new(),
+        ),
+        goals: Arc::new(crate::application::use_cases::goals::MockGoalUseCase::new()),
+        imports: Arc::new(crate::application::use_cases::imports::MockImportUseCase::new()),
+        investments: Arc::new(
+            crate::application::use_cases::investments::MockInvestmentUseCase::new(),
+        ),
+        reconciliations: Arc::new(
+            crate::application::use_cases::reconciliations::MockReconciliationUseCase::new(),
+        ),
+        recurring_movements: Arc::new(
+            crate::application::use_cases::recurring_movements::MockRecurringMovementUseCase::new(),
+        ),
+        rules: Arc::new(crate::application::use_cases::rules::MockRuleUseCase::new()),
+        takeout: Arc::new(crate::application::use_cases::takeout::MockTakeoutUseCase::new()),
+        transfers: Arc::new(crate::application::use_cases::transfers::MockTransferUseCase::new()),
     }
 }
@@ -0,0 +1,244 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::ValidatedQuery;
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{analytics::Interval, auth::Claims},
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct AnalyticsQuery {
+    account_id: Option<Uuid>,
+    #[validate(length(min = 1, max = 32))]
+    tag: Option<String>,
+    from: NaiveDate,
+    to: NaiveDate,
+    interval: Option<Interval>,
+    #[validate(range(min = 1, max = 100))]
+    top: Option<i64>,
+}
+
+#[derive(Deserialize, Validate)]
+struct BalancesQuery {
+    from: NaiveDate,
+    to: NaiveDate,
+    interval: Option<Interval>,
+}
+
+async fn get_analytics(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<AnalyticsQuery>,
+) -> Result<impl IntoResponse, Error> {
+    let analytics = state
+        .analytics
+        .get_analytics(
+            claims.sub,
+            query.account_id,
+            query.tag,
+            query.from,
+            query.to,
+            query.interval.unwrap_or(Interval::Month),
+            query.top.unwrap_or(10),
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(analytics)))
+}
+
+async fn get_balances(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<BalancesQuery>,
+) -> Result<impl IntoResponse, Error> {
+    let series = state
+        .analytics
+        .get_balance_series(
+            claims.sub,
+            account_id,
+            query.from,
+            query.to,
+            query.interval.unwrap_or(Interval::Day),
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(series)))
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/accounts/:account_id/balances", get(get_balances))
+        .route("/analytics", get(get_analytics))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{extract::FromRequestParts, response::IntoResponse};
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::analytics::MockAnalyticsUseCase,
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        domain::entities::analytics::{Analytics, AnalyticsPeriod, BalancePoint, BalanceSeries},
+        domain::entities::currencies::Currency,
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn get_analytics_defaults() {
+        let user_id = uuid::Uuid::new_v4();
+        let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        let analytics = Analytics {
+            currency: Currency::USD,
+            from,
+            to,
+            interval: Interval::Month,
+            income: Decimal::from(10),
+            expense: Decimal::from(0),
+            net: Decimal::from(10),
+            periods: vec![AnalyticsPeriod {
+                period: from,
+                income: Decimal::from(10),
+                expense: Decimal::from(0),
+                net: Decimal::from(10),
+            }],
+            categories: vec![],
+            top_titles: vec![],
+        };
+        let analytics2 = analytics.clone();
+        let (mut parts, _) = axum::http::Request::builder()
+            .uri("/?from=2023-01-01&to=2023-01-31&tag=food")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let query = ValidatedQuery::<AnalyticsQuery>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        let mut analytics_use_case = MockAnalyticsUseCase::new();
+        analytics_use_case
+            .expect_get_analytics()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(None),
+                predicate::eq(Some("food".to_string())),
+                predicate::eq(from),
+                predicate::eq(to),
+                predicate::eq(Interval::Month),
+                predicate::eq(10),
+            )
+            .return_once(|_, _, _, _, _, _, _| Ok(analytics));
+
+        let state = AppState {
+            analytics: Arc::new(analytics_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response =
+            super::get_analytics(axum::extract::State(state), Claims { sub: user_id }, query)
+                .await
+                .unwrap()
+                .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Analytics>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, analytics2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Validation")]
+    async fn get_analytics_invalid_top() {
+        let (mut parts, _) = axum::http::Request::builder()
+            .uri("/?from=2023-01-01&to=2023-01-31&interval=week&top=0")
+            .body(())
+            .unwrap()
+            .into_parts();
+        ValidatedQuery::<AnalyticsQuery>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_balances_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap();
+        let series = BalanceSeries {
+            account_id,
+            currency: Currency::USD,
+            interval: Interval::Month,
+            balances: vec![BalancePoint {
+                period: from,
+                balance: Decimal::from(10),
+            }],
+        };
+        let series2 = series.clone();
+        let (mut parts, _) = axum::http::Request::builder()
+            .uri("/?from=2023-01-01&to=2023-03-01&interval=month")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let query = ValidatedQuery::<BalancesQuery>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        let mut analytics_use_case = MockAnalyticsUseCase::new();
+        analytics_use_case
+            .expect_get_balance_series()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(from),
+                predicate::eq(to),
+                predicate::eq(Interval::Month),
+            )
+            .return_once(|_, _, _, _, _| Ok(series));
+
+        let state = AppState {
+            analytics: Arc::new(analytics_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::get_balances(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            query,
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<BalanceSeries>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, series2);
+    }
+}
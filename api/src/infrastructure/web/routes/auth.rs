@@ -1,7 +1,14 @@
-use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, post},
+    Json, Router,
+};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::domain::entities::auth::Claims;
 use crate::domain::error::Error;
 use crate::infrastructure::web::middleware::ValidatedJson;
 use crate::infrastructure::web::State as AppState;
@@ -20,6 +27,12 @@ struct LoginBody {
     otp: String,
 }
 
+#[derive(Deserialize, Validate)]
+struct DeleteAccountBody {
+    #[validate(length(equal = 6))]
+    otp: String,
+}
+
 #[derive(Debug, Serialize)]
 struct LoginResponse {
     access_token: String,
@@ -50,11 +63,30 @@ async fn signup(
     Ok(StatusCode::CREATED)
 }
 
+async fn deletion_otp(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state.auth.send_deletion_otp(claims.sub).await?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn delete_account(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<DeleteAccountBody>,
+) -> Result<impl IntoResponse, Error> {
+    state.auth.delete_user(claims.sub, &payload.otp).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/otp", post(otp))
         .route("/signup", post(signup))
         .route("/login", post(login))
+        .route("/account/deletion-otp", post(deletion_otp))
+        .route("/account", delete(delete_account))
 }
 
 #[cfg(test)]
@@ -154,4 +186,47 @@ mod tests {
 
         assert_eq!(response.status(), axum::http::StatusCode::CREATED);
     }
+
+    #[tokio::test]
+    async fn deletion_otp_successful() {
+        let user_id = uuid::Uuid::new_v4();
+
+        let mut auth = MockAuthUseCase::new();
+        auth.expect_send_deletion_otp()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(()));
+
+        let state = get_mock_state(auth, MockProfileUseCase::new());
+
+        let response = super::deletion_otp(axum::extract::State(state), Claims { sub: user_id })
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn delete_account_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let otp = "123456";
+
+        let mut auth = MockAuthUseCase::new();
+        auth.expect_delete_user()
+            .with(predicate::eq(user_id), predicate::eq(otp))
+            .return_once(|_, _| Ok(()));
+
+        let state = get_mock_state(auth, MockProfileUseCase::new());
+
+        let response = super::delete_account(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(super::DeleteAccountBody {
+                otp: otp.to_string(),
+            }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
 }
@@ -0,0 +1,301 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, put},
+    Json, Router,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::{ValidatedJson, ValidatedQuery};
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{auth::Claims, currencies::Currency},
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct BudgetBody {
+    category_id: Uuid,
+    month: NaiveDate,
+    amount: Decimal,
+    currency: Currency,
+    #[serde(default)]
+    rollover: bool,
+}
+
+#[derive(Deserialize, Validate)]
+struct BudgetsQuery {
+    month: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Validate)]
+struct BudgetReportQuery {
+    month: NaiveDate,
+    #[validate(range(max = 1000))]
+    threshold: Option<u32>,
+}
+
+async fn get_budgets(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<BudgetsQuery>,
+) -> Result<impl IntoResponse, Error> {
+    let budgets = state.budgets.get_budgets(claims.sub, query.month).await?;
+    Ok((StatusCode::OK, Json(budgets)))
+}
+
+async fn put_budget(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<BudgetBody>,
+) -> Result<impl IntoResponse, Error> {
+    let budget = state
+        .budgets
+        .set_budget(
+            claims.sub,
+            payload.category_id,
+            payload.month,
+            payload.amount,
+            payload.currency,
+            payload.rollover,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(budget)))
+}
+
+async fn delete_budget(
+    State(state): State<AppState>,
+    Path(budget_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state.budgets.delete_budget(claims.sub, budget_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_budget_report(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<BudgetReportQuery>,
+) -> Result<impl IntoResponse, Error> {
+    let report = state
+        .budgets
+        .get_budget_report(claims.sub, query.month, query.threshold.unwrap_or(100))
+        .await?;
+
+    Ok((StatusCode::OK, Json(report)))
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/budgets", get(get_budgets))
+        .route("/budgets", put(put_budget))
+        .route("/budgets/report", get(get_budget_report))
+        .route("/budgets/:budget_id", delete(delete_budget))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{extract::FromRequestParts, response::IntoResponse};
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::budgets::MockBudgetUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        domain::entities::budgets::{Budget, BudgetReport, BudgetReportEntry},
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn put_budget_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let month = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let budget = Budget {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            category_id,
+            month,
+            amount: Decimal::from(300),
+            currency: Currency::EUR,
+            rollover: true,
+        };
+        let budget2 = budget.clone();
+
+        let mut budgets = MockBudgetUseCase::new();
+        budgets
+            .expect_set_budget()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(category_id),
+                predicate::eq(month),
+                predicate::eq(Decimal::from(300)),
+                predicate::eq(Currency::EUR),
+                predicate::eq(true),
+            )
+            .return_once(|_, _, _, _, _, _| Ok(budget));
+
+        let state = AppState {
+            budgets: Arc::new(budgets),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::put_budget(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(BudgetBody {
+                category_id,
+                month,
+                amount: Decimal::from(300),
+                currency: Currency::EUR,
+                rollover: true,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Budget>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, budget2);
+    }
+
+    #[tokio::test]
+    async fn get_budgets_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let month = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+
+        let mut budgets = MockBudgetUseCase::new();
+        budgets
+            .expect_get_budgets()
+            .with(predicate::eq(user_id), predicate::eq(Some(month)))
+            .return_once(|_, _| Ok(vec![]));
+
+        let state = AppState {
+            budgets: Arc::new(budgets),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::get_budgets(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedQuery(BudgetsQuery { month: Some(month) }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn get_budget_report_default_threshold() {
+        let user_id = uuid::Uuid::new_v4();
+        let month = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let report = BudgetReport {
+            month,
+            threshold: 100,
+            entries: vec![BudgetReportEntry {
+                budget_id: uuid::Uuid::new_v4(),
+                category_id: uuid::Uuid::new_v4(),
+                currency: Currency::USD,
+                budgeted: Decimal::from(100),
+                carried_over: Decimal::from(0),
+                available: Decimal::from(100),
+                spent: Decimal::from(120),
+                remaining: Decimal::from(-20),
+                over_threshold: true,
+            }],
+        };
+        let report2 = report.clone();
+        let (mut parts, _) = axum::http::Request::builder()
+            .uri("/?month=2023-01-01")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let query = ValidatedQuery::<BudgetReportQuery>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        let mut budgets = MockBudgetUseCase::new();
+        budgets
+            .expect_get_budget_report()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(month),
+                predicate::eq(100),
+            )
+            .return_once(|_, _, _| Ok(report));
+
+        let state = AppState {
+            budgets: Arc::new(budgets),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response =
+            super::get_budget_report(axum::extract::State(state), Claims { sub: user_id }, query)
+                .await
+                .unwrap()
+                .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<BudgetReport>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, report2);
+    }
+
+    #[tokio::test]
+    async fn delete_budget_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let budget_id = uuid::Uuid::new_v4();
+        let budget = Budget {
+            id: budget_id,
+            user_id,
+            category_id: uuid::Uuid::new_v4(),
+            month: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            amount: Decimal::from(300),
+            currency: Currency::USD,
+            rollover: false,
+        };
+
+        let mut budgets = MockBudgetUseCase::new();
+        budgets
+            .expect_delete_budget()
+            .with(predicate::eq(user_id), predicate::eq(budget_id))
+            .return_once(|_, _| Ok(budget));
+
+        let state = AppState {
+            budgets: Arc::new(budgets),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::delete_budget(
+            axum::extract::State(state),
+            axum::extract::Path(budget_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+}
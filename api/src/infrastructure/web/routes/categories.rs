@@ -0,0 +1,381 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::infrastructure::web::middleware::{ValidatedJson, ValidatedQuery};
+use crate::infrastructure::web::State as AppState;
+use crate::{domain::entities::auth::Claims, domain::error::Error};
+
+#[derive(Deserialize, Validate)]
+struct CategoryBody {
+    #[validate(length(min = 1, max = 64))]
+    name: String,
+    parent_id: Option<Uuid>,
+    #[validate(custom = "validate_color")]
+    color: Option<String>,
+    #[validate(length(min = 1, max = 32))]
+    icon: Option<String>,
+}
+
+fn validate_color(color: &str) -> Result<(), ValidationError> {
+    if color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+    {
+        Ok(())
+    } else {
+        Err(ValidationError::new("color"))
+    }
+}
+
+#[derive(Deserialize, Validate)]
+struct SuggestionQuery {
+    #[validate(length(min = 1, max = 64))]
+    title: String,
+}
+
+async fn get_categories(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let categories = state.categories.get_categories(claims.sub).await?;
+    Ok((StatusCode::OK, Json(categories)))
+}
+
+async fn get_category_suggestion(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<SuggestionQuery>,
+) -> Result<impl IntoResponse, Error> {
+    let suggestion = state
+        .categories
+        .suggest_category(claims.sub, &query.title)
+        .await?;
+    Ok((StatusCode::OK, Json(suggestion)))
+}
+
+async fn post_category(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<CategoryBody>,
+) -> Result<impl IntoResponse, Error> {
+    let category = state
+        .categories
+        .create_category(
+            claims.sub,
+            &payload.name,
+            payload.parent_id,
+            payload.color,
+            payload.icon,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(category)))
+}
+
+async fn put_category(
+    State(state): State<AppState>,
+    Path(category_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<CategoryBody>,
+) -> Result<impl IntoResponse, Error> {
+    let category = state
+        .categories
+        .update_category(
+            claims.sub,
+            category_id,
+            &payload.name,
+            payload.parent_id,
+            payload.color,
+            payload.icon,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(category)))
+}
+
+async fn delete_category(
+    State(state): State<AppState>,
+    Path(category_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .categories
+        .delete_category(claims.sub, category_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/categories", get(get_categories))
+        .route("/categories", post(post_category))
+        .route("/categories/suggestion", get(get_category_suggestion))
+        .route("/categories/:category_id", put(put_category))
+        .route("/categories/:category_id", delete(delete_category))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::categories::MockCategoryUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        domain::entities::categories::{Category, CategorySuggestion},
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn get_categories_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let categories = vec![Category {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            parent_id: None,
+            name: "Bills".to_string(),
+            color: Some("#ff0000".to_string()),
+            icon: None,
+        }];
+        let categories2 = categories.clone();
+
+        let mut categories_use_case = MockCategoryUseCase::new();
+        categories_use_case
+            .expect_get_categories()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(categories));
+
+        let state = AppState {
+            categories: Arc::new(categories_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::get_categories(axum::extract::State(state), Claims { sub: user_id })
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Vec<Category>>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, categories2);
+    }
+
+    #[tokio::test]
+    async fn get_category_suggestion_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let suggestion = CategorySuggestion {
+            category_id: uuid::Uuid::new_v4(),
+            confidence: 0.75,
+        };
+        let suggestion2 = suggestion.clone();
+
+        let mut categories_use_case = MockCategoryUseCase::new();
+        categories_use_case
+            .expect_suggest_category()
+            .with(predicate::eq(user_id), predicate::eq("Supermarket"))
+            .return_once(|_, _| Ok(Some(suggestion)));
+
+        let state = AppState {
+            categories: Arc::new(categories_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::get_category_suggestion(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedQuery(SuggestionQuery {
+                title: "Supermarket".to_string(),
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Option<CategorySuggestion>>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, Some(suggestion2));
+    }
+
+    #[tokio::test]
+    async fn post_category_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let parent_id = uuid::Uuid::new_v4();
+        let category = Category {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            parent_id: Some(parent_id),
+            name: "Rent".to_string(),
+            color: Some("#00ff00".to_string()),
+            icon: Some("house".to_string()),
+        };
+        let category2 = category.clone();
+
+        let mut categories_use_case = MockCategoryUseCase::new();
+        categories_use_case
+            .expect_create_category()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq("Rent"),
+                predicate::eq(Some(parent_id)),
+                predicate::eq(Some("#00ff00".to_string())),
+                predicate::eq(Some("house".to_string())),
+            )
+            .return_once(|_, _, _, _, _| Ok(category));
+
+        let state = AppState {
+            categories: Arc::new(categories_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_category(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(CategoryBody {
+                name: "Rent".to_string(),
+                parent_id: Some(parent_id),
+                color: Some("#00ff00".to_string()),
+                icon: Some("house".to_string()),
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+
+        let body = serde_json::from_slice::<Category>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, category2);
+    }
+
+    #[tokio::test]
+    async fn put_category_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let category = Category {
+            id: category_id,
+            user_id,
+            parent_id: None,
+            name: "Rent".to_string(),
+            color: None,
+            icon: None,
+        };
+        let category2 = category.clone();
+
+        let mut categories_use_case = MockCategoryUseCase::new();
+        categories_use_case
+            .expect_update_category()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(category_id),
+                predicate::eq("Rent"),
+                predicate::eq(None),
+                predicate::eq(None),
+                predicate::eq(None),
+            )
+            .return_once(|_, _, _, _, _, _| Ok(category));
+
+        let state = AppState {
+            categories: Arc::new(categories_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::put_category(
+            axum::extract::State(state),
+            axum::extract::Path(category_id),
+            Claims { sub: user_id },
+            ValidatedJson(CategoryBody {
+                name: "Rent".to_string(),
+                parent_id: None,
+                color: None,
+                icon: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Category>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, category2);
+    }
+
+    #[tokio::test]
+    async fn delete_category_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let category = Category {
+            id: category_id,
+            user_id,
+            parent_id: None,
+            name: "Rent".to_string(),
+            color: None,
+            icon: None,
+        };
+
+        let mut categories_use_case = MockCategoryUseCase::new();
+        categories_use_case
+            .expect_delete_category()
+            .with(predicate::eq(user_id), predicate::eq(category_id))
+            .return_once(|_, _| Ok(category));
+
+        let state = AppState {
+            categories: Arc::new(categories_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::delete_category(
+            axum::extract::State(state),
+            axum::extract::Path(category_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn category_body_invalid_color() {
+        for color in ["red", "#ff00", "#gg0000"] {
+            let body = CategoryBody {
+                name: "Rent".to_string(),
+                parent_id: None,
+                color: Some(color.to_string()),
+                icon: None,
+            };
+            assert!(body.validate().is_err());
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::ValidatedJson;
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{auth::Claims, currencies::Currency},
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct ExchangeRateBody {
+    date: NaiveDate,
+    from: Currency,
+    to: Currency,
+    rate: Decimal,
+}
+
+async fn get_exchange_rates(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let rates = state.exchange_rates.get_exchange_rates(claims.sub).await?;
+    Ok((StatusCode::OK, Json(rates)))
+}
+
+async fn put_exchange_rate(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<ExchangeRateBody>,
+) -> Result<impl IntoResponse, Error> {
+    let rate = state
+        .exchange_rates
+        .set_exchange_rate(
+            claims.sub,
+            payload.date,
+            payload.from,
+            payload.to,
+            payload.rate,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(rate)))
+}
+
+async fn import_exchange_rates(
+    State(state): State<AppState>,
+    claims: Claims,
+    body: String,
+) -> Result<impl IntoResponse, Error> {
+    let rates = state
+        .exchange_rates
+        .import_exchange_rates(claims.sub, &body)
+        .await?;
+    Ok((StatusCode::OK, Json(rates)))
+}
+
+async fn delete_exchange_rate(
+    State(state): State<AppState>,
+    Path(rate_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .exchange_rates
+        .delete_exchange_rate(claims.sub, rate_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/exchange-rates", get(get_exchange_rates))
+        .route("/exchange-rates", put(put_exchange_rate))
+        .route("/exchange-rates/import", post(import_exchange_rates))
+        .route("/exchange-rates/:rate_id", delete(delete_exchange_rate))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::exchange_rates::MockExchangeRateUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        domain::entities::exchange_rates::ExchangeRate, infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn put_exchange_rate_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        let rate = ExchangeRate {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            date,
+            from: Currency::USD,
+            to: Currency::EUR,
+            rate: Decimal::new(92, 2),
+        };
+        let rate2 = rate.clone();
+
+        let mut exchange_rates_use_case = MockExchangeRateUseCase::new();
+        exchange_rates_use_case
+            .expect_set_exchange_rate()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(date),
+                predicate::eq(Currency::USD),
+                predicate::eq(Currency::EUR),
+                predicate::eq(Decimal::new(92, 2)),
+            )
+            .return_once(|_, _, _, _, _| Ok(rate));
+
+        let state = AppState {
+            exchange_rates: Arc::new(exchange_rates_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::put_exchange_rate(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(ExchangeRateBody {
+                date,
+                from: Currency::USD,
+                to: Currency::EUR,
+                rate: Decimal::new(92, 2),
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<ExchangeRate>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, rate2);
+    }
+
+    #[tokio::test]
+    async fn import_exchange_rates_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let csv = "date,from,to,rate\n2023-01-31,USD,EUR,0.92\n".to_string();
+
+        let mut exchange_rates_use_case = MockExchangeRateUseCase::new();
+        exchange_rates_use_case
+            .expect_import_exchange_rates()
+            .with(predicate::eq(user_id), predicate::eq(csv.clone()))
+            .return_once(|_, _| Ok(vec![]));
+
+        let state = AppState {
+            exchange_rates: Arc::new(exchange_rates_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response =
+            super::import_exchange_rates(axum::extract::State(state), Claims { sub: user_id }, csv)
+                .await
+                .unwrap()
+                .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn delete_exchange_rate_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let rate_id = uuid::Uuid::new_v4();
+        let rate = ExchangeRate {
+            id: rate_id,
+            user_id,
+            date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            from: Currency::USD,
+            to: Currency::EUR,
+            rate: Decimal::new(92, 2),
+        };
+
+        let mut exchange_rates_use_case = MockExchangeRateUseCase::new();
+        exchange_rates_use_case
+            .expect_delete_exchange_rate()
+            .with(predicate::eq(user_id), predicate::eq(rate_id))
+            .return_once(|_, _| Ok(rate));
+
+        let state = AppState {
+            exchange_rates: Arc::new(exchange_rates_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::delete_exchange_rate(
+            axum::extract::State(state),
+            axum::extract::Path(rate_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+}
@@ -0,0 +1,235 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::ValidatedJson;
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{auth::Claims, currencies::Currency},
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct GoalBody {
+    #[validate(length(min = 3, max = 64))]
+    name: String,
+    target_amount: Decimal,
+    currency: Currency,
+    deadline: NaiveDate,
+    #[serde(default)]
+    #[validate(length(max = 32))]
+    account_ids: Vec<Uuid>,
+}
+
+async fn get_goals(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let goals = state.goals.get_goals(claims.sub).await?;
+    Ok((StatusCode::OK, Json(goals)))
+}
+
+async fn get_goal(
+    State(state): State<AppState>,
+    Path(goal_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let goal = state.goals.get_goal(claims.sub, goal_id).await?;
+    Ok((StatusCode::OK, Json(goal)))
+}
+
+async fn post_goal(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<GoalBody>,
+) -> Result<impl IntoResponse, Error> {
+    let goal = state
+        .goals
+        .create_goal(
+            claims.sub,
+            &payload.name,
+            payload.target_amount,
+            payload.currency,
+            payload.deadline,
+            payload.account_ids,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(goal)))
+}
+
+async fn put_goal(
+    State(state): State<AppState>,
+    Path(goal_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<GoalBody>,
+) -> Result<impl IntoResponse, Error> {
+    let goal = state
+        .goals
+        .update_goal(
+            claims.sub,
+            goal_id,
+            &payload.name,
+            payload.target_amount,
+            payload.currency,
+            payload.deadline,
+            payload.account_ids,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(goal)))
+}
+
+async fn delete_goal(
+    State(state): State<AppState>,
+    Path(goal_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state.goals.delete_goal(claims.sub, goal_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/goals", get(get_goals))
+        .route("/goals", post(post_goal))
+        .route("/goals/:goal_id", get(get_goal))
+        .route("/goals/:goal_id", put(put_goal))
+        .route("/goals/:goal_id", delete(delete_goal))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::goals::MockGoalUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        domain::entities::goals::{Goal, GoalProgress, GoalStatus},
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn get_goal_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let goal_id = uuid::Uuid::new_v4();
+        let progress = GoalProgress {
+            goal: Goal {
+                id: goal_id,
+                user_id,
+                name: "Emergency fund".to_string(),
+                target_amount: Decimal::from(5000),
+                currency: Currency::EUR,
+                start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                deadline: NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                account_ids: vec![uuid::Uuid::new_v4()],
+            },
+            saved: Decimal::from(4400),
+            remaining: Decimal::from(600),
+            percent_complete: Decimal::from(88),
+            months_left: 3,
+            monthly_contribution: Decimal::from(200),
+            status: GoalStatus::OnTrack,
+        };
+        let progress2 = progress.clone();
+
+        let mut goals_use_case = MockGoalUseCase::new();
+        goals_use_case
+            .expect_get_goal()
+            .with(predicate::eq(user_id), predicate::eq(goal_id))
+            .return_once(|_, _| Ok(progress));
+
+        let state = AppState {
+            goals: Arc::new(goals_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::get_goal(
+            axum::extract::State(state),
+            axum::extract::Path(goal_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["status"], json!("ON_TRACK"));
+        assert_eq!(body, json!(progress2));
+    }
+
+    #[tokio::test]
+    async fn post_goal_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let deadline = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+        let payload: GoalBody = serde_json::from_value(json!({
+            "name": "Emergency fund",
+            "target_amount": "5000",
+            "currency": "EUR",
+            "deadline": "2026-12-31",
+            "account_ids": [account_id],
+        }))
+        .unwrap();
+        let goal = Goal {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "Emergency fund".to_string(),
+            target_amount: Decimal::from(5000),
+            currency: Currency::EUR,
+            start_date: NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+            deadline,
+            account_ids: vec![account_id],
+        };
+        let goal2 = goal.clone();
+
+        let mut goals_use_case = MockGoalUseCase::new();
+        goals_use_case
+            .expect_create_goal()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq("Emergency fund"),
+                predicate::eq(Decimal::from(5000)),
+                predicate::eq(Currency::EUR),
+                predicate::eq(deadline),
+                predicate::eq(vec![account_id]),
+            )
+            .return_once(|_, _, _, _, _, _| Ok(goal));
+
+        let state = AppState {
+            goals: Arc::new(goals_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_goal(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(payload),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!(goal2));
+    }
+}
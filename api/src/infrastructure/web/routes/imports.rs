@@ -0,0 +1,375 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::{ValidatedJson, ValidatedQuery};
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{auth::Claims, imports::CsvFormat, statements::StatementFormat},
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct ImportMappingBody {
+    #[validate(length(min = 3, max = 64))]
+    name: String,
+    #[serde(flatten)]
+    format: CsvFormat,
+}
+
+#[derive(Deserialize, Validate)]
+struct ImportQuery {
+    mapping_id: Uuid,
+}
+
+#[derive(Deserialize, Validate)]
+struct StatementQuery {
+    format: StatementFormat,
+}
+
+async fn get_import_mappings(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let mappings = state.imports.get_import_mappings(claims.sub).await?;
+    Ok((StatusCode::OK, Json(mappings)))
+}
+
+async fn post_import_mapping(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<ImportMappingBody>,
+) -> Result<impl IntoResponse, Error> {
+    let mapping = state
+        .imports
+        .create_import_mapping(claims.sub, &payload.name, payload.format)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(mapping)))
+}
+
+async fn delete_import_mapping(
+    State(state): State<AppState>,
+    Path(mapping_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .imports
+        .delete_import_mapping(claims.sub, mapping_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn preview_import(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<ImportQuery>,
+    body: String,
+) -> Result<impl IntoResponse, Error> {
+    let rows = state
+        .imports
+        .preview_import(claims.sub, account_id, query.mapping_id, &body)
+        .await?;
+
+    Ok((StatusCode::OK, Json(rows)))
+}
+
+async fn import_movements(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<ImportQuery>,
+    body: String,
+) -> Result<impl IntoResponse, Error> {
+    let result = state
+        .imports
+        .import_movements(claims.sub, account_id, query.mapping_id, &body)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+async fn import_statement(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<StatementQuery>,
+    body: String,
+) -> Result<impl IntoResponse, Error> {
+    let result = state
+        .imports
+        .import_statement(claims.sub, account_id, query.format, &body)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/accounts/:account_id/import", post(import_movements))
+        .route("/accounts/:account_id/import/preview", post(preview_import))
+        .route(
+            "/accounts/:account_id/import/statement",
+            post(import_statement),
+        )
+        .route("/import-mappings", get(get_import_mappings))
+        .route("/import-mappings", post(post_import_mapping))
+        .route(
+            "/import-mappings/:mapping_id",
+            delete(delete_import_mapping),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use chrono::NaiveDate;
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::imports::MockImportUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        domain::entities::imports::{ImportMapping, ImportResult, ImportRow},
+        infrastructure::web::get_mock_state,
+    };
+
+    fn get_csv_format() -> CsvFormat {
+        CsvFormat {
+            delimiter: ';',
+            date_column: "Date".to_string(),
+            description_column: "Description".to_string(),
+            amount_column: Some("Amount".to_string()),
+            debit_column: None,
+            credit_column: None,
+            date_format: "%d/%m/%Y".to_string(),
+            decimal_separator: ',',
+        }
+    }
+
+    #[tokio::test]
+    async fn post_import_mapping_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let mapping = ImportMapping {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "bank".to_string(),
+            format: get_csv_format(),
+        };
+        let mapping2 = mapping.clone();
+
+        let mut imports = MockImportUseCase::new();
+        imports
+            .expect_create_import_mapping()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq("bank".to_string()),
+                predicate::eq(get_csv_format()),
+            )
+            .return_once(|_, _, _| Ok(mapping));
+
+        let state = AppState {
+            imports: Arc::new(imports),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_import_mapping(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(ImportMappingBody {
+                name: "bank".to_string(),
+                format: get_csv_format(),
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!(mapping2));
+    }
+
+    #[tokio::test]
+    async fn preview_import_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let mapping_id = uuid::Uuid::new_v4();
+        let csv = "Date;Description;Amount\n31/01/2023;Groceries;-10,50\n".to_string();
+        let rows = vec![ImportRow {
+            date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            title: "Groceries".to_string(),
+            amount: Decimal::new(-1050, 2),
+            duplicate: true,
+        }];
+        let rows2 = rows.clone();
+
+        let mut imports = MockImportUseCase::new();
+        imports
+            .expect_preview_import()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(mapping_id),
+                predicate::eq(csv.clone()),
+            )
+            .return_once(|_, _, _, _| Ok(rows));
+
+        let state = AppState {
+            imports: Arc::new(imports),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::preview_import(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedQuery(ImportQuery { mapping_id }),
+            csv,
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!(rows2));
+    }
+
+    #[tokio::test]
+    async fn import_movements_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let mapping_id = uuid::Uuid::new_v4();
+        let csv = "Date;Description;Amount\n31/01/2023;Groceries;-10,50\n".to_string();
+
+        let mut imports = MockImportUseCase::new();
+        imports
+            .expect_import_movements()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(mapping_id),
+                predicate::eq(csv.clone()),
+            )
+            .return_once(|_, _, _, _| {
+                Ok(ImportResult {
+                    movements: vec![],
+                    skipped: 1,
+                })
+            });
+
+        let state = AppState {
+            imports: Arc::new(imports),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::import_movements(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedQuery(ImportQuery { mapping_id }),
+            csv,
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!({ "movements": [], "skipped": 1 }));
+    }
+
+    #[tokio::test]
+    async fn delete_import_mapping_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let mapping_id = uuid::Uuid::new_v4();
+        let mapping = ImportMapping {
+            id: mapping_id,
+            user_id,
+            name: "bank".to_string(),
+            format: get_csv_format(),
+        };
+
+        let mut imports = MockImportUseCase::new();
+        imports
+            .expect_delete_import_mapping()
+            .with(predicate::eq(user_id), predicate::eq(mapping_id))
+            .return_once(|_, _| Ok(mapping));
+
+        let state = AppState {
+            imports: Arc::new(imports),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::delete_import_mapping(
+            axum::extract::State(state),
+            axum::extract::Path(mapping_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn import_statement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let data = "<OFX></OFX>".to_string();
+
+        let mut imports = MockImportUseCase::new();
+        imports
+            .expect_import_statement()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(StatementFormat::Camt053),
+                predicate::eq(data.clone()),
+            )
+            .return_once(|_, _, _, _| {
+                Ok(ImportResult {
+                    movements: vec![],
+                    skipped: 2,
+                })
+            });
+
+        let state = AppState {
+            imports: Arc::new(imports),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::import_statement(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedQuery(StatementQuery {
+                format: StatementFormat::Camt053,
+            }),
+            data,
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+    }
+}
@@ -0,0 +1,313 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::{ValidatedJson, ValidatedQuery};
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{
+        auth::Claims,
+        currencies::Currency,
+        investments::{CostMethod, TradeKind},
+    },
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct TradeBody {
+    date: NaiveDate,
+    kind: TradeKind,
+    symbol: String,
+    #[serde(default)]
+    quantity: Decimal,
+    price: Decimal,
+    #[serde(default)]
+    fees: Decimal,
+}
+
+#[derive(Deserialize, Validate)]
+struct PortfolioQuery {
+    #[serde(default)]
+    method: CostMethod,
+}
+
+#[derive(Deserialize, Validate)]
+struct PriceBody {
+    date: NaiveDate,
+    symbol: String,
+    currency: Currency,
+    price: Decimal,
+}
+
+async fn get_trades(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let trades = state.investments.get_trades(claims.sub, account_id).await?;
+    Ok((StatusCode::OK, Json(trades)))
+}
+
+async fn post_trade(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<TradeBody>,
+) -> Result<impl IntoResponse, Error> {
+    let trade = state
+        .investments
+        .create_trade(
+            claims.sub,
+            account_id,
+            payload.date,
+            payload.kind,
+            &payload.symbol,
+            payload.quantity,
+            payload.price,
+            payload.fees,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(trade)))
+}
+
+async fn delete_trade(
+    State(state): State<AppState>,
+    Path((account_id, trade_id)): Path<(Uuid, Uuid)>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .investments
+        .delete_trade(claims.sub, account_id, trade_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_portfolio(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<PortfolioQuery>,
+) -> Result<impl IntoResponse, Error> {
+    let portfolio = state
+        .investments
+        .get_portfolio(claims.sub, account_id, query.method)
+        .await?;
+
+    Ok((StatusCode::OK, Json(portfolio)))
+}
+
+async fn get_prices(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let prices = state.investments.get_prices(claims.sub).await?;
+    Ok((StatusCode::OK, Json(prices)))
+}
+
+async fn put_price(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<PriceBody>,
+) -> Result<impl IntoResponse, Error> {
+    let price = state
+        .investments
+        .set_price(
+            claims.sub,
+            payload.date,
+            &payload.symbol,
+            payload.currency,
+            payload.price,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(price)))
+}
+
+async fn import_prices(
+    State(state): State<AppState>,
+    claims: Claims,
+    body: String,
+) -> Result<impl IntoResponse, Error> {
+    let prices = state.investments.import_prices(claims.sub, &body).await?;
+    Ok((StatusCode::OK, Json(prices)))
+}
+
+async fn delete_price(
+    State(state): State<AppState>,
+    Path(price_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state.investments.delete_price(claims.sub, price_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/accounts/:account_id/trades", get(get_trades))
+        .route("/accounts/:account_id/trades", post(post_trade))
+        .route(
+            "/accounts/:account_id/trades/:trade_id",
+            delete(delete_trade),
+        )
+        .route("/accounts/:account_id/portfolio", get(get_portfolio))
+        .route("/prices", get(get_prices))
+        .route("/prices", put(put_price))
+        .route("/prices/import", post(import_prices))
+        .route("/prices/:price_id", delete(delete_price))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::investments::MockInvestmentUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        domain::entities::investments::{Holding, Portfolio, Trade},
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn post_trade_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let date = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let payload: TradeBody = serde_json::from_value(json!({
+            "date": "2026-10-01",
+            "kind": "DIVIDEND",
+            "symbol": "AAPL",
+            "price": "12.5",
+        }))
+        .unwrap();
+        let trade = Trade {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            movement_id: uuid::Uuid::new_v4(),
+            date,
+            kind: TradeKind::Dividend,
+            symbol: "AAPL".to_string(),
+            quantity: Decimal::from(0),
+            price: Decimal::new(125, 1),
+            fees: Decimal::from(0),
+        };
+        let trade2 = trade.clone();
+
+        let mut investments_use_case = MockInvestmentUseCase::new();
+        investments_use_case
+            .expect_create_trade()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(date),
+                predicate::eq(TradeKind::Dividend),
+                predicate::eq("AAPL"),
+                predicate::eq(Decimal::from(0)),
+                predicate::eq(Decimal::new(125, 1)),
+                predicate::eq(Decimal::from(0)),
+            )
+            .return_once(|_, _, _, _, _, _, _, _| Ok(trade));
+
+        let state = AppState {
+            investments: Arc::new(investments_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_trade(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedJson(payload),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!(trade2));
+    }
+
+    #[tokio::test]
+    async fn get_portfolio_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let portfolio = Portfolio {
+            account_id,
+            currency: Currency::USD,
+            method: CostMethod::Average,
+            cash: Decimal::from(500),
+            market_value: Decimal::from(1200),
+            total: Decimal::from(1700),
+            cost_basis: Decimal::from(1000),
+            unrealised_gain: Decimal::from(200),
+            realised_gain: Decimal::from(0),
+            dividends: Decimal::from(0),
+            holdings: vec![Holding {
+                symbol: "AAPL".to_string(),
+                quantity: Decimal::from(10),
+                cost_basis: Decimal::from(1000),
+                price: Some(Decimal::from(120)),
+                price_date: Some(NaiveDate::from_ymd_opt(2026, 10, 1).unwrap()),
+                market_value: Decimal::from(1200),
+                unrealised_gain: Decimal::from(200),
+                realised_gain: Decimal::from(0),
+                dividends: Decimal::from(0),
+            }],
+        };
+        let portfolio2 = portfolio.clone();
+
+        let mut investments_use_case = MockInvestmentUseCase::new();
+        investments_use_case
+            .expect_get_portfolio()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(CostMethod::Average),
+            )
+            .return_once(|_, _, _| Ok(portfolio));
+
+        let state = AppState {
+            investments: Arc::new(investments_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::get_portfolio(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedQuery(PortfolioQuery {
+                method: CostMethod::Average,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Portfolio>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, portfolio2);
+    }
+}
@@ -1,2 +1,33 @@
+use serde::{de::IntoDeserializer, Deserialize, Deserializer};
+
+pub mod analytics;
 pub mod auth;
+pub mod budgets;
+pub mod categories;
+pub mod exchange_rates;
+pub mod goals;
+pub mod imports;
+pub mod investments;
 pub mod profile;
+pub mod reconciliations;
+pub mod recurring_movements;
+pub mod rules;
+pub mod takeout;
+pub mod transfers;
+
+// Query strings can't carry sequences, lists are sent as comma separated values (?categories=BILLS,INCOME)
+fn comma_separated<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    value
+        .map(|value| {
+            value
+                .split(',')
+                .map(|item| T::deserialize(item.trim().to_string().into_deserializer()))
+                .collect()
+        })
+        .transpose()
+}
@@ -1,21 +1,26 @@
 use axum::{
+    body::StreamBody,
     extract::{Path, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
-    routing::{get, post},
+    routing::{delete, get, post, put},
     Json, Router,
 };
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::infrastructure::web::middleware::ValidatedJson;
+use super::comma_separated;
+use crate::infrastructure::web::middleware::{ValidatedJson, ValidatedQuery};
 use crate::infrastructure::web::State as AppState;
 use crate::{
     domain::entities::{
-        accounts::{Account, CategoryType, CurrencyType},
+        accounts::{Account, AccountKind, AccountTerms, MovementCursor, MovementFilter, SplitLine},
         auth::Claims,
+        currencies::Currency,
+        exports::ExportFormat,
     },
     domain::error::Error,
 };
@@ -29,15 +34,100 @@ struct ProfileResponse {
 struct AccountBody {
     #[validate(length(min = 3, max = 64))]
     name: String,
-    currency: CurrencyType,
+    currency: Currency,
+    opening_balance: Option<Decimal>,
+    opening_date: Option<NaiveDate>,
+    #[serde(default)]
+    kind: AccountKind,
+    #[serde(default)]
+    terms: AccountTerms,
+}
+
+#[derive(Deserialize, Validate)]
+struct AccountUpdateBody {
+    #[validate(length(min = 3, max = 64))]
+    name: String,
+    archived: bool,
+    terms: Option<AccountTerms>,
 }
 
 #[derive(Deserialize, Validate)]
 struct MovmentBody {
     #[validate(length(min = 3, max = 64))]
     title: String,
-    category: CategoryType,
+    category_id: Uuid,
+    amount: Decimal,
+    #[validate(length(max = 2000))]
+    note: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+// Serialize is needed to report the errors of the nested validation
+#[derive(Serialize, Deserialize, Validate)]
+struct SplitLineBody {
+    category_id: Uuid,
+    amount: Decimal,
+    #[validate(length(min = 1, max = 64))]
+    memo: Option<String>,
+}
+
+impl From<SplitLineBody> for SplitLine {
+    fn from(line: SplitLineBody) -> Self {
+        SplitLine {
+            category_id: line.category_id,
+            amount: line.amount,
+            memo: line.memo,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate)]
+struct SplitMovementBody {
+    #[validate(length(min = 3, max = 64))]
+    title: String,
     amount: Decimal,
+    #[validate(length(min = 2, max = 50))]
+    #[validate]
+    splits: Vec<SplitLineBody>,
+}
+
+#[derive(Deserialize, Validate)]
+struct SplitsBody {
+    #[validate(length(min = 2, max = 50))]
+    #[validate]
+    splits: Vec<SplitLineBody>,
+}
+
+#[derive(Deserialize, Validate, Default)]
+struct MovementsQuery {
+    cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<u32>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    category_ids: Option<Vec<Uuid>>,
+    min_amount: Option<Decimal>,
+    max_amount: Option<Decimal>,
+    #[validate(length(min = 1, max = 64))]
+    title: Option<String>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    tags: Option<Vec<String>>,
+    #[serde(default)]
+    exclude_transfers: bool,
+}
+
+#[derive(Deserialize, Validate)]
+struct PreferredCurrencyBody {
+    currency: Currency,
+}
+
+#[derive(Deserialize, Validate)]
+struct ExportQuery {
+    format: ExportFormat,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
 }
 
 async fn get_profile(
@@ -48,6 +138,27 @@ async fn get_profile(
     Ok((StatusCode::OK, Json(ProfileResponse { accounts })))
 }
 
+async fn get_net_worth(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let net_worth = state.profile.get_net_worth(claims.sub).await?;
+    Ok((StatusCode::OK, Json(net_worth)))
+}
+
+async fn put_preferred_currency(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<PreferredCurrencyBody>,
+) -> Result<impl IntoResponse, Error> {
+    let user = state
+        .profile
+        .set_preferred_currency(claims.sub, payload.currency)
+        .await?;
+
+    Ok((StatusCode::OK, Json(user)))
+}
+
 async fn get_account(
     State(state): State<AppState>,
     Path(account_id): Path<Uuid>,
@@ -64,20 +175,110 @@ async fn post_account(
 ) -> Result<impl IntoResponse, Error> {
     let account = state
         .profile
-        .create_account(claims.sub, &payload.name, payload.currency)
+        .create_account(
+            claims.sub,
+            &payload.name,
+            payload.currency,
+            payload.opening_balance.unwrap_or_default(),
+            payload.opening_date,
+            payload.kind,
+            payload.terms,
+        )
         .await?;
 
     Ok((StatusCode::CREATED, Json(account)))
 }
 
+async fn put_account(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<AccountUpdateBody>,
+) -> Result<impl IntoResponse, Error> {
+    let account = state
+        .profile
+        .update_account(
+            claims.sub,
+            account_id,
+            &payload.name,
+            payload.archived,
+            payload.terms,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(account)))
+}
+
+async fn delete_account(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state.profile.delete_account(claims.sub, account_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_amortisation_schedule(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let schedule = state
+        .profile
+        .get_amortisation_schedule(claims.sub, account_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(schedule)))
+}
+
+async fn get_card_statement(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let statement = state
+        .profile
+        .get_card_statement(claims.sub, account_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(statement)))
+}
+
 async fn get_movements(
     State(state): State<AppState>,
     Path(account_id): Path<Uuid>,
     claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<MovementsQuery>,
 ) -> Result<impl IntoResponse, Error> {
-    let movements = state.profile.get_movements(claims.sub, account_id).await?;
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(str::parse::<MovementCursor>)
+        .transpose()?;
+    let filter = MovementFilter {
+        from: query.from,
+        to: query.to,
+        category_ids: query.category_ids,
+        min_amount: query.min_amount,
+        max_amount: query.max_amount,
+        title: query.title,
+        tags: query.tags,
+        exclude_transfers: query.exclude_transfers,
+    };
 
-    Ok((StatusCode::OK, Json(movements)))
+    let page = state
+        .profile
+        .get_movements(
+            claims.sub,
+            account_id,
+            filter,
+            cursor,
+            query.limit.unwrap_or(50),
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(page)))
 }
 
 async fn post_movement(
@@ -92,26 +293,166 @@ async fn post_movement(
             claims.sub,
             account_id,
             &payload.title,
-            payload.category,
+            payload.category_id,
             payload.amount,
+            payload.note,
+            payload.tags,
         )
         .await?;
 
     Ok((StatusCode::CREATED, Json(movement)))
 }
 
+async fn put_movement(
+    State(state): State<AppState>,
+    Path((account_id, movement_id)): Path<(Uuid, Uuid)>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<MovmentBody>,
+) -> Result<impl IntoResponse, Error> {
+    let movement = state
+        .profile
+        .update_movement(
+            claims.sub,
+            account_id,
+            movement_id,
+            &payload.title,
+            payload.category_id,
+            payload.amount,
+            payload.note,
+            payload.tags,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(movement)))
+}
+
+async fn delete_movement(
+    State(state): State<AppState>,
+    Path((account_id, movement_id)): Path<(Uuid, Uuid)>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .profile
+        .delete_movement(claims.sub, account_id, movement_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn post_split_movement(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<SplitMovementBody>,
+) -> Result<impl IntoResponse, Error> {
+    let split_movement = state
+        .profile
+        .create_split_movement(
+            claims.sub,
+            account_id,
+            &payload.title,
+            payload.amount,
+            payload.splits.into_iter().map(SplitLine::from).collect(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(split_movement)))
+}
+
+async fn put_splits(
+    State(state): State<AppState>,
+    Path((account_id, movement_id)): Path<(Uuid, Uuid)>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<SplitsBody>,
+) -> Result<impl IntoResponse, Error> {
+    let split_movement = state
+        .profile
+        .split_movement(
+            claims.sub,
+            account_id,
+            movement_id,
+            payload.splits.into_iter().map(SplitLine::from).collect(),
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(split_movement)))
+}
+
+async fn get_tags(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let tags = state.profile.get_tags(claims.sub).await?;
+    Ok((StatusCode::OK, Json(tags)))
+}
+
+async fn export_movements(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedQuery(query): ValidatedQuery<ExportQuery>,
+) -> Result<impl IntoResponse, Error> {
+    let stream = state
+        .profile
+        .export_movements(claims.sub, account_id, query.format, query.from, query.to)
+        .await?;
+    let headers = [
+        (
+            header::CONTENT_TYPE,
+            query.format.content_type().to_string(),
+        ),
+        (
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"movements-{account_id}.{}\"",
+                query.format.extension()
+            ),
+        ),
+    ];
+
+    Ok((StatusCode::OK, headers, StreamBody::new(stream)))
+}
+
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(get_profile))
+        .route("/net-worth", get(get_net_worth))
+        .route("/preferred-currency", put(put_preferred_currency))
         .route("/accounts", post(post_account))
         .route("/accounts/:account_id", get(get_account))
+        .route("/accounts/:account_id", put(put_account))
+        .route("/accounts/:account_id", delete(delete_account))
         .route("/accounts/:account_id/movements", get(get_movements))
         .route("/accounts/:account_id/movements", post(post_movement))
+        .route(
+            "/accounts/:account_id/movements/:movement_id",
+            put(put_movement),
+        )
+        .route(
+            "/accounts/:account_id/movements/:movement_id",
+            delete(delete_movement),
+        )
+        .route(
+            "/accounts/:account_id/movements/split",
+            post(post_split_movement),
+        )
+        .route(
+            "/accounts/:account_id/movements/:movement_id/splits",
+            put(put_splits),
+        )
+        .route("/accounts/:account_id/export", get(export_movements))
+        .route(
+            "/accounts/:account_id/amortisation",
+            get(get_amortisation_schedule),
+        )
+        .route("/accounts/:account_id/statement", get(get_card_statement))
+        .route("/tags", get(get_tags))
 }
 
 #[cfg(test)]
 mod tests {
-    use axum::response::IntoResponse;
+    use axum::{extract::FromRequestParts, response::IntoResponse};
+    use futures::stream::{self, StreamExt};
     use mockall::predicate;
     use rust_decimal::Decimal;
     use serde_json::{json, Value};
@@ -120,8 +461,13 @@ mod tests {
     use crate::{
         application::use_cases::auth::MockAuthUseCase,
         application::use_cases::profile::MockProfileUseCase,
-        domain::entities::accounts::{Account, Movement},
+        domain::entities::accounts::{
+            Account, Movement, MovementPage, MovementSplit, MovementStatus, SplitMovement, Tag,
+        },
+        domain::entities::accounts::{NetWorth, NetWorthEntry},
         domain::entities::auth::Claims,
+        domain::entities::credit::AmortisationSchedule,
+        domain::entities::users::User,
         infrastructure::web::get_mock_state,
     };
 
@@ -130,10 +476,15 @@ mod tests {
         let user_id = uuid::Uuid::new_v4();
         let accounts = vec![Account {
             balance: Decimal::from(0),
-            currency: CurrencyType::Usd,
+            currency: Currency::USD,
+            archived: false,
             id: uuid::Uuid::new_v4(),
             user_id,
             name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
         }];
         let accounts2 = accounts.clone();
 
@@ -164,13 +515,18 @@ mod tests {
     async fn post_account_successful() {
         let user_id = uuid::Uuid::new_v4();
         let name = "name".to_string();
-        let currency = CurrencyType::Usd;
+        let currency = Currency::USD;
         let account = Account {
             balance: Decimal::from(0),
-            currency: currency.clone(),
+            currency,
+            archived: false,
             id: uuid::Uuid::new_v4(),
             user_id,
             name: name.clone(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
         };
         let account2 = account.clone();
 
@@ -180,16 +536,27 @@ mod tests {
             .with(
                 predicate::eq(user_id),
                 predicate::eq(name.clone()),
-                predicate::eq(currency.clone()),
+                predicate::eq(currency),
+                predicate::eq(Decimal::from(0)),
+                predicate::eq(None),
+                predicate::eq(AccountKind::Checking),
+                predicate::eq(AccountTerms::default()),
             )
-            .return_once(|_, _, _| Ok(account));
+            .return_once(|_, _, _, _, _, _, _| Ok(account));
 
         let state = get_mock_state(MockAuthUseCase::new(), profile);
 
         let response = super::post_account(
             axum::extract::State(state),
             Claims { sub: user_id },
-            ValidatedJson(AccountBody { currency, name }),
+            ValidatedJson(AccountBody {
+                currency,
+                name,
+                opening_balance: None,
+                opening_date: None,
+                kind: AccountKind::Checking,
+                terms: AccountTerms::default(),
+            }),
         )
         .await
         .unwrap()
@@ -211,10 +578,15 @@ mod tests {
         let account_id = uuid::Uuid::new_v4();
         let account = Account {
             balance: Decimal::from(0),
-            currency: CurrencyType::Usd,
+            currency: Currency::USD,
+            archived: false,
             id: account_id,
             user_id,
             name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
         };
         let account2 = account.clone();
 
@@ -245,21 +617,121 @@ mod tests {
         assert_eq!(body, account2);
     }
 
+    #[tokio::test]
+    async fn put_account_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let name = "name".to_string();
+        let account = Account {
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: true,
+            id: account_id,
+            user_id,
+            name: name.clone(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+        let account2 = account.clone();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_update_account()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(name.clone()),
+                predicate::eq(true),
+                predicate::eq(None),
+            )
+            .return_once(|_, _, _, _, _| Ok(account));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::put_account(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedJson(AccountUpdateBody {
+                name,
+                archived: true,
+                terms: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Account>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, account2);
+    }
+
+    #[tokio::test]
+    async fn delete_account_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let account = Account {
+            balance: Decimal::from(0),
+            currency: Currency::USD,
+            archived: false,
+            id: account_id,
+            user_id,
+            name: "name".to_string(),
+            opening_balance: Decimal::from(0),
+            opening_date: None,
+            kind: AccountKind::Checking,
+            terms: AccountTerms::default(),
+        };
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_delete_account()
+            .with(predicate::eq(user_id), predicate::eq(account_id))
+            .return_once(|_, _| Ok(account));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::delete_account(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+
     #[tokio::test]
     async fn post_movement_successful() {
         let user_id = uuid::Uuid::new_v4();
         let account_id = uuid::Uuid::new_v4();
         let title = "title".to_string();
-        let category = CategoryType::Generic;
+        let category_id = uuid::Uuid::new_v4();
         let amount = Decimal::from(0);
         let movement_id = uuid::Uuid::new_v4();
         let movement = Movement {
             account_id,
             id: movement_id,
             amount,
-            category: category.clone(),
+            category_id: Some(category_id),
             timestamp: chrono::Utc::now(),
             title: title.clone(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
         };
         let movement2 = movement.clone();
 
@@ -270,10 +742,12 @@ mod tests {
                 predicate::eq(user_id),
                 predicate::eq(account_id),
                 predicate::eq(title.clone()),
-                predicate::eq(category.clone()),
+                predicate::eq(category_id),
                 predicate::eq(amount),
+                predicate::eq(Some("weekly groceries".to_string())),
+                predicate::eq(vec!["food".to_string()]),
             )
-            .return_once(|_, _, _, _, _| Ok(movement));
+            .return_once(|_, _, _, _, _, _, _| Ok(movement));
 
         let state = get_mock_state(MockAuthUseCase::new(), profile);
 
@@ -283,8 +757,10 @@ mod tests {
             Claims { sub: user_id },
             ValidatedJson(MovmentBody {
                 amount,
-                category,
+                category_id,
                 title,
+                note: Some("weekly groceries".to_string()),
+                tags: vec!["food".to_string()],
             }),
         )
         .await
@@ -309,17 +785,102 @@ mod tests {
             account_id,
             id: uuid::Uuid::new_v4(),
             amount: Decimal::from(0),
-            category: CategoryType::Generic,
+            category_id: None,
             timestamp: chrono::Utc::now(),
             title: "title".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
         }];
-        let movements2 = movements.clone();
+        let page = MovementPage {
+            movements,
+            next_cursor: None,
+            splits: vec![],
+        };
+        let page2 = page.clone();
 
         let mut profile = MockProfileUseCase::new();
         profile
             .expect_get_movements()
-            .with(predicate::eq(user_id), predicate::eq(account_id))
-            .return_once(|_, _| Ok(movements));
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(MovementFilter::default()),
+                predicate::eq(None),
+                predicate::eq(50),
+            )
+            .return_once(|_, _, _, _, _| Ok(page));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::get_movements(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedQuery(MovementsQuery::default()),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<MovementPage>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, page2);
+    }
+
+    #[tokio::test]
+    async fn get_movements_filtered() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let bills_id = uuid::Uuid::new_v4();
+        let income_id = uuid::Uuid::new_v4();
+        let cursor = MovementCursor {
+            timestamp: "2023-01-31T10:00:00Z".parse().unwrap(),
+            id: uuid::Uuid::new_v4(),
+        };
+        let (mut parts, _) = axum::http::Request::builder()
+            .uri(format!(
+                "/?cursor={cursor}&limit=10&category_ids={bills_id},{income_id}&min_amount=-10.5&title=rent&tags=food,home&exclude_transfers=true"
+            ))
+            .body(())
+            .unwrap()
+            .into_parts();
+        let query = ValidatedQuery::<MovementsQuery>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_get_movements()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(MovementFilter {
+                    category_ids: Some(vec![bills_id, income_id]),
+                    min_amount: Some(Decimal::new(-105, 1)),
+                    title: Some("rent".to_string()),
+                    tags: Some(vec!["food".to_string(), "home".to_string()]),
+                    exclude_transfers: true,
+                    ..Default::default()
+                }),
+                predicate::eq(Some(cursor)),
+                predicate::eq(10),
+            )
+            .return_once(|_, _, _, _, _| {
+                Ok(MovementPage {
+                    movements: vec![],
+                    next_cursor: None,
+                    splits: vec![],
+                })
+            });
 
         let state = get_mock_state(MockAuthUseCase::new(), profile);
 
@@ -327,18 +888,427 @@ mod tests {
             axum::extract::State(state),
             axum::extract::Path(account_id),
             Claims { sub: user_id },
+            query,
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn put_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement_id = uuid::Uuid::new_v4();
+        let title = "title".to_string();
+        let category_id = uuid::Uuid::new_v4();
+        let amount = Decimal::from(10);
+        let movement = Movement {
+            account_id,
+            id: movement_id,
+            amount,
+            category_id: Some(category_id),
+            timestamp: chrono::Utc::now(),
+            title: title.clone(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let movement2 = movement.clone();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_update_movement()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(movement_id),
+                predicate::eq(title.clone()),
+                predicate::eq(category_id),
+                predicate::eq(amount),
+                predicate::eq(None),
+                predicate::eq(vec![]),
+            )
+            .return_once(|_, _, _, _, _, _, _, _| Ok(movement));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::put_movement(
+            axum::extract::State(state),
+            axum::extract::Path((account_id, movement_id)),
+            Claims { sub: user_id },
+            ValidatedJson(MovmentBody {
+                amount,
+                category_id,
+                title,
+                note: None,
+                tags: vec![],
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Movement>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, movement2);
+    }
+
+    #[tokio::test]
+    async fn delete_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            account_id,
+            id: movement_id,
+            amount: Decimal::from(0),
+            category_id: None,
+            timestamp: chrono::Utc::now(),
+            title: "title".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_delete_movement()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(movement_id),
+            )
+            .return_once(|_, _, _| Ok(movement));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::delete_movement(
+            axum::extract::State(state),
+            axum::extract::Path((account_id, movement_id)),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn get_net_worth_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let net_worth = NetWorth {
+            currency: Currency::EUR,
+            total: Decimal::from(10),
+            rate_date: None,
+            breakdown: vec![NetWorthEntry {
+                currency: Currency::EUR,
+                balance: Decimal::from(10),
+                rate: Decimal::from(1),
+                converted: Decimal::from(10),
+            }],
+        };
+        let net_worth2 = net_worth.clone();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_get_net_worth()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(net_worth));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::get_net_worth(axum::extract::State(state), Claims { sub: user_id })
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<NetWorth>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, net_worth2);
+    }
+
+    #[tokio::test]
+    async fn put_preferred_currency_successful() {
+        let user_id = uuid::Uuid::new_v4();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_set_preferred_currency()
+            .with(predicate::eq(user_id), predicate::eq(Currency::EUR))
+            .return_once(move |_, _| {
+                Ok(User {
+                    id: user_id,
+                    email: "email".to_string(),
+                    preferred_currency: Currency::EUR,
+                })
+            });
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::put_preferred_currency(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(PreferredCurrencyBody {
+                currency: Currency::EUR,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Value>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body["preferred_currency"], json!("EUR"));
+    }
+
+    #[tokio::test]
+    async fn get_tags_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let tags = vec![Tag {
+            name: "food".to_string(),
+            count: 3,
+        }];
+        let tags2 = tags.clone();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_get_tags()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(tags));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::get_tags(axum::extract::State(state), Claims { sub: user_id })
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Vec<Tag>>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, tags2);
+    }
+
+    #[tokio::test]
+    async fn export_movements_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_export_movements()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(ExportFormat::Json),
+                predicate::eq(None),
+                predicate::eq(None),
+            )
+            .return_once(|_, _, _, _, _| {
+                Ok(stream::iter(vec![Ok("[".to_string()), Ok("]".to_string())]).boxed())
+            });
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::export_movements(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedQuery(ExportQuery {
+                format: ExportFormat::Json,
+                from: None,
+                to: None,
+            }),
         )
         .await
         .unwrap()
         .into_response();
 
         assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            format!("attachment; filename=\"movements-{account_id}.json\"")
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(body, "[]");
+    }
 
-        let body = serde_json::from_slice::<Vec<Movement>>(
+    #[tokio::test]
+    async fn get_amortisation_schedule_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let schedule = AmortisationSchedule::new(
+            account_id,
+            Currency::EUR,
+            Decimal::from(10000),
+            Decimal::from(6),
+            12,
+            NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+        );
+        let schedule2 = schedule.clone();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_get_amortisation_schedule()
+            .with(predicate::eq(user_id), predicate::eq(account_id))
+            .return_once(|_, _| Ok(schedule));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::get_amortisation_schedule(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<AmortisationSchedule>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, schedule2);
+    }
+
+    #[tokio::test]
+    async fn post_split_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            account_id,
+            id: uuid::Uuid::new_v4(),
+            amount: Decimal::from(-20),
+            category_id: None,
+            timestamp: chrono::Utc::now(),
+            title: "supermarket".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let split_movement = SplitMovement {
+            splits: vec![MovementSplit {
+                id: uuid::Uuid::new_v4(),
+                movement_id: movement.id,
+                category_id,
+                amount: Decimal::from(-20),
+                memo: None,
+            }],
+            movement,
+        };
+        let split_movement2 = split_movement.clone();
+
+        let mut profile = MockProfileUseCase::new();
+        profile
+            .expect_create_split_movement()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq("supermarket".to_string()),
+                predicate::eq(Decimal::from(-20)),
+                predicate::eq(vec![
+                    SplitLine {
+                        category_id,
+                        amount: Decimal::from(-15),
+                        memo: Some("groceries".to_string()),
+                    },
+                    SplitLine {
+                        category_id,
+                        amount: Decimal::from(-5),
+                        memo: None,
+                    },
+                ]),
+            )
+            .return_once(|_, _, _, _, _| Ok(split_movement));
+
+        let state = get_mock_state(MockAuthUseCase::new(), profile);
+
+        let response = super::post_split_movement(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedJson(SplitMovementBody {
+                title: "supermarket".to_string(),
+                amount: Decimal::from(-20),
+                splits: vec![
+                    SplitLineBody {
+                        category_id,
+                        amount: Decimal::from(-15),
+                        memo: Some("groceries".to_string()),
+                    },
+                    SplitLineBody {
+                        category_id,
+                        amount: Decimal::from(-5),
+                        memo: None,
+                    },
+                ],
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+
+        let body = serde_json::from_slice::<SplitMovement>(
             &hyper::body::to_bytes(response.into_body()).await.unwrap(),
         )
         .unwrap();
 
-        assert_eq!(body, movements2);
+        assert_eq!(body, split_movement2);
+    }
+
+    #[tokio::test]
+    async fn split_movement_body_invalid_lines() {
+        let line = |memo: Option<&str>| SplitLineBody {
+            category_id: uuid::Uuid::new_v4(),
+            amount: Decimal::from(-10),
+            memo: memo.map(str::to_string),
+        };
+        for splits in [vec![line(None)], vec![line(None), line(Some(""))]] {
+            let body = SplitMovementBody {
+                title: "supermarket".to_string(),
+                amount: Decimal::from(-20),
+                splits,
+            };
+            assert!(body.validate().is_err());
+        }
     }
 }
@@ -0,0 +1,303 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::ValidatedJson;
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{accounts::MovementStatus, auth::Claims},
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct ReconciliationBody {
+    statement_date: NaiveDate,
+    statement_balance: Decimal,
+}
+
+#[derive(Deserialize, Validate)]
+struct MovementStatusBody {
+    status: MovementStatus,
+}
+
+async fn post_reconciliation(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<ReconciliationBody>,
+) -> Result<impl IntoResponse, Error> {
+    let report = state
+        .reconciliations
+        .start_reconciliation(
+            claims.sub,
+            account_id,
+            payload.statement_date,
+            payload.statement_balance,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(report)))
+}
+
+async fn get_reconciliation(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let report = state
+        .reconciliations
+        .get_reconciliation(claims.sub, account_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(report)))
+}
+
+async fn finish_reconciliation(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let reconciliation = state
+        .reconciliations
+        .finish_reconciliation(claims.sub, account_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(reconciliation)))
+}
+
+async fn delete_reconciliation(
+    State(state): State<AppState>,
+    Path(account_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .reconciliations
+        .cancel_reconciliation(claims.sub, account_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn put_movement_status(
+    State(state): State<AppState>,
+    Path((account_id, movement_id)): Path<(Uuid, Uuid)>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<MovementStatusBody>,
+) -> Result<impl IntoResponse, Error> {
+    let movement = state
+        .reconciliations
+        .set_movement_status(claims.sub, account_id, movement_id, payload.status)
+        .await?;
+
+    Ok((StatusCode::OK, Json(movement)))
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/accounts/:account_id/movements/:movement_id/status",
+            put(put_movement_status),
+        )
+        .route(
+            "/accounts/:account_id/reconciliation",
+            post(post_reconciliation),
+        )
+        .route(
+            "/accounts/:account_id/reconciliation",
+            get(get_reconciliation),
+        )
+        .route(
+            "/accounts/:account_id/reconciliation",
+            delete(delete_reconciliation),
+        )
+        .route(
+            "/accounts/:account_id/reconciliation/finish",
+            post(finish_reconciliation),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        application::use_cases::reconciliations::MockReconciliationUseCase,
+        domain::entities::accounts::Movement,
+        domain::entities::reconciliations::{Reconciliation, ReconciliationReport},
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn post_reconciliation_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let statement_date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        let report = ReconciliationReport {
+            reconciliation: Reconciliation {
+                id: uuid::Uuid::new_v4(),
+                account_id,
+                statement_date,
+                statement_balance: Decimal::from(100),
+                finished_at: None,
+            },
+            cleared_balance: Decimal::from(90),
+            difference: Decimal::from(10),
+            movements: vec![],
+        };
+        let report2 = report.clone();
+
+        let mut reconciliations = MockReconciliationUseCase::new();
+        reconciliations
+            .expect_start_reconciliation()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(statement_date),
+                predicate::eq(Decimal::from(100)),
+            )
+            .return_once(|_, _, _, _| Ok(report));
+
+        let state = AppState {
+            reconciliations: Arc::new(reconciliations),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_reconciliation(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+            ValidatedJson(ReconciliationBody {
+                statement_date,
+                statement_balance: Decimal::from(100),
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+
+        let body = serde_json::from_slice::<ReconciliationReport>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, report2);
+    }
+
+    #[tokio::test]
+    async fn finish_reconciliation_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let reconciliation = Reconciliation {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            statement_date: NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+            statement_balance: Decimal::from(100),
+            finished_at: Some(chrono::Utc::now()),
+        };
+        let reconciliation2 = reconciliation.clone();
+
+        let mut reconciliations = MockReconciliationUseCase::new();
+        reconciliations
+            .expect_finish_reconciliation()
+            .with(predicate::eq(user_id), predicate::eq(account_id))
+            .return_once(|_, _| Ok(reconciliation));
+
+        let state = AppState {
+            reconciliations: Arc::new(reconciliations),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::finish_reconciliation(
+            axum::extract::State(state),
+            axum::extract::Path(account_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Reconciliation>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, reconciliation2);
+    }
+
+    #[tokio::test]
+    async fn put_movement_status_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            account_id,
+            id: movement_id,
+            amount: Decimal::from(10),
+            category_id: None,
+            timestamp: chrono::Utc::now(),
+            title: "title".to_string(),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Cleared,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let movement2 = movement.clone();
+
+        let mut reconciliations = MockReconciliationUseCase::new();
+        reconciliations
+            .expect_set_movement_status()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq(movement_id),
+                predicate::eq(MovementStatus::Cleared),
+            )
+            .return_once(|_, _, _, _| Ok(movement));
+
+        let state = AppState {
+            reconciliations: Arc::new(reconciliations),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::put_movement_status(
+            axum::extract::State(state),
+            axum::extract::Path((account_id, movement_id)),
+            Claims { sub: user_id },
+            ValidatedJson(MovementStatusBody {
+                status: MovementStatus::Cleared,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = serde_json::from_slice::<Movement>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, movement2);
+    }
+}
@@ -0,0 +1,300 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::ValidatedJson;
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{auth::Claims, recurring_movements::Frequency},
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct RecurringMovementBody {
+    account_id: Uuid,
+    #[validate(length(min = 3, max = 64))]
+    title: String,
+    category_id: Uuid,
+    amount: Decimal,
+    frequency: Frequency,
+    start_date: NaiveDate,
+}
+
+#[derive(Deserialize, Validate)]
+struct RecurringMovementUpdateBody {
+    paused: bool,
+}
+
+async fn get_recurring_movements(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let recurring_movements = state
+        .recurring_movements
+        .get_recurring_movements(claims.sub)
+        .await?;
+    Ok((StatusCode::OK, Json(recurring_movements)))
+}
+
+async fn post_recurring_movement(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<RecurringMovementBody>,
+) -> Result<impl IntoResponse, Error> {
+    let recurring_movement = state
+        .recurring_movements
+        .create_recurring_movement(
+            claims.sub,
+            payload.account_id,
+            &payload.title,
+            payload.category_id,
+            payload.amount,
+            payload.frequency,
+            payload.start_date,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(recurring_movement)))
+}
+
+async fn put_recurring_movement(
+    State(state): State<AppState>,
+    Path(recurring_movement_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<RecurringMovementUpdateBody>,
+) -> Result<impl IntoResponse, Error> {
+    let recurring_movement = state
+        .recurring_movements
+        .set_recurring_movement_paused(claims.sub, recurring_movement_id, payload.paused)
+        .await?;
+
+    Ok((StatusCode::OK, Json(recurring_movement)))
+}
+
+async fn delete_recurring_movement(
+    State(state): State<AppState>,
+    Path(recurring_movement_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .recurring_movements
+        .delete_recurring_movement(claims.sub, recurring_movement_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/recurring-movements", get(get_recurring_movements))
+        .route("/recurring-movements", post(post_recurring_movement))
+        .route(
+            "/recurring-movements/:recurring_movement_id",
+            put(put_recurring_movement),
+        )
+        .route(
+            "/recurring-movements/:recurring_movement_id",
+            delete(delete_recurring_movement),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        application::use_cases::recurring_movements::MockRecurringMovementUseCase,
+        domain::entities::recurring_movements::RecurringMovement,
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn post_recurring_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 27).unwrap();
+        let recurring_movement = RecurringMovement {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            account_id,
+            category_id,
+            title: "salary".to_string(),
+            amount: Decimal::from(2000),
+            frequency: Frequency::Monthly,
+            start_date,
+            next_date: start_date,
+            paused: false,
+        };
+        let recurring_movement2 = recurring_movement.clone();
+
+        let mut recurring_movements = MockRecurringMovementUseCase::new();
+        recurring_movements
+            .expect_create_recurring_movement()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(account_id),
+                predicate::eq("salary"),
+                predicate::eq(category_id),
+                predicate::eq(Decimal::from(2000)),
+                predicate::eq(Frequency::Monthly),
+                predicate::eq(start_date),
+            )
+            .return_once(|_, _, _, _, _, _, _| Ok(recurring_movement));
+
+        let state = AppState {
+            recurring_movements: Arc::new(recurring_movements),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_recurring_movement(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(RecurringMovementBody {
+                account_id,
+                title: "salary".to_string(),
+                category_id,
+                amount: Decimal::from(2000),
+                frequency: Frequency::Monthly,
+                start_date,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+
+        let body = serde_json::from_slice::<RecurringMovement>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, recurring_movement2);
+    }
+
+    #[tokio::test]
+    async fn get_recurring_movements_successful() {
+        let user_id = uuid::Uuid::new_v4();
+
+        let mut recurring_movements = MockRecurringMovementUseCase::new();
+        recurring_movements
+            .expect_get_recurring_movements()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(vec![]));
+
+        let state = AppState {
+            recurring_movements: Arc::new(recurring_movements),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response =
+            super::get_recurring_movements(axum::extract::State(state), Claims { sub: user_id })
+                .await
+                .unwrap()
+                .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn put_recurring_movement_paused() {
+        let user_id = uuid::Uuid::new_v4();
+        let recurring_movement_id = uuid::Uuid::new_v4();
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let recurring_movement = RecurringMovement {
+            id: recurring_movement_id,
+            user_id,
+            account_id: uuid::Uuid::new_v4(),
+            category_id: uuid::Uuid::new_v4(),
+            title: "rent".to_string(),
+            amount: Decimal::from(-800),
+            frequency: Frequency::Monthly,
+            start_date,
+            next_date: start_date,
+            paused: true,
+        };
+
+        let mut recurring_movements = MockRecurringMovementUseCase::new();
+        recurring_movements
+            .expect_set_recurring_movement_paused()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(recurring_movement_id),
+                predicate::eq(true),
+            )
+            .return_once(|_, _, _| Ok(recurring_movement));
+
+        let state = AppState {
+            recurring_movements: Arc::new(recurring_movements),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::put_recurring_movement(
+            axum::extract::State(state),
+            axum::extract::Path(recurring_movement_id),
+            Claims { sub: user_id },
+            ValidatedJson(RecurringMovementUpdateBody { paused: true }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn delete_recurring_movement_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let recurring_movement_id = uuid::Uuid::new_v4();
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let recurring_movement = RecurringMovement {
+            id: recurring_movement_id,
+            user_id,
+            account_id: uuid::Uuid::new_v4(),
+            category_id: uuid::Uuid::new_v4(),
+            title: "rent".to_string(),
+            amount: Decimal::from(-800),
+            frequency: Frequency::Monthly,
+            start_date,
+            next_date: start_date,
+            paused: false,
+        };
+
+        let mut recurring_movements = MockRecurringMovementUseCase::new();
+        recurring_movements
+            .expect_delete_recurring_movement()
+            .with(predicate::eq(user_id), predicate::eq(recurring_movement_id))
+            .return_once(|_, _| Ok(recurring_movement));
+
+        let state = AppState {
+            recurring_movements: Arc::new(recurring_movements),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::delete_recurring_movement(
+            axum::extract::State(state),
+            axum::extract::Path(recurring_movement_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+}
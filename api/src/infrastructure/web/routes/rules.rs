@@ -0,0 +1,294 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::ValidatedJson;
+use crate::infrastructure::web::State as AppState;
+use crate::{
+    domain::entities::{
+        auth::Claims,
+        rules::{RuleActions, RuleConditions},
+    },
+    domain::error::Error,
+};
+
+#[derive(Deserialize, Validate)]
+struct RuleBody {
+    #[validate(length(min = 3, max = 64))]
+    name: String,
+    #[serde(default)]
+    priority: i32,
+    conditions: RuleConditions,
+    actions: RuleActions,
+}
+
+#[derive(Deserialize, Validate)]
+struct ApplyRulesBody {
+    account_id: Option<Uuid>,
+    dry_run: bool,
+}
+
+async fn get_rules(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let rules = state.rules.get_rules(claims.sub).await?;
+    Ok((StatusCode::OK, Json(rules)))
+}
+
+async fn post_rule(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<RuleBody>,
+) -> Result<impl IntoResponse, Error> {
+    let rule = state
+        .rules
+        .create_rule(
+            claims.sub,
+            &payload.name,
+            payload.priority,
+            payload.conditions,
+            payload.actions,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(rule)))
+}
+
+async fn put_rule(
+    State(state): State<AppState>,
+    Path(rule_id): Path<Uuid>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<RuleBody>,
+) -> Result<impl IntoResponse, Error> {
+    let rule = state
+        .rules
+        .update_rule(
+            claims.sub,
+            rule_id,
+            &payload.name,
+            payload.priority,
+            payload.conditions,
+            payload.actions,
+        )
+        .await?;
+
+    Ok((StatusCode::OK, Json(rule)))
+}
+
+async fn delete_rule(
+    State(state): State<AppState>,
+    Path(rule_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state.rules.delete_rule(claims.sub, rule_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn apply_rules(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<ApplyRulesBody>,
+) -> Result<impl IntoResponse, Error> {
+    let changes = state
+        .rules
+        .apply_rules(claims.sub, payload.account_id, payload.dry_run)
+        .await?;
+
+    Ok((StatusCode::OK, Json(changes)))
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/rules", get(get_rules))
+        .route("/rules", post(post_rule))
+        .route("/rules/apply", post(apply_rules))
+        .route("/rules/:rule_id", put(put_rule))
+        .route("/rules/:rule_id", delete(delete_rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        application::use_cases::rules::MockRuleUseCase,
+        domain::entities::accounts::{Movement, MovementStatus},
+        domain::entities::rules::{Rule, RuleChange},
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn post_rule_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let category_id = uuid::Uuid::new_v4();
+        let payload: RuleBody = serde_json::from_value(json!({
+            "name": "groceries",
+            "conditions": { "title_contains": "grocer", "max_amount": "0" },
+            "actions": { "category_id": category_id, "add_tags": ["food"] },
+        }))
+        .unwrap();
+        let conditions = RuleConditions {
+            title_contains: Some("grocer".to_string()),
+            max_amount: Some(Decimal::from(0)),
+            ..Default::default()
+        };
+        let actions = RuleActions {
+            category_id: Some(category_id),
+            add_tags: vec!["food".to_string()],
+            rename_title: None,
+        };
+        let rule = Rule {
+            id: uuid::Uuid::new_v4(),
+            user_id,
+            name: "groceries".to_string(),
+            priority: 0,
+            conditions: conditions.clone(),
+            actions: actions.clone(),
+        };
+        let rule2 = rule.clone();
+
+        let mut rules_use_case = MockRuleUseCase::new();
+        rules_use_case
+            .expect_create_rule()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq("groceries".to_string()),
+                predicate::eq(0),
+                predicate::eq(conditions),
+                predicate::eq(actions),
+            )
+            .return_once(|_, _, _, _, _| Ok(rule));
+
+        let state = AppState {
+            rules: Arc::new(rules_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_rule(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(payload),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!(rule2));
+    }
+
+    #[tokio::test]
+    async fn delete_rule_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let rule_id = uuid::Uuid::new_v4();
+
+        let mut rules_use_case = MockRuleUseCase::new();
+        rules_use_case
+            .expect_delete_rule()
+            .with(predicate::eq(user_id), predicate::eq(rule_id))
+            .return_once(move |_, _| {
+                Ok(Rule {
+                    id: rule_id,
+                    user_id,
+                    name: "groceries".to_string(),
+                    priority: 0,
+                    conditions: RuleConditions::default(),
+                    actions: RuleActions::default(),
+                })
+            });
+
+        let state = AppState {
+            rules: Arc::new(rules_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::delete_rule(
+            axum::extract::State(state),
+            axum::extract::Path(rule_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn apply_rules_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let account_id = uuid::Uuid::new_v4();
+        let movement = Movement {
+            id: uuid::Uuid::new_v4(),
+            account_id,
+            timestamp: chrono::Utc::now(),
+            title: "Groceries".to_string(),
+            category_id: None,
+            amount: Decimal::from(-20),
+            transfer_id: None,
+            external_id: None,
+            status: MovementStatus::Pending,
+            note: None,
+            tags: vec![],
+            fingerprint: None,
+        };
+        let changes = vec![RuleChange {
+            before: movement.clone(),
+            after: Movement {
+                tags: vec!["food".to_string()],
+                ..movement
+            },
+        }];
+        let changes2 = changes.clone();
+
+        let mut rules_use_case = MockRuleUseCase::new();
+        rules_use_case
+            .expect_apply_rules()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(Some(account_id)),
+                predicate::eq(true),
+            )
+            .return_once(|_, _, _| Ok(changes));
+
+        let state = AppState {
+            rules: Arc::new(rules_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::apply_rules(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(ApplyRulesBody {
+                account_id: Some(account_id),
+                dry_run: true,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!(changes2));
+    }
+}
@@ -0,0 +1,70 @@
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+
+use crate::infrastructure::web::State as AppState;
+use crate::{domain::entities::auth::Claims, domain::error::Error};
+
+async fn get_takeout(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    let takeout = state.takeout.get_takeout(claims.sub).await?;
+    let headers = [
+        (header::CONTENT_TYPE, "application/zip"),
+        (
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"takeout.zip\"",
+        ),
+    ];
+
+    Ok((StatusCode::OK, headers, takeout))
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/takeout", get(get_takeout))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        application::use_cases::takeout::MockTakeoutUseCase, infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn get_takeout_successful() {
+        let user_id = uuid::Uuid::new_v4();
+
+        let mut takeout = MockTakeoutUseCase::new();
+        takeout
+            .expect_get_takeout()
+            .with(predicate::eq(user_id))
+            .return_once(|_| Ok(vec![1, 2, 3]));
+
+        let state = AppState {
+            takeout: Arc::new(takeout),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::get_takeout(axum::extract::State(state), Claims { sub: user_id })
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/zip");
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(&body[..], &[1, 2, 3]);
+    }
+}
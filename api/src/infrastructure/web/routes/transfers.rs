@@ -0,0 +1,223 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, post},
+    Json, Router,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::infrastructure::web::middleware::ValidatedJson;
+use crate::infrastructure::web::State as AppState;
+use crate::{domain::entities::auth::Claims, domain::error::Error};
+
+#[derive(Deserialize, Validate)]
+struct TransferBody {
+    from_account_id: Uuid,
+    to_account_id: Uuid,
+    #[validate(length(min = 3, max = 64))]
+    title: String,
+    amount: Decimal,
+}
+
+async fn post_transfer(
+    State(state): State<AppState>,
+    claims: Claims,
+    ValidatedJson(payload): ValidatedJson<TransferBody>,
+) -> Result<impl IntoResponse, Error> {
+    let transfer = state
+        .transfers
+        .create_transfer(
+            claims.sub,
+            payload.from_account_id,
+            payload.to_account_id,
+            &payload.title,
+            payload.amount,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(transfer)))
+}
+
+async fn delete_transfer(
+    State(state): State<AppState>,
+    Path(transfer_id): Path<Uuid>,
+    claims: Claims,
+) -> Result<impl IntoResponse, Error> {
+    state
+        .transfers
+        .delete_transfer(claims.sub, transfer_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/transfers", post(post_transfer))
+        .route("/transfers/:transfer_id", delete(delete_transfer))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        application::use_cases::auth::MockAuthUseCase,
+        application::use_cases::profile::MockProfileUseCase,
+        application::use_cases::transfers::MockTransferUseCase,
+        domain::entities::accounts::{Movement, MovementStatus, Transfer},
+        infrastructure::web::get_mock_state,
+    };
+
+    #[tokio::test]
+    async fn post_transfer_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let from_account_id = uuid::Uuid::new_v4();
+        let to_account_id = uuid::Uuid::new_v4();
+        let title = "title".to_string();
+        let amount = Decimal::from(10);
+        let transfer_id = uuid::Uuid::new_v4();
+        let transfer = Transfer {
+            id: transfer_id,
+            debit: Movement {
+                account_id: from_account_id,
+                id: uuid::Uuid::new_v4(),
+                amount: -amount,
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: title.clone(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            credit: Movement {
+                account_id: to_account_id,
+                id: uuid::Uuid::new_v4(),
+                amount,
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: title.clone(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            rate: Decimal::from(1),
+            rate_date: None,
+        };
+        let transfer2 = transfer.clone();
+
+        let mut transfers_use_case = MockTransferUseCase::new();
+        transfers_use_case
+            .expect_create_transfer()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(from_account_id),
+                predicate::eq(to_account_id),
+                predicate::eq(title.clone()),
+                predicate::eq(amount),
+            )
+            .return_once(|_, _, _, _, _| Ok(transfer));
+
+        let state = AppState {
+            transfers: Arc::new(transfers_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::post_transfer(
+            axum::extract::State(state),
+            Claims { sub: user_id },
+            ValidatedJson(TransferBody {
+                from_account_id,
+                to_account_id,
+                title,
+                amount,
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+
+        let body = serde_json::from_slice::<Transfer>(
+            &hyper::body::to_bytes(response.into_body()).await.unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(body, transfer2);
+    }
+
+    #[tokio::test]
+    async fn delete_transfer_successful() {
+        let user_id = uuid::Uuid::new_v4();
+        let transfer_id = uuid::Uuid::new_v4();
+        let transfer = Transfer {
+            id: transfer_id,
+            debit: Movement {
+                account_id: uuid::Uuid::new_v4(),
+                id: uuid::Uuid::new_v4(),
+                amount: Decimal::from(-10),
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: "title".to_string(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            credit: Movement {
+                account_id: uuid::Uuid::new_v4(),
+                id: uuid::Uuid::new_v4(),
+                amount: Decimal::from(10),
+                category_id: None,
+                timestamp: chrono::Utc::now(),
+                title: "title".to_string(),
+                transfer_id: Some(transfer_id),
+                external_id: None,
+                status: MovementStatus::Pending,
+                note: None,
+                tags: vec![],
+                fingerprint: None,
+            },
+            rate: Decimal::from(1),
+            rate_date: None,
+        };
+
+        let mut transfers_use_case = MockTransferUseCase::new();
+        transfers_use_case
+            .expect_delete_transfer()
+            .with(predicate::eq(user_id), predicate::eq(transfer_id))
+            .return_once(|_, _| Ok(transfer));
+
+        let state = AppState {
+            transfers: Arc::new(transfers_use_case),
+            ..get_mock_state(MockAuthUseCase::new(), MockProfileUseCase::new())
+        };
+
+        let response = super::delete_transfer(
+            axum::extract::State(state),
+            axum::extract::Path(transfer_id),
+            Claims { sub: user_id },
+        )
+        .await
+        .unwrap()
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
+}
@@ -13,5 +13,15 @@ async fn main() {
     let config = envy::from_env::<config::Config>().expect("Error reading configuration");
     let _guard = log::init(&config.log_level, config.log_pretty);
 
-    infrastructure::run(config).await;
+    // `check-balances [--repair]` runs the balance integrity check once instead of the server
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("check-balances") => {
+            let repair = args.iter().any(|arg| arg == "--repair");
+            if !infrastructure::check_balances(config, repair).await {
+                std::process::exit(1);
+            }
+        }
+        _ => infrastructure::run(config).await,
+    }
 }
@@ -1,4 +1,4 @@
-import { apiFetch, withJson } from '.';
+import { apiFetch, apiFetchProtected, withJson } from '.';
 import { accessTokenStore } from '../stores';
 
 export type LoginResponse = {
@@ -40,3 +40,21 @@ export const signup = async (email: string, otp: string) =>
 			{ email, otp },
 		),
 	);
+
+export const requestAccountDeletion = async () =>
+	await apiFetchProtected(`/auth/account/deletion-otp`, {
+		method: 'POST',
+	});
+
+export const deleteUserAccount = async (otp: string) => {
+	await apiFetchProtected(
+		`/auth/account`,
+		withJson(
+			{
+				method: 'DELETE',
+			},
+			{ otp },
+		),
+	);
+	accessTokenStore.set(null);
+};
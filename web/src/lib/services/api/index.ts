@@ -33,6 +33,17 @@ export const apiFetchProtected = async <T = void>(
 	}
 };
 
+export const apiDownloadProtected = async (url: string): Promise<Blob> => {
+	const response = await fetch(`${apiUrl}${url}`, withBearer({ mode: 'cors', method: 'GET' }));
+
+	if (!response.ok) {
+		if (response.status == 401) accessTokenStore.set(null);
+		throw response.status;
+	}
+
+	return await response.blob();
+};
+
 export const withJson = (options: RequestInit | undefined, data?: unknown) => ({
 	...options,
 	headers: {
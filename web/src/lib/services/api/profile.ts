@@ -1,17 +1,95 @@
-import { apiFetchProtected, withJson } from '.';
+import { apiDownloadProtected, apiFetchProtected, withJson } from '.';
 
-export const currencies = ['USD', 'EUR'] as const;
+export const currencies = ['USD', 'EUR', 'GBP', 'CHF', 'JPY'] as const;
 export type Currency = (typeof currencies)[number];
 
-export const categories = ['GENERIC', 'BILLS', 'INCOME', 'SHOPPING', 'INSURANCE'] as const;
-export type Category = (typeof categories)[number];
+export type Category = {
+	id: string;
+	parent_id: string | null;
+	name: string;
+	color: string | null;
+	icon: string | null;
+};
+
+export type CategorySuggestion = {
+	category_id: Category['id'];
+	confidence: number;
+};
 
 export type Movement = {
 	id: string;
 	title: string;
-	category: Category;
+	category_id: Category['id'] | null;
 	timestamp: string;
 	amount: string;
+	transfer_id: string | null;
+	external_id: string | null;
+	status: MovementStatus;
+	note: string | null;
+	tags: string[];
+};
+
+export type Tag = {
+	name: string;
+	count: number;
+};
+
+export const movementStatuses = ['PENDING', 'CLEARED', 'RECONCILED'] as const;
+export type MovementStatus = (typeof movementStatuses)[number];
+
+export type Transfer = {
+	id: string;
+	debit: Movement;
+	credit: Movement;
+	rate: string;
+	rate_date: string | null;
+};
+
+export type ExchangeRate = {
+	id: string;
+	date: string;
+	from: Currency;
+	to: Currency;
+	rate: string;
+};
+
+export type MovementSplit = {
+	id: string;
+	movement_id: Movement['id'];
+	category_id: Category['id'];
+	amount: string;
+	memo: string | null;
+};
+
+export type SplitLine = Pick<MovementSplit, 'category_id' | 'amount' | 'memo'>;
+
+export type SplitMovement = {
+	movement: Movement;
+	splits: MovementSplit[];
+};
+
+export type MovementPage = {
+	movements: Movement[];
+	next_cursor: string | null;
+	splits: MovementSplit[];
+};
+
+export const accountKinds = [
+	'CHECKING',
+	'SAVINGS',
+	'CASH',
+	'CREDIT_CARD',
+	'LOAN',
+	'INVESTMENT',
+] as const;
+export type AccountKind = (typeof accountKinds)[number];
+
+export type AccountTerms = {
+	credit_limit: string | null;
+	apr: string | null;
+	statement_day: number | null;
+	loan_principal: string | null;
+	loan_term_months: number | null;
 };
 
 export type Account = {
@@ -19,6 +97,39 @@ export type Account = {
 	name: string;
 	balance: string;
 	currency: Currency;
+	opening_balance: string;
+	opening_date: string | null;
+	kind: AccountKind;
+	terms: AccountTerms;
+};
+
+export type Instalment = {
+	number: number;
+	date: string;
+	payment: string;
+	interest: string;
+	principal: string;
+	remaining: string;
+};
+
+export type AmortisationSchedule = {
+	account_id: Account['id'];
+	currency: Currency;
+	principal: string;
+	apr: string;
+	payment: string;
+	total_interest: string;
+	instalments: Instalment[];
+};
+
+export type CardStatement = {
+	account_id: Account['id'];
+	currency: Currency;
+	statement_date: string;
+	due_date: string;
+	statement_balance: string;
+	minimum_payment: string;
+	available_credit: string;
 };
 
 export type Profile = {
@@ -35,12 +146,31 @@ export const getAccount = async (account_id: Account['id']) =>
 		method: 'GET',
 	});
 
-export const getMovements = async (account_id: Account['id']) =>
-	await apiFetchProtected<Movement[]>(`/profile/accounts/${account_id}/movements`, {
+export const getMovements = async (
+	account_id: Account['id'],
+	cursor?: string,
+	tags: Tag['name'][] = [],
+) => {
+	const query = new URLSearchParams();
+	if (cursor) query.set('cursor', cursor);
+	if (tags.length) query.set('tags', tags.join(','));
+	return await apiFetchProtected<MovementPage>(
+		`/profile/accounts/${account_id}/movements${query.size ? `?${query}` : ''}`,
+		{
+			method: 'GET',
+		},
+	);
+};
+
+export const getTags = async () =>
+	await apiFetchProtected<Tag[]>(`/profile/tags`, {
 		method: 'GET',
 	});
 
-export const createAccount = async (account: Pick<Account, 'name' | 'currency'>) =>
+export const createAccount = async (
+	account: Pick<Account, 'name' | 'currency'> &
+		Partial<Pick<Account, 'opening_balance' | 'opening_date' | 'kind' | 'terms'>>,
+) =>
 	await apiFetchProtected<Account>(
 		`/profile/accounts`,
 		withJson(
@@ -53,7 +183,13 @@ export const createAccount = async (account: Pick<Account, 'name' | 'currency'>)
 
 export const createMovement = async (
 	accountId: Account['id'],
-	movement: Pick<Movement, 'title' | 'category' | 'amount'>,
+	movement: {
+		title: string;
+		category_id: Category['id'];
+		amount: string;
+		note?: string;
+		tags?: Tag['name'][];
+	},
 ) =>
 	await apiFetchProtected<Account>(
 		`/profile/accounts/${accountId}/movements`,
@@ -64,3 +200,751 @@ export const createMovement = async (
 			movement,
 		),
 	);
+
+export const createSplitMovement = async (
+	accountId: Account['id'],
+	movement: { title: string; amount: string; splits: SplitLine[] },
+) =>
+	await apiFetchProtected<SplitMovement>(
+		`/profile/accounts/${accountId}/movements/split`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			movement,
+		),
+	);
+
+export const splitMovement = async (
+	accountId: Account['id'],
+	movementId: Movement['id'],
+	splits: SplitLine[],
+) =>
+	await apiFetchProtected<SplitMovement>(
+		`/profile/accounts/${accountId}/movements/${movementId}/splits`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			{ splits },
+		),
+	);
+
+export const createTransfer = async (transfer: {
+	from_account_id: Account['id'];
+	to_account_id: Account['id'];
+	title: string;
+	amount: string;
+}) =>
+	await apiFetchProtected<Transfer>(
+		`/profile/transfers`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			transfer,
+		),
+	);
+
+export const getExchangeRates = async () =>
+	await apiFetchProtected<ExchangeRate[]>(`/profile/exchange-rates`, {
+		method: 'GET',
+	});
+
+export const setExchangeRate = async (rate: Pick<ExchangeRate, 'date' | 'from' | 'to' | 'rate'>) =>
+	await apiFetchProtected<ExchangeRate>(
+		`/profile/exchange-rates`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			rate,
+		),
+	);
+
+export const importExchangeRates = async (csv: string) =>
+	await apiFetchProtected<ExchangeRate[]>(`/profile/exchange-rates/import`, {
+		method: 'POST',
+		headers: { 'content-type': 'text/csv' },
+		body: csv,
+	});
+
+export type NetWorth = {
+	currency: Currency;
+	total: string;
+	rate_date: string | null;
+	breakdown: {
+		currency: Currency;
+		balance: string;
+		rate: string;
+		converted: string;
+	}[];
+};
+
+export const getNetWorth = async () =>
+	await apiFetchProtected<NetWorth>(`/profile/net-worth`, {
+		method: 'GET',
+	});
+
+export const setPreferredCurrency = async (currency: Currency) =>
+	await apiFetchProtected(
+		`/profile/preferred-currency`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			{ currency },
+		),
+	);
+
+export const getCategories = async () =>
+	await apiFetchProtected<Category[]>(`/profile/categories`, {
+		method: 'GET',
+	});
+
+export const getCategorySuggestion = async (title: string) =>
+	await apiFetchProtected<CategorySuggestion | null>(
+		`/profile/categories/suggestion?${new URLSearchParams({ title })}`,
+		{
+			method: 'GET',
+		},
+	);
+
+export const createCategory = async (
+	category: Pick<Category, 'name' | 'parent_id' | 'color' | 'icon'>,
+) =>
+	await apiFetchProtected<Category>(
+		`/profile/categories`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			category,
+		),
+	);
+
+export const updateCategory = async (
+	categoryId: Category['id'],
+	category: Pick<Category, 'name' | 'parent_id' | 'color' | 'icon'>,
+) =>
+	await apiFetchProtected<Category>(
+		`/profile/categories/${categoryId}`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			category,
+		),
+	);
+
+export const deleteCategory = async (categoryId: Category['id']) =>
+	await apiFetchProtected(`/profile/categories/${categoryId}`, {
+		method: 'DELETE',
+	});
+
+export type Budget = {
+	id: string;
+	category_id: Category['id'];
+	month: string;
+	amount: string;
+	currency: Currency;
+	rollover: boolean;
+};
+
+export type BudgetReport = {
+	month: string;
+	threshold: number;
+	entries: {
+		budget_id: Budget['id'];
+		category_id: Category['id'];
+		currency: Currency;
+		budgeted: string;
+		carried_over: string;
+		available: string;
+		spent: string;
+		remaining: string;
+		over_threshold: boolean;
+	}[];
+};
+
+export const getBudgets = async (month?: string) =>
+	await apiFetchProtected<Budget[]>(`/profile/budgets${month ? `?month=${month}` : ''}`, {
+		method: 'GET',
+	});
+
+export const setBudget = async (budget: Omit<Budget, 'id'>) =>
+	await apiFetchProtected<Budget>(
+		`/profile/budgets`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			budget,
+		),
+	);
+
+export const deleteBudget = async (budgetId: Budget['id']) =>
+	await apiFetchProtected(`/profile/budgets/${budgetId}`, {
+		method: 'DELETE',
+	});
+
+export const getBudgetReport = async (month: string, threshold?: number) =>
+	await apiFetchProtected<BudgetReport>(
+		`/profile/budgets/report?month=${month}${threshold ? `&threshold=${threshold}` : ''}`,
+		{
+			method: 'GET',
+		},
+	);
+
+export const intervals = ['day', 'week', 'month'] as const;
+export type Interval = (typeof intervals)[number];
+
+export type Analytics = {
+	currency: Currency;
+	from: string;
+	to: string;
+	interval: Interval;
+	income: string;
+	expense: string;
+	net: string;
+	periods: {
+		period: string;
+		income: string;
+		expense: string;
+		net: string;
+	}[];
+	categories: {
+		category_id: Category['id'] | null;
+		income: string;
+		expense: string;
+	}[];
+	top_titles: {
+		title: string;
+		count: number;
+		expense: string;
+	}[];
+};
+
+export const getAnalytics = async (
+	from: string,
+	to: string,
+	options: {
+		accountId?: Account['id'];
+		tag?: Tag['name'];
+		interval?: Interval;
+		top?: number;
+	} = {},
+) => {
+	const query = new URLSearchParams({ from, to });
+	if (options.accountId) query.set('account_id', options.accountId);
+	if (options.tag) query.set('tag', options.tag);
+	if (options.interval) query.set('interval', options.interval);
+	if (options.top) query.set('top', options.top.toString());
+	return await apiFetchProtected<Analytics>(`/profile/analytics?${query}`, {
+		method: 'GET',
+	});
+};
+
+export type BalanceSeries = {
+	account_id: Account['id'];
+	currency: Currency;
+	interval: Interval;
+	balances: {
+		period: string;
+		balance: string;
+	}[];
+};
+
+export const getBalanceSeries = async (
+	accountId: Account['id'],
+	from: string,
+	to: string,
+	interval?: Interval,
+) => {
+	const query = new URLSearchParams({ from, to });
+	if (interval) query.set('interval', interval);
+	return await apiFetchProtected<BalanceSeries>(
+		`/profile/accounts/${accountId}/balances?${query}`,
+		{
+			method: 'GET',
+		},
+	);
+};
+
+export const getAmortisationSchedule = async (accountId: Account['id']) =>
+	await apiFetchProtected<AmortisationSchedule>(`/profile/accounts/${accountId}/amortisation`, {
+		method: 'GET',
+	});
+
+export const getCardStatement = async (accountId: Account['id']) =>
+	await apiFetchProtected<CardStatement>(`/profile/accounts/${accountId}/statement`, {
+		method: 'GET',
+	});
+
+export type Reconciliation = {
+	id: string;
+	account_id: Account['id'];
+	statement_date: string;
+	statement_balance: string;
+	finished_at: string | null;
+};
+
+export type ReconciliationReport = {
+	reconciliation: Reconciliation;
+	cleared_balance: string;
+	difference: string;
+	movements: Movement[];
+};
+
+export const startReconciliation = async (
+	accountId: Account['id'],
+	statement: Pick<Reconciliation, 'statement_date' | 'statement_balance'>,
+) =>
+	await apiFetchProtected<ReconciliationReport>(
+		`/profile/accounts/${accountId}/reconciliation`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			statement,
+		),
+	);
+
+export const getReconciliation = async (accountId: Account['id']) =>
+	await apiFetchProtected<ReconciliationReport>(`/profile/accounts/${accountId}/reconciliation`, {
+		method: 'GET',
+	});
+
+export const finishReconciliation = async (accountId: Account['id']) =>
+	await apiFetchProtected<Reconciliation>(`/profile/accounts/${accountId}/reconciliation/finish`, {
+		method: 'POST',
+	});
+
+export const cancelReconciliation = async (accountId: Account['id']) =>
+	await apiFetchProtected(`/profile/accounts/${accountId}/reconciliation`, {
+		method: 'DELETE',
+	});
+
+export const setMovementStatus = async (
+	accountId: Account['id'],
+	movementId: Movement['id'],
+	status: Exclude<MovementStatus, 'RECONCILED'>,
+) =>
+	await apiFetchProtected<Movement>(
+		`/profile/accounts/${accountId}/movements/${movementId}/status`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			{ status },
+		),
+	);
+
+export const frequencies = ['WEEKLY', 'MONTHLY', 'YEARLY'] as const;
+export type Frequency = (typeof frequencies)[number];
+
+export type RecurringMovement = {
+	id: string;
+	account_id: Account['id'];
+	category_id: Category['id'];
+	title: string;
+	amount: string;
+	frequency: Frequency;
+	start_date: string;
+	next_date: string;
+	paused: boolean;
+};
+
+export const getRecurringMovements = async () =>
+	await apiFetchProtected<RecurringMovement[]>(`/profile/recurring-movements`, {
+		method: 'GET',
+	});
+
+export const createRecurringMovement = async (
+	recurringMovement: Pick<
+		RecurringMovement,
+		'account_id' | 'category_id' | 'title' | 'amount' | 'frequency' | 'start_date'
+	>,
+) =>
+	await apiFetchProtected<RecurringMovement>(
+		`/profile/recurring-movements`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			recurringMovement,
+		),
+	);
+
+export const setRecurringMovementPaused = async (
+	recurringMovementId: RecurringMovement['id'],
+	paused: boolean,
+) =>
+	await apiFetchProtected<RecurringMovement>(
+		`/profile/recurring-movements/${recurringMovementId}`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			{ paused },
+		),
+	);
+
+export const deleteRecurringMovement = async (recurringMovementId: RecurringMovement['id']) =>
+	await apiFetchProtected(`/profile/recurring-movements/${recurringMovementId}`, {
+		method: 'DELETE',
+	});
+
+export type ImportMapping = {
+	id: string;
+	name: string;
+	delimiter: string;
+	date_column: string;
+	description_column: string;
+	amount_column: string | null;
+	debit_column: string | null;
+	credit_column: string | null;
+	date_format: string;
+	decimal_separator: string;
+};
+
+export type ImportRow = {
+	date: string;
+	title: string;
+	amount: string;
+	duplicate: boolean;
+};
+
+export type ImportResult = {
+	movements: Movement[];
+	skipped: number;
+};
+
+export const getImportMappings = async () =>
+	await apiFetchProtected<ImportMapping[]>(`/profile/import-mappings`, {
+		method: 'GET',
+	});
+
+export const createImportMapping = async (mapping: Omit<ImportMapping, 'id'>) =>
+	await apiFetchProtected<ImportMapping>(
+		`/profile/import-mappings`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			mapping,
+		),
+	);
+
+export const deleteImportMapping = async (mappingId: ImportMapping['id']) =>
+	await apiFetchProtected(`/profile/import-mappings/${mappingId}`, {
+		method: 'DELETE',
+	});
+
+export const previewImport = async (
+	accountId: Account['id'],
+	mappingId: ImportMapping['id'],
+	csv: string,
+) =>
+	await apiFetchProtected<ImportRow[]>(
+		`/profile/accounts/${accountId}/import/preview?mapping_id=${mappingId}`,
+		{
+			method: 'POST',
+			headers: { 'content-type': 'text/csv' },
+			body: csv,
+		},
+	);
+
+export const importMovements = async (
+	accountId: Account['id'],
+	mappingId: ImportMapping['id'],
+	csv: string,
+) =>
+	await apiFetchProtected<ImportResult>(
+		`/profile/accounts/${accountId}/import?mapping_id=${mappingId}`,
+		{
+			method: 'POST',
+			headers: { 'content-type': 'text/csv' },
+			body: csv,
+		},
+	);
+
+export type StatementFormat = 'ofx' | 'camt053';
+
+export const importStatement = async (
+	accountId: Account['id'],
+	format: StatementFormat,
+	data: string,
+) =>
+	await apiFetchProtected<ImportResult>(
+		`/profile/accounts/${accountId}/import/statement?format=${format}`,
+		{
+			method: 'POST',
+			headers: { 'content-type': format === 'ofx' ? 'application/x-ofx' : 'application/xml' },
+			body: data,
+		},
+	);
+
+export type ExportFormat = 'csv' | 'json' | 'ofx';
+
+export const exportMovements = async (
+	accountId: Account['id'],
+	format: ExportFormat,
+	from?: string,
+	to?: string,
+) => {
+	const query = new URLSearchParams({ format });
+	if (from) query.set('from', from);
+	if (to) query.set('to', to);
+	return await apiDownloadProtected(`/profile/accounts/${accountId}/export?${query}`);
+};
+
+export const downloadTakeout = async () => await apiDownloadProtected(`/profile/takeout`);
+
+export type RuleConditions = {
+	title_contains: string | null;
+	title_regex: string | null;
+	min_amount: string | null;
+	max_amount: string | null;
+	account_id: Account['id'] | null;
+};
+
+export type RuleActions = {
+	category_id: Category['id'] | null;
+	add_tags: Tag['name'][];
+	rename_title: string | null;
+};
+
+export type Rule = {
+	id: string;
+	name: string;
+	priority: number;
+	conditions: RuleConditions;
+	actions: RuleActions;
+};
+
+export type RuleChange = {
+	before: Movement;
+	after: Movement;
+};
+
+export const getRules = async () =>
+	await apiFetchProtected<Rule[]>(`/profile/rules`, {
+		method: 'GET',
+	});
+
+export const createRule = async (rule: Omit<Rule, 'id'>) =>
+	await apiFetchProtected<Rule>(
+		`/profile/rules`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			rule,
+		),
+	);
+
+export const updateRule = async (ruleId: Rule['id'], rule: Omit<Rule, 'id'>) =>
+	await apiFetchProtected<Rule>(
+		`/profile/rules/${ruleId}`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			rule,
+		),
+	);
+
+export const deleteRule = async (ruleId: Rule['id']) =>
+	await apiFetchProtected(`/profile/rules/${ruleId}`, {
+		method: 'DELETE',
+	});
+
+export const applyRules = async (dryRun: boolean, accountId?: Account['id']) =>
+	await apiFetchProtected<RuleChange[]>(
+		`/profile/rules/apply`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			{ dry_run: dryRun, account_id: accountId ?? null },
+		),
+	);
+
+export type Goal = {
+	id: string;
+	name: string;
+	target_amount: string;
+	currency: Currency;
+	start_date: string;
+	deadline: string;
+	account_ids: Account['id'][];
+};
+
+export const goalStatuses = ['ACHIEVED', 'ON_TRACK', 'BEHIND'] as const;
+export type GoalStatus = (typeof goalStatuses)[number];
+
+export type GoalProgress = {
+	goal: Goal;
+	saved: string;
+	remaining: string;
+	percent_complete: string;
+	months_left: number;
+	monthly_contribution: string;
+	status: GoalStatus;
+};
+
+export const getGoals = async () =>
+	await apiFetchProtected<GoalProgress[]>(`/profile/goals`, {
+		method: 'GET',
+	});
+
+export const getGoal = async (goalId: Goal['id']) =>
+	await apiFetchProtected<GoalProgress>(`/profile/goals/${goalId}`, {
+		method: 'GET',
+	});
+
+export const createGoal = async (
+	goal: Pick<Goal, 'name' | 'target_amount' | 'currency' | 'deadline' | 'account_ids'>,
+) =>
+	await apiFetchProtected<Goal>(
+		`/profile/goals`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			goal,
+		),
+	);
+
+export const updateGoal = async (
+	goalId: Goal['id'],
+	goal: Pick<Goal, 'name' | 'target_amount' | 'currency' | 'deadline' | 'account_ids'>,
+) =>
+	await apiFetchProtected<Goal>(
+		`/profile/goals/${goalId}`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			goal,
+		),
+	);
+
+export const deleteGoal = async (goalId: Goal['id']) =>
+	await apiFetchProtected(`/profile/goals/${goalId}`, {
+		method: 'DELETE',
+	});
+
+export const tradeKinds = ['BUY', 'SELL', 'DIVIDEND'] as const;
+export type TradeKind = (typeof tradeKinds)[number];
+
+export type Trade = {
+	id: string;
+	account_id: Account['id'];
+	movement_id: Movement['id'];
+	date: string;
+	kind: TradeKind;
+	symbol: string;
+	quantity: string;
+	price: string;
+	fees: string;
+};
+
+export type Price = {
+	id: string;
+	symbol: string;
+	currency: Currency;
+	date: string;
+	price: string;
+};
+
+export const costMethods = ['fifo', 'average'] as const;
+export type CostMethod = (typeof costMethods)[number];
+
+export type Holding = {
+	symbol: string;
+	quantity: string;
+	cost_basis: string;
+	price: string | null;
+	price_date: string | null;
+	market_value: string;
+	unrealised_gain: string;
+	realised_gain: string;
+	dividends: string;
+};
+
+export type Portfolio = {
+	account_id: Account['id'];
+	currency: Currency;
+	method: CostMethod;
+	cash: string;
+	market_value: string;
+	total: string;
+	cost_basis: string;
+	unrealised_gain: string;
+	realised_gain: string;
+	dividends: string;
+	holdings: Holding[];
+};
+
+export const getTrades = async (accountId: Account['id']) =>
+	await apiFetchProtected<Trade[]>(`/profile/accounts/${accountId}/trades`, {
+		method: 'GET',
+	});
+
+export const createTrade = async (
+	accountId: Account['id'],
+	trade: Pick<Trade, 'date' | 'kind' | 'symbol' | 'price'> &
+		Partial<Pick<Trade, 'quantity' | 'fees'>>,
+) =>
+	await apiFetchProtected<Trade>(
+		`/profile/accounts/${accountId}/trades`,
+		withJson(
+			{
+				method: 'POST',
+			},
+			trade,
+		),
+	);
+
+export const deleteTrade = async (accountId: Account['id'], tradeId: Trade['id']) =>
+	await apiFetchProtected(`/profile/accounts/${accountId}/trades/${tradeId}`, {
+		method: 'DELETE',
+	});
+
+export const getPortfolio = async (accountId: Account['id'], method?: CostMethod) => {
+	const query = new URLSearchParams();
+	if (method) query.set('method', method);
+	return await apiFetchProtected<Portfolio>(`/profile/accounts/${accountId}/portfolio?${query}`, {
+		method: 'GET',
+	});
+};
+
+export const getPrices = async () =>
+	await apiFetchProtected<Price[]>(`/profile/prices`, {
+		method: 'GET',
+	});
+
+export const setPrice = async (price: Pick<Price, 'date' | 'symbol' | 'currency' | 'price'>) =>
+	await apiFetchProtected<Price>(
+		`/profile/prices`,
+		withJson(
+			{
+				method: 'PUT',
+			},
+			price,
+		),
+	);
+
+export const importPrices = async (csv: string) =>
+	await apiFetchProtected<Price[]>(`/profile/prices/import`, {
+		method: 'POST',
+		headers: { 'content-type': 'text/csv' },
+		body: csv,
+	});
+
+export const deletePrice = async (priceId: Price['id']) =>
+	await apiFetchProtected(`/profile/prices/${priceId}`, {
+		method: 'DELETE',
+	});